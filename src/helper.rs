@@ -4,3 +4,6 @@ pub mod number_format;
 pub mod string_helper;
 pub mod date;
 pub mod time_zone;
+pub mod image;
+pub mod guid;
+pub mod crypt;
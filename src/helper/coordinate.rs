@@ -75,6 +75,30 @@ pub fn coordinate_from_string(coordinate:&str)->Vec<&str> {
     vec![col, row, is_lock_col, is_lock_row]
 }
 
+/// Whether the text is one cell coordinate, such as "B3", up to "XFD1048576".
+pub fn is_coordinate(coordinate:&str)->bool {
+    let re = Regex::new(r"^([A-Z]{1,3})([1-9][0-9]{0,6})$").unwrap();
+    match re.captures(coordinate) {
+        Some(caps) => {
+            let col = caps[1].chars().fold(0, |acc, c| acc * 26 + (c as usize - 'A' as usize + 1));
+            let row = caps[2].parse::<usize>().unwrap_or(0);
+            col <= 16384 && row <= 1048576
+        },
+        None => false,
+    }
+}
+
+/// Whether the text is a range of cells from the top left to the bottom right cell, such as "B2:E6", or one cell.
+pub fn is_range(range:&str)->bool {
+    let coordinates: Vec<&str> = range.split(':').collect();
+    if coordinates.len() > 2 || !coordinates.iter().all(|v| is_coordinate(v)) {
+        return false;
+    }
+    let start = index_from_coordinate(coordinates[0]);
+    let end = index_from_coordinate(coordinates[coordinates.len() - 1]);
+    start[0] <= end[0] && start[1] <= end[1]
+}
+
 pub fn coordinate_from_index(col:&usize, row:&usize)->String {
     format!(
         "{}{}",
@@ -0,0 +1,62 @@
+use sha2::{Digest, Sha512};
+
+/// Hash a password with the legacy 16 bit algorithm of Excel. ex) "secret" -> "DAA7"
+/// Only the first 15 characters count, as in Excel.
+pub(crate) fn hash_password_legacy(password:&str) -> String {
+    let mut hash: u16 = 0;
+    let mut length: u16 = 0;
+    for (i, c) in password.chars().take(15).enumerate() {
+        let value = ((c as u32) & 0xFF) << (i + 1);
+        let rotated_bits = value >> 15;
+        hash ^= ((value & 0x7FFF) | rotated_bits) as u16;
+        length = length.wrapping_add(1);
+    }
+    hash ^= length;
+    hash ^= 0xCE4B;
+    format!("{:04X}", hash)
+}
+
+/// Hash a password with SHA-512 as the agile protection of ECMA-376 does.
+/// # Return value
+/// * `String` - hash value in base64.
+pub(crate) fn hash_password(password:&str, salt:&[u8], spin_count:&u32) -> String {
+    let mut data = salt.to_vec();
+    for unit in password.encode_utf16() {
+        data.extend_from_slice(&unit.to_le_bytes());
+    }
+    let mut hash = Sha512::digest(&data);
+    for i in 0..*spin_count {
+        let mut hasher = Sha512::new();
+        hasher.update(&hash);
+        hasher.update(&i.to_le_bytes());
+        hash = hasher.finalize();
+    }
+    encode_base64(&hash)
+}
+
+/// Make a new random 16 byte salt.
+pub(crate) fn new_salt() -> Vec<u8> {
+    let mut salt = vec![0u8; 16];
+    getrandom::getrandom(&mut salt).expect("failed to get random bytes for the salt.");
+    salt
+}
+
+pub(crate) fn encode_base64(data:&[u8]) -> String {
+    base64::encode(data)
+}
+
+pub(crate) fn decode_base64(value:&str) -> Option<Vec<u8>> {
+    base64::decode(value).ok()
+}
+
+#[test]
+fn test_hash_password() {
+    let salt: Vec<u8> = (0..16).collect();
+    assert_eq!("CXKZSg0lRQXjv6TEwydCy5XHt70aX6Jx9GKMtQyMjIRIkpFW7ePJqjaX3Ec5TpP9BgjuXpQtVWZE+jlsPm5OFw==", hash_password("Password1!", &salt, &100000));
+    assert_eq!("DAA7", hash_password_legacy("secret"));
+    assert_eq!("83AF", hash_password_legacy("password"));
+    assert_eq!("c2FsdA==", encode_base64(b"salt"));
+    assert_eq!(Some(b"salt".to_vec()), decode_base64("c2FsdA=="));
+    assert_eq!(16, new_salt().len());
+    assert_ne!(new_salt(), new_salt());
+}
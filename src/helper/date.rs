@@ -3,43 +3,79 @@ use chrono::{NaiveDateTime, Duration};
 pub const CALENDAR_WINDOWS_1900: &'static str = "1900";
 pub const CALENDAR_MAC_1904: &'static str = "1904";
 
-pub fn excel_to_date_time_object(excel_timestamp:&f64, time_zone:Option<String>)-> NaiveDateTime {
-    let time_zone = match time_zone { Some(v)=> v, None=> get_default_timezone()};
-
-    let mut base_date = NaiveDateTime::parse_from_str("1970-01-01 00:00:00", "%Y-%m-%d %T").unwrap();
+/// Convert a serial number into a date and time in the 1900 date system.
+/// Serial numbers below 1 give a time on 1970-01-01.
+/// # Arguments
+/// * `excel_timestamp` - serial number. ex) 44349.5
+/// * `_time_zone` - ignored. Serial numbers carry no time zone, so the result is the local time written in the cell.
+///
+/// Use `excel_to_date_time_object_with_calendar`, which also supports the 1904 date system.
+#[deprecated(note = "the time zone argument is ignored; use excel_to_date_time_object_with_calendar")]
+pub fn excel_to_date_time_object(excel_timestamp:&f64, _time_zone:Option<String>)-> NaiveDateTime {
     if excel_timestamp < &1f64 {
-        // Unix timestamp base date
-        base_date = NaiveDateTime::parse_from_str("1970-01-01 00:00:00", "%Y-%m-%d %T").unwrap();
+        let base_date = NaiveDateTime::parse_from_str("1970-01-01 00:00:00", "%Y-%m-%d %T").unwrap();
+        return base_date + Duration::seconds((excel_timestamp * 86400f64).round() as i64);
+    }
+    excel_to_date_time_object_with_calendar(excel_timestamp, CALENDAR_WINDOWS_1900)
+}
+
+/// Convert a serial number of `calendar` into a date and time.
+/// # Arguments
+/// * `excel_timestamp` - serial number. ex) 44349.5
+/// * `calendar` - CALENDAR_WINDOWS_1900 or CALENDAR_MAC_1904.
+pub fn excel_to_date_time_object_with_calendar(excel_timestamp:&f64, calendar:&str)-> NaiveDateTime {
+    let base_date = if calendar == CALENDAR_MAC_1904 {
+        NaiveDateTime::parse_from_str("1904-01-01 00:00:00", "%Y-%m-%d %T").unwrap()
     } else {
-        // MS Excel calendar base dates
-        if CALENDAR_WINDOWS_1900 == CALENDAR_WINDOWS_1900 {
-            // Allow adjustment for 1900 Leap Year in MS Excel
-            if excel_timestamp < &60f64 {
-                base_date = NaiveDateTime::parse_from_str("1899-12-31 00:00:00", "%Y-%m-%d %T").unwrap();
-            } else {
-                base_date = NaiveDateTime::parse_from_str("1899-12-30 00:00:00", "%Y-%m-%d %T").unwrap();
-            }
+        // Allow adjustment for 1900 Leap Year in MS Excel
+        if excel_timestamp < &60f64 {
+            NaiveDateTime::parse_from_str("1899-12-31 00:00:00", "%Y-%m-%d %T").unwrap()
         } else {
-            base_date = NaiveDateTime::parse_from_str("1904-01-01 00:00:00", "%Y-%m-%d %T").unwrap();
+            NaiveDateTime::parse_from_str("1899-12-30 00:00:00", "%Y-%m-%d %T").unwrap()
         }
-    }
+    };
 
     let days = excel_timestamp.floor();
     let part_day = excel_timestamp - days;
-    let hours = (part_day * 24.0).floor();
-    let part_day = part_day * 24f64 - hours;
-    let minutes = (part_day * 60f64).floor();
-    let part_day = part_day * 60f64 - minutes;
-    let seconds = (part_day * 60f64).round();
-
-    base_date = base_date + Duration::days(days as i64);
-    base_date = base_date + Duration::hours(hours as i64);
-    base_date = base_date + Duration::minutes(minutes as i64);
-    base_date = base_date + Duration::seconds(seconds as i64);
-
-    base_date
+    let seconds = (part_day * 86400f64).round();
+
+    base_date + Duration::days(days as i64) + Duration::seconds(seconds as i64)
+}
+
+pub fn date_time_object_to_excel(date_time:&NaiveDateTime, calendar:&str)-> f64 {
+    let base_date = if calendar == CALENDAR_MAC_1904 {
+        NaiveDateTime::parse_from_str("1904-01-01 00:00:00", "%Y-%m-%d %T").unwrap()
+    } else {
+        NaiveDateTime::parse_from_str("1899-12-30 00:00:00", "%Y-%m-%d %T").unwrap()
+    };
+    let duration = date_time.signed_duration_since(base_date);
+    let mut excel_timestamp = duration.num_seconds() as f64 / 86400f64;
+
+    // Allow adjustment for 1900 Leap Year in MS Excel
+    if calendar != CALENDAR_MAC_1904 && excel_timestamp < 61f64 {
+        excel_timestamp -= 1f64;
+    }
+    excel_timestamp
 }
 
-fn get_default_timezone()-> String {
-    String::from("UTC")
+#[test]
+fn test_excel_to_date_time_object() {
+    let date = |v:&str| NaiveDateTime::parse_from_str(v, "%Y-%m-%d %T").unwrap();
+
+    assert_eq!(date("1900-01-01 00:00:00"), excel_to_date_time_object_with_calendar(&1f64, CALENDAR_WINDOWS_1900));
+    assert_eq!(date("1900-02-28 00:00:00"), excel_to_date_time_object_with_calendar(&59f64, CALENDAR_WINDOWS_1900));
+    assert_eq!(date("1900-03-01 00:00:00"), excel_to_date_time_object_with_calendar(&61f64, CALENDAR_WINDOWS_1900));
+    assert_eq!(date("2021-06-02 05:04:02"), excel_to_date_time_object_with_calendar(&44349.211134259262f64, CALENDAR_WINDOWS_1900));
+    assert_eq!(date("1904-01-01 00:00:00"), excel_to_date_time_object_with_calendar(&0f64, CALENDAR_MAC_1904));
+    assert_eq!(date("2024-05-23 00:00:00"), excel_to_date_time_object_with_calendar(&43973f64, CALENDAR_MAC_1904));
+
+    assert_eq!(1f64, date_time_object_to_excel(&date("1900-01-01 00:00:00"), CALENDAR_WINDOWS_1900));
+    assert_eq!(59f64, date_time_object_to_excel(&date("1900-02-28 00:00:00"), CALENDAR_WINDOWS_1900));
+    assert_eq!(61f64, date_time_object_to_excel(&date("1900-03-01 00:00:00"), CALENDAR_WINDOWS_1900));
+    assert_eq!(45435.5f64, date_time_object_to_excel(&date("2024-05-23 12:00:00"), CALENDAR_WINDOWS_1900));
+    assert_eq!(43973f64, date_time_object_to_excel(&date("2024-05-23 00:00:00"), CALENDAR_MAC_1904));
+
+    #[allow(deprecated)]
+    let value = excel_to_date_time_object(&44349.5f64, None);
+    assert_eq!(date("2021-06-02 12:00:00"), value);
 }
@@ -1,7 +1,99 @@
-use onig::*;
 use helper::coordinate::*;
 
-pub fn adjustment_insert_formula_coordinate(
+/// Quote a sheet name for use in a reference when it needs quoting.
+pub fn quote_sheet_name(sheet_name:&str)-> String {
+    let is_plain = sheet_name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !sheet_name.starts_with(|c:char| c.is_ascii_digit())
+        && !is_reference_like(sheet_name);
+    if is_plain && sheet_name != "" {
+        return sheet_name.to_string();
+    }
+    format!("'{}'", sheet_name.replace("'", "''"))
+}
+
+// Names such as "A1", "XFD10", "R1C1", "R" or "C" would be read as references when left unquoted.
+fn is_reference_like(sheet_name:&str)-> bool {
+    let upper = sheet_name.to_uppercase();
+    let letters:String = upper.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+    let digits = &upper[letters.len()..];
+    if letters.len() > 0 && letters.len() <= 3 && digits != "" && digits.chars().all(|c| c.is_ascii_digit()) {
+        let column = letters.chars().fold(0, |acc, c| acc * 26 + (c as usize - 'A' as usize + 1));
+        if column <= 16384 {
+            return true;
+        }
+    }
+    let mut chars = upper.chars().peekable();
+    let mut has_part = false;
+    for part in &['R', 'C'] {
+        if chars.peek() == Some(part) {
+            chars.next();
+            while chars.peek().map_or(false, |c| c.is_ascii_digit()) {
+                chars.next();
+            }
+            has_part = true;
+        }
+    }
+    has_part && chars.next().is_none()
+}
+
+/// Remove the quotes of a sheet name taken from a reference.
+pub fn unquote_sheet_name(sheet_name:&str)-> String {
+    if sheet_name.starts_with('\'') && sheet_name.ends_with('\'') && sheet_name.len() > 1 {
+        return sheet_name[1..sheet_name.len() - 1].replace("''", "'");
+    }
+    sheet_name.to_string()
+}
+
+pub fn adjustment_rename_formula_sheet(
+    formula:&str,
+    old_worksheet_name:&str,
+    new_worksheet_name:&str
+)-> String {
+    let quoted_old = format!("'{}'!", old_worksheet_name.replace("'", "''"));
+    let plain_old = format!("{}!", old_worksheet_name);
+    let new_prefix = format!("{}!", quote_sheet_name(new_worksheet_name));
+    let mut result = String::new();
+    let mut in_string = false;
+    let mut prev_char: Option<char> = None;
+    let mut index = 0;
+    while index < formula.len() {
+        let rest = &formula[index..];
+        if !in_string {
+            let is_boundary = match prev_char {
+                Some(c) => !(c.is_alphanumeric() || c == '_' || c == '.' || c == '\''),
+                None => true,
+            };
+            if is_boundary && rest.starts_with(&quoted_old) {
+                result.push_str(&new_prefix);
+                index += quoted_old.len();
+                prev_char = Some('!');
+                continue;
+            }
+            if is_boundary && rest.starts_with(&plain_old) {
+                result.push_str(&new_prefix);
+                index += plain_old.len();
+                prev_char = Some('!');
+                continue;
+            }
+        }
+        let c = rest.chars().next().unwrap();
+        if c == '"' {
+            in_string = !in_string;
+        }
+        result.push(c);
+        prev_char = Some(c);
+        index += c.len_utf8();
+    }
+    result
+}
+
+// A reference end point: column and row with their `$` locks.
+type ReferencePoint = (Option<(usize, bool)>, Option<(usize, bool)>);
+
+/// Shift every A1 reference pointing at `worksheet_name`, including `$` locked
+/// references and whole columns or rows, leaving string literals untouched.
+/// Unqualified references belong to `self_worksheet_name`.
+pub fn adjustment_insert_formula_references(
     formula:&str,
     root_col_num:&usize,
     offset_col_num:&usize,
@@ -10,51 +102,68 @@ pub fn adjustment_insert_formula_coordinate(
     worksheet_name:&str,
     self_worksheet_name:&str
 )-> String {
-    let re = Regex::new(r#"[^\(]*!*[A-Z]+[0-9]+\:[A-Z]+[0-9]+"#).unwrap();
-    let result = re.replace_all(formula,
-        |caps: &Captures| {
-            let caps_string: String = (&caps.at(0).unwrap()).parse().unwrap();
-            let split_str:Vec<&str> = caps_string.split("!").collect();
-            let with_wksheet: bool;
-            let wksheet: String;
-            let range: String;
-            if split_str.len() == 2 {
-                with_wksheet = true;
-                wksheet = split_str.get(0).unwrap().to_string();
-                range = split_str.get(1).unwrap().to_string();
-            } else {
-                with_wksheet = false;
-                wksheet = self_worksheet_name.to_string();
-                range = split_str.get(0).unwrap().to_string();
+    adjustment_formula_references(formula, worksheet_name, self_worksheet_name, |points| {
+        for point in points.iter_mut() {
+            if let Some((col_num, _)) = &mut point.0 {
+                *col_num = adjustment_insert_coordinate(col_num, root_col_num, offset_col_num);
             }
-            if &wksheet != &worksheet_name {
-                return caps_string;
+            if let Some((row_num, _)) = &mut point.1 {
+                *row_num = adjustment_insert_coordinate(row_num, root_row_num, offset_row_num);
             }
-            let split_range:Vec<&str> = range.split(":").collect();
-            let mut result = String::from("");
-            for coordinate in split_range {
-                let index_coordinate = index_from_coordinate(coordinate);
-                let mut col_num = index_coordinate[0];
-                let mut row_num = index_coordinate[1];
-                let is_lock_col = index_coordinate[2] == 1;
-                let is_lock_row = index_coordinate[3] == 1;
-                col_num = adjustment_insert_coordinate(&col_num, root_col_num, offset_col_num);
-                row_num = adjustment_insert_coordinate(&row_num, root_row_num, offset_row_num);
-                let new_corrdinate = coordinate_from_index_with_lock(&col_num, &row_num, &is_lock_col, &is_lock_row);
-                if &result != "" {
-                    result = format!("{}:" , result);
-                }
-                result = format!("{}{}", result, new_corrdinate);
+        }
+        true
+    })
+}
+
+/// Shrink every A1 reference pointing at `worksheet_name` after columns or rows are removed.
+/// A reference that lies entirely in the removed area becomes `#REF!`.
+pub fn adjustment_remove_formula_references(
+    formula:&str,
+    root_col_num:&usize,
+    offset_col_num:&usize,
+    root_row_num:&usize,
+    offset_row_num:&usize,
+    worksheet_name:&str,
+    self_worksheet_name:&str
+)-> String {
+    adjustment_formula_references(formula, worksheet_name, self_worksheet_name, |points| {
+        let last = points.len() - 1;
+        if let (Some((start, _)), Some((end, _))) = (points[0].0, points[last].0) {
+            match remove_span(start, end, root_col_num, offset_col_num) {
+                Some((start, end)) => {
+                    points[0].0 = points[0].0.map(|(_, lock)| (start, lock));
+                    points[last].0 = points[last].0.map(|(_, lock)| (end, lock));
+                },
+                None => return false,
             }
-            if with_wksheet {
-                result = format!("{}!{}", wksheet, result);
+        }
+        if let (Some((start, _)), Some((end, _))) = (points[0].1, points[last].1) {
+            match remove_span(start, end, root_row_num, offset_row_num) {
+                Some((start, end)) => {
+                    points[0].1 = points[0].1.map(|(_, lock)| (start, lock));
+                    points[last].1 = points[last].1.map(|(_, lock)| (end, lock));
+                },
+                None => return false,
             }
-            result
         }
-    );
-    result
+        true
+    })
 }
 
+#[deprecated(note = "use adjustment_insert_formula_references")]
+pub fn adjustment_insert_formula_coordinate(
+    formula:&str,
+    root_col_num:&usize,
+    offset_col_num:&usize,
+    root_row_num:&usize,
+    offset_row_num:&usize,
+    worksheet_name:&str,
+    self_worksheet_name:&str
+)-> String {
+    adjustment_insert_formula_references(formula, root_col_num, offset_col_num, root_row_num, offset_row_num, worksheet_name, self_worksheet_name)
+}
+
+#[deprecated(note = "use adjustment_remove_formula_references")]
 pub fn adjustment_remove_formula_coordinate(
     formula:&str,
     root_col_num:&usize,
@@ -64,47 +173,255 @@ pub fn adjustment_remove_formula_coordinate(
     worksheet_name:&str,
     self_worksheet_name:&str
 )-> String {
-    let re = Regex::new(r#"[^\(]*!*[A-Z]+[0-9]+\:[A-Z]+[0-9]+"#).unwrap();
-    let result = re.replace_all(formula,
-        |caps: &Captures| {
-            let caps_string: String = (&caps.at(0).unwrap()).parse().unwrap();
-            let split_str:Vec<&str> = caps_string.split("!").collect();
-            let with_wksheet: bool;
-            let wksheet: String;
-            let range: String;
-            if split_str.len() == 2 {
-                with_wksheet = true;
-                wksheet = split_str.get(0).unwrap().to_string();
-                range = split_str.get(1).unwrap().to_string();
-            } else {
-                with_wksheet = false;
-                wksheet = self_worksheet_name.to_string();
-                range = split_str.get(0).unwrap().to_string();
-            }
-            if &wksheet != &worksheet_name {
-                return caps_string;
+    adjustment_remove_formula_references(formula, root_col_num, offset_col_num, root_row_num, offset_row_num, worksheet_name, self_worksheet_name)
+}
+
+/// Whether the formula is a single, optionally sheet qualified, reference. ex) "Sheet1!$A$1:$B$2"
+pub fn is_reference_formula(formula:&str)-> bool {
+    let chars: Vec<char> = formula.chars().collect();
+    if chars.len() == 0 {
+        return false;
+    }
+    let start = match parse_sheet_prefix(&chars, 0) {
+        Some((_, index)) => index,
+        None => 0,
+    };
+    match parse_reference(&chars, start) {
+        Some((_, end)) => end == chars.len(),
+        None => false,
+    }
+}
+
+fn adjustment_formula_references<F>(formula:&str, worksheet_name:&str, self_worksheet_name:&str, adjust:F)-> String
+where
+    F: Fn(&mut Vec<ReferencePoint>) -> bool,
+{
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::new();
+    let mut in_string = false;
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c == '"' {
+            in_string = !in_string;
+        }
+        let is_boundary = !in_string && match index {
+            0 => true,
+            _ => {
+                let prev = chars[index - 1];
+                !(prev.is_alphanumeric() || prev == '_' || prev == '.' || prev == '$' || prev == '\'')
             }
-            let split_range:Vec<&str> = range.split(":").collect();
-            let mut result = String::from("");
-            for coordinate in split_range {
-                let index_coordinate = index_from_coordinate(coordinate);
-                let mut col_num = index_coordinate[0];
-                let mut row_num = index_coordinate[1];
-                let is_lock_col = index_coordinate[2] == 1;
-                let is_lock_row = index_coordinate[3] == 1;
-                col_num = adjustment_remove_coordinate(&col_num, root_col_num, offset_col_num);
-                row_num = adjustment_remove_coordinate(&row_num, root_row_num, offset_row_num);
-                let new_corrdinate = coordinate_from_index_with_lock(&col_num, &row_num, &is_lock_col, &is_lock_row);
-                if &result != "" {
-                    result = format!("{}:" , result);
+        };
+        if is_boundary {
+            let (sheet_name, reference_start) = match parse_sheet_prefix(&chars, index) {
+                Some((sheet_name, next)) => (Some(sheet_name), next),
+                None => (None, index),
+            };
+            if let Some((mut points, reference_end)) = parse_reference(&chars, reference_start) {
+                let target = match &sheet_name {
+                    Some(v) => v.as_str(),
+                    None => self_worksheet_name,
+                };
+                if target == worksheet_name {
+                    result.extend(chars[index..reference_start].iter());
+                    if adjust(&mut points) {
+                        result.push_str(&reference_to_string(&points));
+                    } else {
+                        result.push_str("#REF!");
+                    }
+                } else {
+                    result.extend(chars[index..reference_end].iter());
                 }
-                result = format!("{}{}", result, new_corrdinate);
+                index = reference_end;
+                continue;
             }
-            if with_wksheet {
-                result = format!("{}!{}", wksheet, result);
+            if sheet_name.is_some() {
+                result.extend(chars[index..reference_start].iter());
+                index = reference_start;
+                continue;
             }
-            result
         }
-    );
+        result.push(c);
+        index += 1;
+    }
     result
 }
+
+fn parse_sheet_prefix(chars:&[char], start:usize)-> Option<(String, usize)> {
+    let mut sheet_name = String::new();
+    let mut index = start;
+    if chars[index] == '\'' {
+        index += 1;
+        loop {
+            if index >= chars.len() {
+                return None;
+            }
+            if chars[index] == '\'' {
+                if index + 1 < chars.len() && chars[index + 1] == '\'' {
+                    sheet_name.push('\'');
+                    index += 2;
+                    continue;
+                }
+                index += 1;
+                break;
+            }
+            sheet_name.push(chars[index]);
+            index += 1;
+        }
+    } else {
+        while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_' || chars[index] == '.') {
+            sheet_name.push(chars[index]);
+            index += 1;
+        }
+    }
+    if sheet_name == "" || index >= chars.len() || chars[index] != '!' {
+        return None;
+    }
+    Some((sheet_name, index + 1))
+}
+
+fn parse_reference(chars:&[char], start:usize)-> Option<(Vec<ReferencePoint>, usize)> {
+    let (first, mut index) = parse_reference_point(chars, start);
+    let mut points = vec![first];
+    if index < chars.len() && chars[index] == ':' {
+        let (second, next) = parse_reference_point(chars, index + 1);
+        if first.0.is_some() == second.0.is_some() && first.1.is_some() == second.1.is_some() {
+            points.push(second);
+            index = next;
+        }
+    }
+    let is_valid = match (points[0], points.len()) {
+        ((Some(_), Some(_)), _) => true,
+        ((Some(_), None), 2) | ((None, Some(_)), 2) => true,
+        _ => false,
+    };
+    if !is_valid {
+        return None;
+    }
+    // a function name, a defined name or a sheet prefix rather than a reference
+    if index < chars.len() {
+        let next = chars[index];
+        if next.is_alphanumeric() || next == '_' || next == '.' || next == '(' || next == '!' || next == '$' {
+            return None;
+        }
+    }
+    Some((points, index))
+}
+
+fn parse_reference_point(chars:&[char], start:usize)-> (ReferencePoint, usize) {
+    let mut index = start;
+    let mut col = None;
+    let mut row = None;
+
+    let is_lock = index < chars.len() && chars[index] == '$';
+    let letters_start = if is_lock { index + 1 } else { index };
+    let mut end = letters_start;
+    while end < chars.len() && chars[end].is_ascii_uppercase() && end - letters_start < 3 {
+        end += 1;
+    }
+    if end > letters_start {
+        let letters: String = chars[letters_start..end].iter().collect();
+        col = Some((column_index_from_string(letters), is_lock));
+        index = end;
+    }
+
+    let is_lock = index < chars.len() && chars[index] == '$';
+    let digits_start = if is_lock { index + 1 } else { index };
+    let mut end = digits_start;
+    while end < chars.len() && chars[end].is_ascii_digit() {
+        end += 1;
+    }
+    if end > digits_start {
+        let digits: String = chars[digits_start..end].iter().collect();
+        match digits.parse::<usize>() {
+            Ok(v) if v > 0 => {
+                row = Some((v, is_lock));
+                index = end;
+            },
+            _ => {}
+        }
+    }
+    ((col, row), index)
+}
+
+fn reference_to_string(points:&Vec<ReferencePoint>)-> String {
+    let mut result: Vec<String> = Vec::new();
+    for (col, row) in points {
+        let mut value = String::new();
+        if let Some((col_num, is_lock)) = col {
+            if *is_lock {
+                value.push('$');
+            }
+            value.push_str(&string_from_column_index(col_num));
+        }
+        if let Some((row_num, is_lock)) = row {
+            if *is_lock {
+                value.push('$');
+            }
+            value.push_str(&row_num.to_string());
+        }
+        result.push(value);
+    }
+    result.join(":")
+}
+
+// The span left after removing `offset_num` lines from `root_num`; `None` when nothing is left.
+fn remove_span(start:usize, end:usize, root_num:&usize, offset_num:&usize)-> Option<(usize, usize)> {
+    if root_num == &0 || offset_num == &0 {
+        return Some((start, end));
+    }
+    let removed_end = root_num + offset_num;
+    if start >= *root_num && end < removed_end {
+        return None;
+    }
+    let new_start = if start >= removed_end { start - offset_num } else if start >= *root_num { *root_num } else { start };
+    let new_end = if end >= removed_end { end - offset_num } else if end >= *root_num { root_num - 1 } else { end };
+    Some((new_start, new_end))
+}
+
+#[test]
+fn test_adjustment_rename_formula_sheet() {
+    assert_eq!("SUM(Data!A1:A3)", adjustment_rename_formula_sheet("SUM(Sheet1!A1:A3)", "Sheet1", "Data"));
+    assert_eq!("'My Data'!A1+'My Data'!B1", adjustment_rename_formula_sheet("Sheet1!A1+'Sheet1'!B1", "Sheet1", "My Data"));
+    assert_eq!("Sheet2!A1&\"Sheet1!A1\"", adjustment_rename_formula_sheet("'It''s'!A1&\"Sheet1!A1\"", "It's", "Sheet2"));
+    assert_eq!("MySheet1!A1", adjustment_rename_formula_sheet("MySheet1!A1", "Sheet1", "Data"));
+}
+
+#[test]
+fn test_quote_sheet_name() {
+    assert_eq!("Sheet1", quote_sheet_name("Sheet1"));
+    assert_eq!("'My Data'", quote_sheet_name("My Data"));
+    assert_eq!("'It''s'", quote_sheet_name("It's"));
+    assert_eq!("'A1'", quote_sheet_name("A1"));
+    assert_eq!("'xfd100'", quote_sheet_name("xfd100"));
+    assert_eq!("'2024'", quote_sheet_name("2024"));
+    assert_eq!("'1Q'", quote_sheet_name("1Q"));
+    assert_eq!("'R1C1'", quote_sheet_name("R1C1"));
+    assert_eq!("'R'", quote_sheet_name("R"));
+    assert_eq!("'c2'", quote_sheet_name("c2"));
+    assert_eq!("XFE1", quote_sheet_name("XFE1"));
+    assert_eq!("Data2024", quote_sheet_name("Data2024"));
+    assert_eq!("Rate", quote_sheet_name("Rate"));
+}
+
+#[test]
+fn test_adjustment_formula_references() {
+    assert_eq!("Sheet1!$A$3:$B$4", adjustment_insert_formula_references("Sheet1!$A$1:$B$2", &0, &0, &1, &2, "Sheet1", ""));
+    assert_eq!("OFFSET('My Data'!$A$1,0,0,COUNTA('My Data'!$D:$D),1)", adjustment_insert_formula_references("OFFSET('My Data'!$A$1,0,0,COUNTA('My Data'!$B:$B),1)", &2, &2, &0, &0, "My Data", ""));
+    assert_eq!("0.19", adjustment_insert_formula_references("0.19", &1, &1, &1, &1, "Sheet1", "Sheet1"));
+    assert_eq!("LOG10(A2)&\"B2\"", adjustment_insert_formula_references("LOG10(A1)&\"B2\"", &0, &0, &1, &1, "Sheet1", "Sheet1"));
+    assert_eq!("Sheet2!A1+A2", adjustment_insert_formula_references("Sheet2!A1+A1", &0, &0, &1, &1, "Sheet1", "Sheet1"));
+    assert_eq!("Sheet1!$A$2:$A$3", adjustment_remove_formula_references("Sheet1!$A$2:$A$5", &0, &0, &3, &2, "Sheet1", ""));
+    assert_eq!("SUM(Sheet1!#REF!)", adjustment_remove_formula_references("SUM(Sheet1!B2:C4)", &2, &2, &0, &0, "Sheet1", ""));
+    assert_eq!(true, is_reference_formula("'My Data'!$A$1:$B$2"));
+    assert_eq!(true, is_reference_formula("Sheet1!$1:$1"));
+    assert_eq!(false, is_reference_formula("0.19"));
+    assert_eq!(false, is_reference_formula("Sheet1!$A$1+1"));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_adjustment_formula_coordinate() {
+    assert_eq!("SUM(A3:B4)", adjustment_insert_formula_coordinate("SUM(A1:B2)", &0, &0, &1, &2, "Sheet1", "Sheet1"));
+    assert_eq!("SUM(A1:B2)", adjustment_remove_formula_coordinate("SUM(A3:B4)", &0, &0, &1, &2, "Sheet1", "Sheet1"));
+}
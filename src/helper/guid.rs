@@ -0,0 +1,27 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static GUID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Make a new GUID in registry format. ex) "{3F2504E0-4F89-41D3-9A0C-0305E82C3301}"
+pub(crate) fn new_guid(seed:&str) -> String {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(v) => v.as_nanos(),
+        Err(_) => 0,
+    };
+    let count = GUID_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let hash = format!("{:X}", md5::compute(format!("{}{}{}", seed, nanos, count)));
+    // version 4, RFC 4122 variant
+    let variant = (u8::from_str_radix(&hash[16..17], 16).unwrap() & 0x3) | 0x8;
+    format!("{{{}-{}-4{}-{:X}{}-{}}}", &hash[0..8], &hash[8..12], &hash[13..16], variant, &hash[17..20], &hash[20..32])
+}
+
+#[test]
+fn new_guid_format() {
+    let guid = new_guid("seed");
+    assert_eq!(38, guid.len());
+    assert!(guid.starts_with("{") && guid.ends_with("}"));
+    assert_eq!("4", &guid[15..16]);
+    assert!(["8", "9", "A", "B"].contains(&&guid[20..21]));
+    assert_ne!(guid, new_guid("seed"));
+}
@@ -0,0 +1,267 @@
+use regex::Regex;
+
+pub const IMAGE_FORMAT_PNG: &'static str = "png";
+pub const IMAGE_FORMAT_JPEG: &'static str = "jpeg";
+pub const IMAGE_FORMAT_GIF: &'static str = "gif";
+pub const IMAGE_FORMAT_BMP: &'static str = "bmp";
+pub const IMAGE_FORMAT_SVG: &'static str = "svg";
+
+/// Resolution assumed when the image does not record one.
+pub const DEFAULT_DPI: f64 = 96f64;
+
+/// EMU per inch.
+pub const EMU_PER_INCH: f64 = 914400f64;
+
+/// Transparent 1x1 PNG, the fallback of SVG images for the versions of Excel that cannot show SVG.
+pub const SVG_FALLBACK_PNG: &'static [u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
+    0x89, 0x00, 0x00, 0x00, 0x0B, 0x49, 0x44, 0x41, 0x54, 0x78, 0xDA, 0x63, 0x60, 0x00, 0x02, 0x00,
+    0x00, 0x05, 0x00, 0x01, 0xE9, 0xFA, 0xDC, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44,
+    0xAE, 0x42, 0x60, 0x82,
+];
+
+/// Detect the format of an image from its first bytes.
+/// # Arguments
+/// * `data` - content of the image file.
+/// # Return value
+/// * `Option<&str>` - file extension of the format. ex) IMAGE_FORMAT_PNG
+pub fn get_image_format(data:&[u8])-> Option<&'static str> {
+    if data.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        return Some(IMAGE_FORMAT_PNG);
+    }
+    if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(IMAGE_FORMAT_JPEG);
+    }
+    if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        return Some(IMAGE_FORMAT_GIF);
+    }
+    if data.starts_with(b"BM") && data.len() > 26 {
+        return Some(IMAGE_FORMAT_BMP);
+    }
+    let head = String::from_utf8_lossy(&data[..data.len().min(1024)]).to_string();
+    if head.trim_start_matches('\u{feff}').trim_start().starts_with('<') && head.contains("<svg") {
+        return Some(IMAGE_FORMAT_SVG);
+    }
+    None
+}
+
+/// Get the size of an image in pixels.
+/// # Arguments
+/// * `data` - content of the image file.
+/// # Return value
+/// * `Option<(u32, u32)>` - (width, height).
+pub fn get_image_size(data:&[u8])-> Option<(u32, u32)> {
+    match get_image_format(data)? {
+        IMAGE_FORMAT_PNG => {
+            Some((read_u32_be(data, 16)?, read_u32_be(data, 20)?))
+        },
+        IMAGE_FORMAT_JPEG => {
+            for (marker, index) in jpeg_segments(data) {
+                match marker {
+                    0xC0..=0xC3 | 0xC5..=0xC7 | 0xC9..=0xCB | 0xCD..=0xCF => {
+                        let height = read_u16_be(data, index + 5)? as u32;
+                        let width = read_u16_be(data, index + 7)? as u32;
+                        return Some((width, height));
+                    },
+                    _ => {}
+                }
+            }
+            None
+        },
+        IMAGE_FORMAT_GIF => {
+            Some((read_u16_le(data, 6)? as u32, read_u16_le(data, 8)? as u32))
+        },
+        IMAGE_FORMAT_BMP => {
+            if read_u32_le(data, 14)? == 12 {
+                return Some((read_u16_le(data, 18)? as u32, read_u16_le(data, 20)? as u32));
+            }
+            let width = read_u32_le(data, 18)? as i32;
+            let height = read_u32_le(data, 22)? as i32;
+            Some((width.unsigned_abs(), height.unsigned_abs()))
+        },
+        IMAGE_FORMAT_SVG => {
+            get_svg_size(&String::from_utf8_lossy(data))
+        },
+        _ => None,
+    }
+}
+
+/// Get the resolution of an image.
+/// # Arguments
+/// * `data` - content of the image file.
+/// # Return value
+/// * `(f64, f64)` - (horizontal, vertical) dots per inch. DEFAULT_DPI if the image does not record one.
+pub fn get_image_dpi(data:&[u8])-> (f64, f64) {
+    let dpi = match get_image_format(data) {
+        Some(IMAGE_FORMAT_PNG) => get_png_dpi(data),
+        Some(IMAGE_FORMAT_JPEG) => get_jpeg_dpi(data),
+        Some(IMAGE_FORMAT_BMP) => {
+            match (read_u32_le(data, 38), read_u32_le(data, 42)) {
+                (Some(x), Some(y)) if read_u32_le(data, 14).unwrap_or(0) >= 40 => Some((x as f64 * 0.0254, y as f64 * 0.0254)),
+                _ => None,
+            }
+        },
+        _ => None,
+    };
+    match dpi {
+        Some((x, y)) if x > 0f64 && y > 0f64 => (x, y),
+        _ => (DEFAULT_DPI, DEFAULT_DPI),
+    }
+}
+
+/// Convert a length in pixels to EMU.
+/// # Arguments
+/// * `pixel` - length in pixels.
+/// * `dpi` - dots per inch of the image.
+pub fn pixel_to_emu(pixel:&u32, dpi:&f64)-> usize {
+    (*pixel as f64 * EMU_PER_INCH / dpi).round() as usize
+}
+
+fn get_png_dpi(data:&[u8])-> Option<(f64, f64)> {
+    let mut index = 8;
+    while index + 8 <= data.len() {
+        let length = read_u32_be(data, index)? as usize;
+        let chunk_type = &data[index + 4..index + 8];
+        if chunk_type == b"pHYs" {
+            let x = read_u32_be(data, index + 8)? as f64;
+            let y = read_u32_be(data, index + 12)? as f64;
+            if *data.get(index + 16)? == 1 {
+                return Some((x * 0.0254, y * 0.0254));
+            }
+            return None;
+        }
+        if chunk_type == b"IDAT" {
+            return None;
+        }
+        index += length + 12;
+    }
+    None
+}
+
+fn get_jpeg_dpi(data:&[u8])-> Option<(f64, f64)> {
+    for (marker, index) in jpeg_segments(data) {
+        if marker == 0xE0 && data.get(index + 4..index + 9) == Some(b"JFIF\0") {
+            let x = read_u16_be(data, index + 12)? as f64;
+            let y = read_u16_be(data, index + 14)? as f64;
+            return match data.get(index + 11)? {
+                1 => Some((x, y)),
+                2 => Some((x * 2.54, y * 2.54)),
+                _ => None,
+            };
+        }
+    }
+    None
+}
+
+// (marker, index of the 0xFF byte) of each segment before the image data.
+fn jpeg_segments(data:&[u8])-> Vec<(u8, usize)> {
+    let mut result = Vec::new();
+    let mut index = 2;
+    while index + 4 <= data.len() {
+        if data[index] != 0xFF {
+            index += 1;
+            continue;
+        }
+        let marker = data[index + 1];
+        match marker {
+            0xFF => {
+                index += 1;
+                continue;
+            },
+            0x01 | 0xD0..=0xD7 => {
+                index += 2;
+                continue;
+            },
+            0xD9 | 0xDA => break,
+            _ => {}
+        }
+        result.push((marker, index));
+        index += 2 + read_u16_be(data, index + 2).unwrap_or(0) as usize;
+    }
+    result
+}
+
+fn get_svg_size(text:&str)-> Option<(u32, u32)> {
+    let start = text.find("<svg")?;
+    let end = text[start..].find('>').map(|v| start + v).unwrap_or(text.len());
+    let tag = &text[start..end];
+    let get_attribute = |name:&str| -> Option<String> {
+        let re = Regex::new(&format!(r#"\s{}\s*=\s*["']([^"']*)["']"#, name)).unwrap();
+        re.captures(tag).map(|caps| caps[1].trim().to_string())
+    };
+    let view_box: Option<Vec<f64>> = get_attribute("viewBox").map(|v| {
+        v.split(|c:char| c == ',' || c.is_whitespace())
+            .filter(|v| v != &"")
+            .filter_map(|v| v.parse::<f64>().ok())
+            .collect()
+    });
+    let (view_box_width, view_box_height) = match &view_box {
+        Some(v) if v.len() == 4 => (Some(v[2]), Some(v[3])),
+        _ => (None, None),
+    };
+    let width = get_attribute("width").and_then(|v| svg_length_to_pixel(&v)).or(view_box_width).unwrap_or(300f64);
+    let height = get_attribute("height").and_then(|v| svg_length_to_pixel(&v)).or(view_box_height).unwrap_or(150f64);
+    Some((width.round() as u32, height.round() as u32))
+}
+
+fn svg_length_to_pixel(value:&str)-> Option<f64> {
+    let units:[(&str, f64); 6] = [("px", 1f64), ("pt", 96f64 / 72f64), ("pc", 16f64), ("in", 96f64), ("cm", 96f64 / 2.54), ("mm", 96f64 / 25.4)];
+    for (unit, scale) in units.iter() {
+        if value.ends_with(unit) {
+            return value[..value.len() - unit.len()].trim().parse::<f64>().ok().map(|v| v * scale);
+        }
+    }
+    value.parse::<f64>().ok()
+}
+
+fn read_u16_be(data:&[u8], index:usize)-> Option<u16> {
+    let bytes = data.get(index..index + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u16_le(data:&[u8], index:usize)-> Option<u16> {
+    let bytes = data.get(index..index + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32_be(data:&[u8], index:usize)-> Option<u32> {
+    let bytes = data.get(index..index + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u32_le(data:&[u8], index:usize)-> Option<u32> {
+    let bytes = data.get(index..index + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[test]
+fn test_get_image_size() {
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&[0, 0, 0, 120, 0, 0, 0, 80, 8, 6, 0, 0, 0, 0, 0, 0, 0]);
+    png.extend_from_slice(&[0, 0, 0, 9]);
+    png.extend_from_slice(b"pHYs");
+    png.extend_from_slice(&[0, 0, 0x0B, 0x13, 0, 0, 0x0B, 0x13, 1, 0, 0, 0, 0]);
+    assert_eq!(Some(IMAGE_FORMAT_PNG), get_image_format(&png));
+    assert_eq!(Some((120, 80)), get_image_size(&png));
+    assert_eq!(72, get_image_dpi(&png).0.round() as u32);
+
+    let jpeg = [0xFF, 0xD8, 0xFF, 0xE0, 0, 16, b'J', b'F', b'I', b'F', 0, 1, 1, 1, 0, 150, 0, 150, 0, 0,
+        0xFF, 0xC0, 0, 11, 8, 0, 40, 0, 60, 1, 1, 0x11, 0, 0xFF, 0xD9];
+    assert_eq!(Some(IMAGE_FORMAT_JPEG), get_image_format(&jpeg));
+    assert_eq!(Some((60, 40)), get_image_size(&jpeg));
+    assert_eq!((150f64, 150f64), get_image_dpi(&jpeg));
+
+    let gif = [b'G', b'I', b'F', b'8', b'9', b'a', 10, 0, 20, 0, 0, 0, 0];
+    assert_eq!(Some((10, 20)), get_image_size(&gif));
+    assert_eq!((DEFAULT_DPI, DEFAULT_DPI), get_image_dpi(&gif));
+
+    let svg = br#"<?xml version="1.0"?><svg xmlns="http://www.w3.org/2000/svg" width="2in" viewBox="0 0 200 50"></svg>"#;
+    assert_eq!(Some(IMAGE_FORMAT_SVG), get_image_format(svg));
+    assert_eq!(Some((192, 50)), get_image_size(svg));
+
+    assert_eq!(Some((1, 1)), get_image_size(SVG_FALLBACK_PNG));
+
+    assert_eq!(9525, pixel_to_emu(&1, &DEFAULT_DPI));
+}
@@ -60,7 +60,15 @@ const DATE_FORMAT_REPLACEMENTS_12: &'static [(&'static str, &'static str)] = &[
     ("h", "%-I"),
 ];
 
+/// Format a value with a number format code. Dates are serial numbers of the 1900 date system.
 pub fn to_formatted_string<S: Into<String>>(value:S, format:S)-> String {
+    to_formatted_string_with_calendar(value, format, CALENDAR_WINDOWS_1900)
+}
+
+/// Format a value with a number format code. Dates are serial numbers of `calendar`.
+/// # Arguments
+/// * `calendar` - CALENDAR_WINDOWS_1900 or CALENDAR_MAC_1904.
+pub fn to_formatted_string_with_calendar<S: Into<String>>(value:S, format:S, calendar:&str)-> String {
     let mut value = value.into();
     let mut format = format.into();
 
@@ -107,7 +115,7 @@ pub fn to_formatted_string<S: Into<String>>(value:S, format:S)-> String {
     let re2 = Regex::new("%$").unwrap();
     if re.find(&format).is_some() {
         // datetime format
-        value = format_as_date(&value.parse::<f64>().unwrap(), &format);
+        value = format_as_date(&value.parse::<f64>().unwrap(), &format, calendar);
     } else {
         if &format.starts_with(r#"""#) == &true && &format.ends_with(r#"""#) == &true {
             let conv_format = format.trim_matches('"').parse::<f64>().unwrap();
@@ -122,6 +130,17 @@ pub fn to_formatted_string<S: Into<String>>(value:S, format:S)-> String {
     value.trim().to_string()
 }
 
+/// Whether the format code displays a date or a time.
+pub fn is_date_format(format:&str)-> bool {
+    if format == NumberFormat::FORMAT_GENERAL || format == NumberFormat::FORMAT_TEXT {
+        return false;
+    }
+    // remove quoted strings, escaped characters, spacing and colors or locales
+    let re = Regex::new(r#""[^"]*"|\\.|_.|\*.|\[(?![hms]+\])[^\]]*\]"#).unwrap();
+    let format = re.replace_all(format, "");
+    format.chars().any(|c| matches!(c, 'd' | 'D' | 'm' | 'M' | 'y' | 'Y' | 'h' | 'H' | 's' | 'S'))
+}
+
 fn format_as_percentage(value:&f64, format:&str)-> String {
     let mut value = value.to_string();
     let mut format = format.to_string();
@@ -228,7 +247,7 @@ fn split_format_compare(value:&f64, cond:&str, val:&f64, dfcond:&str, dfval:&f64
     value >= check_val
 }
 
-fn format_as_date(value:&f64, format:&str)-> String {
+fn format_as_date(value:&f64, format:&str, calendar:&str)-> String {
     let value = value;
     let mut format = format.to_string();
 
@@ -291,7 +310,7 @@ fn format_as_date(value:&f64, format:&str)-> String {
         }
     );
 
-    let date_obj = excel_to_date_time_object(value, None);
+    let date_obj = excel_to_date_time_object_with_calendar(value, calendar);
     date_obj.format(&format).to_string()
 }
 
@@ -597,6 +616,23 @@ fn gcd(a: &f64, b: &f64) -> f64 {
     }
 }
 
+#[test]
+fn test_is_date_format() {
+    assert_eq!(true, is_date_format(NumberFormat::FORMAT_DATE_YYYYMMDD));
+    assert_eq!(true, is_date_format(NumberFormat::FORMAT_DATE_TIME4));
+    assert_eq!(true, is_date_format("[h]:mm:ss"));
+    assert_eq!(true, is_date_format("[$-409]d-mmm-yy;@"));
+    assert_eq!(false, is_date_format(NumberFormat::FORMAT_GENERAL));
+    assert_eq!(false, is_date_format("#,##0.00;[Red]-#,##0.00"));
+    assert_eq!(false, is_date_format(r#"0.00" days""#));
+}
+
+#[test]
+fn test_to_formatted_string_date_1904() {
+    assert_eq!(r#"2024-05-23"#, to_formatted_string_with_calendar("43973", NumberFormat::FORMAT_DATE_YYYYMMDD, CALENDAR_MAC_1904));
+    assert_eq!(r#"2020-05-22"#, to_formatted_string_with_calendar("43973", NumberFormat::FORMAT_DATE_YYYYMMDD, CALENDAR_WINDOWS_1900));
+}
+
 #[test]
 fn test_to_formatted_string_date() {
     let value = String::from("45435"); // 2024/5/23
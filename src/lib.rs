@@ -38,6 +38,9 @@
 //! let _ = book.get_sheet_by_name_mut("Sheet2").unwrap().get_cell_mut("A1").set_value("TEST1");
 //! // or
 //! let _ = book.get_sheet_mut(1).get_cell_by_column_and_row_mut(1, 1).set_value("TEST1");
+//! // typed value (strings are never converted)
+//! let _ = book.get_sheet_mut(1).get_cell_mut("A2").set_value_number(123);
+//! let _ = book.get_sheet_mut(1).get_cell_mut("A3").set_value_bool(true);
 //! ```
 //! ### Read value
 //! ```rust
@@ -121,6 +124,9 @@ extern crate md5;
 extern crate thousands;
 extern crate onig;
 extern crate chrono;
+extern crate sha2;
+extern crate base64;
+extern crate getrandom;
 
 #[macro_use]
 extern crate lazy_static;
@@ -143,6 +149,7 @@ pub use self::structs::*;
 pub fn new_file()->structs::Spreadsheet {
     let mut spreadsheet = structs::Spreadsheet::default();
     spreadsheet.set_theme(Theme::get_defalut_value());
+    spreadsheet.set_filter_privacy(true);
     let worksheet = spreadsheet.new_sheet("Sheet1").unwrap();
     worksheet.set_active_cell("A1");
     spreadsheet
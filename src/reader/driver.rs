@@ -141,12 +141,14 @@ pub(crate) fn get_text_element(
     let mut buf = Vec::new();
     let mut text_element = TextElement::default();
     let mut with_first_space = false;
+    let mut in_text = false;
     loop {
         match reader.read_event(&mut buf) {
             Ok(Event::Start(ref e)) => {
                 match e.name() {
                     b"rPr" => text_element.set_font(get_font(reader, theme)),
                     b"t" => {
+                        in_text = true;
                         match get_attribute(e, b"xml:space") {
                             Some(v) => {
                                 if v == "preserve" {
@@ -159,7 +161,7 @@ pub(crate) fn get_text_element(
                     _ => (),
                 }
             },
-            Ok(Event::Text(e)) => {
+            Ok(Event::Text(e)) if in_text => {
                 let mut value = e.unescape_and_decode(&reader).unwrap();
                 if with_first_space {
                     value = format!("\r\n{}", value);
@@ -168,6 +170,7 @@ pub(crate) fn get_text_element(
             },
             Ok(Event::End(ref e)) => {
                 match e.name() {
+                    b"t" => in_text = false,
                     b"r" => return text_element,
                     _ => (),
                 }
@@ -179,3 +182,7 @@ pub(crate) fn get_text_element(
         buf.clear();
     }
 }
+
+pub(crate) fn is_true(value:&str) -> bool {
+    value == "1" || value == "true"
+}
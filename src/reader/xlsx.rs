@@ -10,6 +10,7 @@ use super::driver;
 
 mod doc_props_app;
 mod doc_props_core;
+mod doc_props_custom;
 mod workbook;
 mod workbook_rels;
 mod worksheet;
@@ -22,6 +23,8 @@ mod vml_drawing;
 mod drawing;
 mod vba_project_bin;
 mod comment;
+mod threaded_comment;
+mod person;
 pub(crate) mod chart;
 pub(crate) mod drawing_rels;
 pub(crate) mod media;
@@ -82,6 +85,7 @@ pub fn read(path: &Path)->Result<Spreadsheet, XlsxError> {
     let (mut book, sheets) = workbook::read(&dir).unwrap();
     doc_props_app::read(&dir, &mut book).unwrap();
     doc_props_core::read(&dir, &mut book).unwrap(); 
+    doc_props_custom::read(&dir, &mut book).unwrap();
     vba_project_bin::read(&dir, &mut book).unwrap();
     let workbook_rel = workbook_rels::read(&dir).unwrap();
 
@@ -91,6 +95,9 @@ pub fn read(path: &Path)->Result<Spreadsheet, XlsxError> {
             "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme" => {
                 theme = theme::read(&dir, rel_target).unwrap();
             },
+            "http://schemas.microsoft.com/office/2017/10/relationships/person" => {
+                person::read(&dir, rel_target, &mut book).unwrap();
+            },
             _ => {}
         }
     }
@@ -98,12 +105,14 @@ pub fn read(path: &Path)->Result<Spreadsheet, XlsxError> {
     let shared_string = shared_strings::read(&dir, &theme).unwrap();
     let (cell_xfs_vec, dxf_vec) = styles::read(&dir, &theme).unwrap();
 
+    let calendar = book.get_calendar().to_string();
+
     let mut sheet_count = 0;
     for (sheets_name, sheets_sheet_id, sheets_rid) in &sheets {
         for (rel_id, _, rel_target) in &workbook_rel {
             if sheets_rid == rel_id {
                 let worksheet = book.new_sheet_crate(sheets_sheet_id.clone(), sheets_name.clone());
-                let (is_active_sheet, _drawing_id, _legacy_drawing_id, hyperlink_vec) = worksheet::read(&dir, &rel_target, worksheet, &theme, &shared_string, &cell_xfs_vec, &dxf_vec).unwrap();
+                let (is_active_sheet, _drawing_id, _legacy_drawing_id, hyperlink_vec) = worksheet::read(&dir, &rel_target, worksheet, &theme, &shared_string, &cell_xfs_vec, &dxf_vec, &calendar).unwrap();
                 let worksheet_rel = worksheet_rels::read(&dir, &rel_target, &hyperlink_vec, worksheet).unwrap();
                 for (_worksheet_id, type_value, worksheet_target) in &worksheet_rel {
                     match type_value.as_str() {
@@ -117,9 +126,14 @@ pub fn read(path: &Path)->Result<Spreadsheet, XlsxError> {
                             let mut vml_drawing_list = vml_drawing::read(&dir, vml_drawing_target).unwrap();
                             let _ = comment::read(&dir, &worksheet_target, worksheet, &mut vml_drawing_list, &theme).unwrap();
                         },
+                        // threaded comment
+                        "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment" => {
+                            threaded_comment::read(&dir, &worksheet_target, worksheet).unwrap();
+                        },
                         _ => {}
                     }
                 }
+                worksheet.remove_threaded_comment_fallbacks();
                 match is_active_sheet {
                     true => book.set_active_sheet_index(sheet_count),
                     false => {},
@@ -128,6 +142,12 @@ pub fn read(path: &Path)->Result<Spreadsheet, XlsxError> {
         }
         sheet_count += 1;
     }
+    // the active tab of the first window wins over the selected sheet tabs
+    let active_tab = book.get_workbook_view_collection().first().map(|x| *x.get_active_tab());
+    match active_tab {
+        Some(v) if v < book.get_sheet_count() => book.set_active_sheet_index(v),
+        _ => {}
+    }
     book.set_theme(theme);
 
     dir.close()?;
@@ -42,6 +42,7 @@ pub(crate) fn read(
                         comment.set_author(author);
                     },
                     b"r" => text_element_vec.push(get_text_element(&mut reader, theme)),
+                    b"t" => value = String::from(""),
                     _ => (),
                 }
             },
@@ -53,6 +54,12 @@ pub(crate) fn read(
                     b"author" => {
                         authors.push(value.clone());
                     },
+                    b"t" => {
+                        // text without runs
+                        let mut text_element = TextElement::default();
+                        text_element.set_text(value.clone());
+                        text_element_vec.push(text_element);
+                    },
                     b"text" => {
                         let mut rich_text = RichText::default();
                         rich_text.set_rich_text_elements(text_element_vec);
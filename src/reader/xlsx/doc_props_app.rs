@@ -3,6 +3,7 @@ use quick_xml::Reader;
 use quick_xml::events::{Event};
 use tempdir::TempDir;
 use super::XlsxError;
+use super::driver::*;
 
 use ::structs::Spreadsheet;
 
@@ -18,9 +19,19 @@ pub(crate) fn read(dir: &TempDir, spreadsheet:&mut Spreadsheet) -> result::Resul
         match reader.read_event(&mut buf) {
             Ok(Event::Text(e)) => string_value = e.unescape_and_decode(&reader).unwrap(),
             Ok(Event::End(ref e)) => {
+                // HeadingPairs and TitlesOfParts are generated from the workbook when writing.
+                let properties = spreadsheet.get_properties_mut();
                 match e.name() {
-                    b"Manager" => {spreadsheet.get_properties_mut().set_manager(string_value.clone());},
-                    b"Company" => {spreadsheet.get_properties_mut().set_company(string_value.clone());},
+                    b"Application" => {properties.set_application(string_value.clone());},
+                    b"AppVersion" => {properties.set_app_version(string_value.clone());},
+                    b"DocSecurity" => {properties.set_doc_security(string_value.parse::<i32>().unwrap_or(0));},
+                    b"ScaleCrop" => {properties.set_scale_crop(is_true(&string_value));},
+                    b"Manager" => {properties.set_manager(string_value.clone());},
+                    b"Company" => {properties.set_company(string_value.clone());},
+                    b"LinksUpToDate" => {properties.set_links_up_to_date(is_true(&string_value));},
+                    b"SharedDoc" => {properties.set_shared_doc(is_true(&string_value));},
+                    b"HyperlinkBase" => {properties.set_hyperlink_base(string_value.clone());},
+                    b"HyperlinksChanged" => {properties.set_hyperlinks_changed(is_true(&string_value));},
                 _ => (),
                 }
                 string_value = String::from("");
@@ -32,4 +43,4 @@ pub(crate) fn read(dir: &TempDir, spreadsheet:&mut Spreadsheet) -> result::Resul
         buf.clear();
     }
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,76 @@
+use std::result;
+use quick_xml::Reader;
+use quick_xml::events::{Event};
+use tempdir::TempDir;
+use super::XlsxError;
+use super::driver::*;
+
+use ::structs::Spreadsheet;
+use ::structs::CustomPropertyValue;
+
+const FILE_PATH: &'static str = "docProps/custom.xml";
+
+pub(crate) fn read(dir: &TempDir, spreadsheet:&mut Spreadsheet) -> result::Result<(), XlsxError> {
+    let path = dir.path().join(FILE_PATH);
+    let mut reader = match Reader::from_file(path){
+        Ok(v) => {v},
+        Err(_) => {return Ok(());}
+    };
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut name: String = String::from("");
+    let mut string_value: String = String::from("");
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                match e.name() {
+                    b"property" => {
+                        name = condvert_character_reference(get_attribute(e, b"name").unwrap_or(String::from("")).as_str());
+                    },
+                    _ => (),
+                }
+                string_value = String::from("");
+            },
+            Ok(Event::Text(e)) => string_value = e.unescape_and_decode(&reader).unwrap(),
+            Ok(Event::Empty(ref e)) => {
+                match e.name() {
+                    b"vt:lpwstr" | b"vt:lpstr" | b"vt:bstr" => {
+                        spreadsheet.get_properties_mut().set_custom_property(name.as_str(), "");
+                    },
+                    _ => (),
+                }
+            },
+            Ok(Event::End(ref e)) => {
+                let value = match e.name() {
+                    b"vt:lpwstr" | b"vt:lpstr" | b"vt:bstr" => Some(CustomPropertyValue::Text(string_value.clone())),
+                    b"vt:i1" | b"vt:i2" | b"vt:i4" | b"vt:i8" | b"vt:int"
+                    | b"vt:ui1" | b"vt:ui2" | b"vt:ui4" | b"vt:ui8" | b"vt:uint" => {
+                        match (string_value.parse::<i64>(), string_value.parse::<f64>()) {
+                            (Ok(v), _) => Some(CustomPropertyValue::Integer(v)),
+                            (Err(_), Ok(v)) => Some(CustomPropertyValue::Number(v)),
+                            _ => Some(CustomPropertyValue::Text(string_value.clone())),
+                        }
+                    },
+                    b"vt:r4" | b"vt:r8" | b"vt:decimal" => {
+                        match string_value.parse::<f64>() {
+                            Ok(v) => Some(CustomPropertyValue::Number(v)),
+                            Err(_) => Some(CustomPropertyValue::Text(string_value.clone())),
+                        }
+                    },
+                    b"vt:bool" => Some(CustomPropertyValue::Bool(string_value == "true" || string_value == "1")),
+                    b"vt:filetime" | b"vt:date" => Some(CustomPropertyValue::Date(string_value.clone())),
+                    _ => None,
+                };
+                match value {
+                    Some(v) => {spreadsheet.get_properties_mut().set_custom_property(name.as_str(), v);},
+                    None => {}
+                }
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::result;
+use quick_xml::Reader;
+use quick_xml::events::{Event};
+use tempdir::TempDir;
+use super::XlsxError;
+use super::driver::*;
+use structs::Spreadsheet;
+use structs::Person;
+
+pub(crate) fn read(
+    dir: &TempDir,
+    target: &str,
+    spreadsheet: &mut Spreadsheet
+) -> result::Result<(), XlsxError> {
+    let path = dir.path().join(format!("xl/{}", target));
+    let mut reader = Reader::from_file(path)?;
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) => {
+                match e.name() {
+                    b"person" => {
+                        let mut person = Person::default();
+                        match get_attribute(e, b"displayName") {
+                            Some(v) => {person.set_display_name(v);},
+                            None => {}
+                        }
+                        match get_attribute(e, b"id") {
+                            Some(v) => {person.set_id(v);},
+                            None => {}
+                        }
+                        match get_attribute(e, b"userId") {
+                            Some(v) => {person.set_user_id(v);},
+                            None => {}
+                        }
+                        match get_attribute(e, b"providerId") {
+                            Some(v) => {person.set_provider_id(v);},
+                            None => {}
+                        }
+                        spreadsheet.add_person_collection(person);
+                    },
+                    _ => (),
+                }
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(())
+}
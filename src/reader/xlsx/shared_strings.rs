@@ -78,7 +78,7 @@ pub(crate) fn read(dir: &TempDir, theme:&Theme) -> result::Result<Vec<(String, O
     Ok(res)
 }
 
-fn get_rubi(reader:&mut quick_xml::Reader<std::io::BufReader<std::fs::File>>) {
+pub(crate) fn get_rubi(reader:&mut quick_xml::Reader<std::io::BufReader<std::fs::File>>) {
     let mut buf = Vec::new();
     loop {
         match reader.read_event(&mut buf) {
@@ -0,0 +1,116 @@
+use std::result;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use tempdir::TempDir;
+use super::XlsxError;
+use super::driver::*;
+use structs::Worksheet;
+use structs::ThreadedComment;
+use structs::ThreadedCommentMention;
+
+pub(crate) fn read(
+    dir: &TempDir,
+    target: &str,
+    worksheet: &mut Worksheet
+) -> result::Result<(), XlsxError> {
+    let path = dir.path().join(format!("xl/worksheets/{}", target));
+    let mut reader = Reader::from_file(path)?;
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+
+    let mut threaded_comment = ThreadedComment::default();
+    let mut value: String = String::from("");
+
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                match e.name() {
+                    b"threadedComment" => {
+                        threaded_comment = get_threaded_comment(e);
+                    },
+                    b"text" => {
+                        value = String::from("");
+                    },
+                    _ => (),
+                }
+            },
+            Ok(Event::Empty(ref e)) => {
+                match e.name() {
+                    b"threadedComment" => {
+                        worksheet.add_threaded_comments(get_threaded_comment(e));
+                    },
+                    b"mention" => {
+                        let mut mention = ThreadedCommentMention::default();
+                        match get_attribute(e, b"mentionpersonId") {
+                            Some(v) => {mention.set_mention_person_id(v);},
+                            None => {}
+                        }
+                        match get_attribute(e, b"mentionId") {
+                            Some(v) => {mention.set_mention_id(v);},
+                            None => {}
+                        }
+                        match get_attribute(e, b"startIndex") {
+                            Some(v) => {mention.set_start_index(v.parse::<usize>().unwrap_or(0));},
+                            None => {}
+                        }
+                        match get_attribute(e, b"length") {
+                            Some(v) => {mention.set_length(v.parse::<usize>().unwrap_or(0));},
+                            None => {}
+                        }
+                        threaded_comment.add_mentions(mention);
+                    },
+                    _ => (),
+                }
+            },
+            Ok(Event::Text(e)) => {
+                value = e.unescape_and_decode(&reader).unwrap();
+            },
+            Ok(Event::End(ref e)) => {
+                match e.name() {
+                    b"text" => {
+                        threaded_comment.set_text(value.clone());
+                    },
+                    b"threadedComment" => {
+                        worksheet.add_threaded_comments(threaded_comment);
+                        threaded_comment = ThreadedComment::default();
+                    },
+                    _ => (),
+                }
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(())
+}
+
+fn get_threaded_comment(e:&BytesStart) -> ThreadedComment {
+    let mut threaded_comment = ThreadedComment::default();
+    match get_attribute(e, b"ref") {
+        Some(v) => {threaded_comment.get_coordinate_mut().set_coordinate(v);},
+        None => {}
+    }
+    match get_attribute(e, b"dT") {
+        Some(v) => {threaded_comment.set_date_time(v);},
+        None => {}
+    }
+    match get_attribute(e, b"personId") {
+        Some(v) => {threaded_comment.set_person_id(v);},
+        None => {}
+    }
+    match get_attribute(e, b"id") {
+        Some(v) => {threaded_comment.set_id(v);},
+        None => {}
+    }
+    match get_attribute(e, b"parentId") {
+        Some(v) => {threaded_comment.set_parent_id(v);},
+        None => {}
+    }
+    match get_attribute(e, b"done") {
+        Some(v) => {threaded_comment.set_done(v == "1" || v == "true");},
+        None => {}
+    }
+    threaded_comment
+}
@@ -36,10 +36,36 @@ pub(crate) fn read(
                         let mut color = Color::default();
                         let _ = color.set_argb(fillcolor.replace("#", ""));
                         comment.set_fill_color(color);
+                        // the shadow is only on when v:shadow says so
+                        comment.set_shadow(false);
+                    },
+                    b"v:textbox" => {
+                        match get_attribute(e, b"style") {
+                            Some(v) => comment.set_auto_fit(v.contains("mso-fit-shape-to-text:t")),
+                            None => {}
+                        }
+                    },
+                    b"div" => {
+                        match get_attribute(e, b"style") {
+                            Some(v) => set_div_style(&mut comment, &v),
+                            None => {}
+                        }
                     },
                     _ => (),
                 }
             },
+            Ok(Event::Empty(ref e)) => {
+                match e.name() {
+                    b"v:shadow" => {
+                        match get_attribute(e, b"on") {
+                            Some(v) => comment.set_shadow(v == "t" || v == "true"),
+                            None => comment.set_shadow(true),
+                        }
+                    },
+                    b"x:Visible" => comment.set_visible(true),
+                    _ => (),
+                }
+            },
             Ok(Event::Text(e)) => string_value = e.unescape_and_decode(&reader).unwrap(),
             Ok(Event::End(ref e)) => {
                 match e.name() {
@@ -106,3 +132,12 @@ fn set_style(comment:&mut Comment, style_string:&str) {
         }
     }
 }
+
+fn set_div_style(comment:&mut Comment, style_string:&str) {
+    for style in style_string.split(';') {
+        let params: Vec<&str> = style.split(':').collect();
+        if params.len() == 2 && params[0].trim() == "text-align" {
+            comment.set_alignment(params[1].trim());
+        }
+    }
+}
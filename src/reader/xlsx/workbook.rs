@@ -7,6 +7,9 @@ use super::driver::*;
 
 use ::structs::Spreadsheet;
 use ::structs::DefinedName;
+use ::structs::Calculation;
+use ::structs::WorkbookView;
+use ::structs::Security;
 
 const FILE_PATH: &'static str = "xl/workbook.xml";
 
@@ -19,14 +22,40 @@ pub(crate) fn read(dir: &TempDir) -> result::Result<(Spreadsheet, Vec<(String, S
     let mut spreadsheet = Spreadsheet::default();
     let mut sheets: Vec<(String, String, String)> = Vec::new();
 
-    let mut defined_name_value = String::from("");
-    let mut is_local_only = false;
+    let mut defined_name = DefinedName::default();
     let mut string_value = String::from("");
 
     loop {
         match reader.read_event(&mut buf) {
             Ok(Event::Empty(ref e)) => {
                 match e.name() {
+                    b"workbookPr" => {
+                        match get_attribute(e, b"date1904") {
+                            Some(v) => spreadsheet.set_date1904(is_true(&v)),
+                            None => {}
+                        }
+                        match get_attribute(e, b"codeName") {
+                            Some(v) => spreadsheet.set_code_name(v),
+                            None => {}
+                        }
+                        match get_attribute(e, b"filterPrivacy") {
+                            Some(v) => spreadsheet.set_filter_privacy(is_true(&v)),
+                            None => {}
+                        }
+                        match get_attribute(e, b"defaultThemeVersion") {
+                            Some(v) => spreadsheet.set_default_theme_version(v),
+                            None => {}
+                        }
+                    },
+                    b"workbookProtection" => {
+                        set_security(e, spreadsheet.get_security_mut());
+                    },
+                    b"workbookView" => {
+                        spreadsheet.add_workbook_view_collection(get_workbook_view(e));
+                    },
+                    b"calcPr" => {
+                        spreadsheet.set_calculation(get_calculation(e));
+                    },
                     b"sheet" => {
                         let name_value =  get_attribute(e, b"name").unwrap();
                         let sheet_id_value =  get_attribute(e, b"sheetId").unwrap();
@@ -39,8 +68,8 @@ pub(crate) fn read(dir: &TempDir) -> result::Result<(Spreadsheet, Vec<(String, S
             Ok(Event::Start(ref e)) => {
                 match e.name() {
                     b"definedName" => {
-                        defined_name_value =  get_attribute(e, b"name").unwrap();
-                        is_local_only = match get_attribute(e, b"localSheetId") { Some(_) => true, None => false};
+                        defined_name = get_defined_name(e);
+                        string_value = String::from("");
                     },
                     _ => (),
                 }
@@ -49,15 +78,11 @@ pub(crate) fn read(dir: &TempDir) -> result::Result<(Spreadsheet, Vec<(String, S
             Ok(Event::End(ref e)) => {
                 match e.name() {
                     b"definedName" => {
-                        let mut defined_name = DefinedName::default();
-                        defined_name.set_name(defined_name_value);
-                        defined_name.set_address(string_value);
-                        defined_name.set_is_local_only(is_local_only);
+                        defined_name.set_formula(string_value);
                         spreadsheet.add_defined_names(defined_name);
-                        
-                        defined_name_value = String::from("");
+
+                        defined_name = DefinedName::default();
                         string_value = String::from("");
-                        is_local_only = false;
                     },
                     _ => (),
                 }
@@ -70,3 +95,193 @@ pub(crate) fn read(dir: &TempDir) -> result::Result<(Spreadsheet, Vec<(String, S
     }
     Ok((spreadsheet,sheets))
 }
+
+
+fn set_security(e:&quick_xml::events::BytesStart<'_>, security:&mut Security) {
+    match get_attribute(e, b"lockStructure") {
+        Some(v) => {security.set_lock_structure(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"lockWindows") {
+        Some(v) => {security.set_lock_windows(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"lockRevision") {
+        Some(v) => {security.set_lock_revision(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"workbookPassword") {
+        Some(v) => security.set_workbook_password_hash(v),
+        None => {}
+    }
+    match get_attribute(e, b"workbookAlgorithmName") {
+        Some(v) => security.set_workbook_algorithm_name(v),
+        None => {}
+    }
+    match get_attribute(e, b"workbookHashValue") {
+        Some(v) => security.set_workbook_hash_value(v),
+        None => {}
+    }
+    match get_attribute(e, b"workbookSaltValue") {
+        Some(v) => security.set_workbook_salt_value(v),
+        None => {}
+    }
+    match get_attribute(e, b"workbookSpinCount") {
+        Some(v) => security.set_workbook_spin_count(v.parse::<u32>().unwrap_or(0)),
+        None => {}
+    }
+    match get_attribute(e, b"revisionsPassword") {
+        Some(v) => security.set_revisions_password_hash(v),
+        None => {}
+    }
+    match get_attribute(e, b"revisionsAlgorithmName") {
+        Some(v) => security.set_revisions_algorithm_name(v),
+        None => {}
+    }
+    match get_attribute(e, b"revisionsHashValue") {
+        Some(v) => security.set_revisions_hash_value(v),
+        None => {}
+    }
+    match get_attribute(e, b"revisionsSaltValue") {
+        Some(v) => security.set_revisions_salt_value(v),
+        None => {}
+    }
+    match get_attribute(e, b"revisionsSpinCount") {
+        Some(v) => security.set_revisions_spin_count(v.parse::<u32>().unwrap_or(0)),
+        None => {}
+    }
+}
+
+fn get_defined_name(e:&quick_xml::events::BytesStart<'_>) -> DefinedName {
+    let mut defined_name = DefinedName::default();
+    match get_attribute(e, b"name") {
+        Some(v) => {defined_name.set_name(condvert_character_reference(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"localSheetId") {
+        Some(v) => match v.parse::<usize>() {
+            Ok(v) => {defined_name.set_local_sheet_id(v);},
+            Err(_) => {}
+        },
+        None => {}
+    }
+    match get_attribute(e, b"hidden") {
+        Some(v) => {defined_name.set_hidden(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"comment") {
+        Some(v) => {defined_name.set_comment(condvert_character_reference(&v));},
+        None => {}
+    }
+    defined_name
+}
+
+fn get_workbook_view(e:&quick_xml::events::BytesStart<'_>) -> WorkbookView {
+    let mut workbook_view = WorkbookView::default();
+    match get_attribute(e, b"xWindow") {
+        Some(v) => {workbook_view.set_x_window(v.parse::<i32>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"yWindow") {
+        Some(v) => {workbook_view.set_y_window(v.parse::<i32>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"windowWidth") {
+        Some(v) => {workbook_view.set_window_width(v.parse::<u32>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"windowHeight") {
+        Some(v) => {workbook_view.set_window_height(v.parse::<u32>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"tabRatio") {
+        Some(v) => {workbook_view.set_tab_ratio(v.parse::<u32>().unwrap_or(600));},
+        None => {}
+    }
+    match get_attribute(e, b"firstSheet") {
+        Some(v) => {workbook_view.set_first_sheet(v.parse::<usize>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"activeTab") {
+        Some(v) => {workbook_view.set_active_tab(v.parse::<usize>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"showHorizontalScroll") {
+        Some(v) => {workbook_view.set_show_horizontal_scroll(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"showVerticalScroll") {
+        Some(v) => {workbook_view.set_show_vertical_scroll(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"showSheetTabs") {
+        Some(v) => {workbook_view.set_show_sheet_tabs(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"minimized") {
+        Some(v) => {workbook_view.set_minimized(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"visibility") {
+        Some(v) => {workbook_view.set_visibility(v);},
+        None => {}
+    }
+    match get_attribute(e, b"autoFilterDateGrouping") {
+        Some(v) => {workbook_view.set_auto_filter_date_grouping(is_true(&v));},
+        None => {}
+    }
+    workbook_view
+}
+
+fn get_calculation(e:&quick_xml::events::BytesStart<'_>) -> Calculation {
+    let mut calculation = Calculation::default();
+    match get_attribute(e, b"calcId") {
+        Some(v) => {calculation.set_calc_id(v.parse::<u32>().unwrap_or(0));},
+        None => {}
+    }
+    match get_attribute(e, b"calcMode") {
+        Some(v) => {calculation.set_calc_mode(v);},
+        None => {}
+    }
+    match get_attribute(e, b"fullCalcOnLoad") {
+        Some(v) => {calculation.set_full_calc_on_load(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"refMode") {
+        Some(v) => {calculation.set_ref_mode(v);},
+        None => {}
+    }
+    match get_attribute(e, b"iterate") {
+        Some(v) => {calculation.set_iterate(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"iterateCount") {
+        Some(v) => {calculation.set_iterate_count(v.parse::<u32>().unwrap_or(100));},
+        None => {}
+    }
+    match get_attribute(e, b"iterateDelta") {
+        Some(v) => {calculation.set_iterate_delta(v.parse::<f64>().unwrap_or(0.001));},
+        None => {}
+    }
+    match get_attribute(e, b"fullPrecision") {
+        Some(v) => {calculation.set_full_precision(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"calcCompleted") {
+        Some(v) => {calculation.set_calc_completed(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"calcOnSave") {
+        Some(v) => {calculation.set_calc_on_save(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"concurrentCalc") {
+        Some(v) => {calculation.set_concurrent_calc(is_true(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"forceFullCalc") {
+        Some(v) => {calculation.set_force_full_calc(is_true(&v));},
+        None => {}
+    }
+    calculation
+}
@@ -3,6 +3,7 @@ use quick_xml::events::{Event};
 use tempdir::TempDir;
 use super::XlsxError;
 use super::driver::*;
+use super::shared_strings::get_rubi;
 
 use ::structs::Worksheet;
 use ::structs::Color;
@@ -13,10 +14,18 @@ use ::structs::Conditional;
 use ::structs::Style;
 use ::structs::PageMargins;
 use ::structs::RichText;
+use ::structs::TextElement;
+use ::structs::CellValue;
+use ::structs::CellErrorType;
 use ::structs::Hyperlink;
 use ::structs::ConditionalSet;
 
 use super::super::helper::coordinate::*;
+use super::super::helper::date::*;
+use super::super::helper::number_format::*;
+
+// serial number of 9999-12-31 plus one day
+const MAX_DATE_SERIAL: f64 = 2958466f64;
 
 pub(crate) fn read(
     dir: &TempDir,
@@ -25,7 +34,8 @@ pub(crate) fn read(
     theme: &Theme,
     shared_strings: &Vec<(String, Option<RichText>)>,
     cell_xfs_vec: &Vec<Style>,
-    dxf_vec: &Vec<Style>
+    dxf_vec: &Vec<Style>,
+    calendar: &str
 ) -> Result<(bool, Option<String>, Option<String>, Vec<(String, String)>), XlsxError> {
     let path = dir.path().join(format!("xl/{}", target));
     let mut reader = Reader::from_file(path)?;
@@ -42,6 +52,7 @@ pub(crate) fn read(
     let mut type_value: String = String::from("");
     let mut string_value: String = String::from("");
     let mut style_index: Option<usize> = None;
+    let mut is_date_style = false;
 
     loop {
         match reader.read_event(&mut buf) {
@@ -102,9 +113,14 @@ pub(crate) fn read(
                                 Err(_) => {},
                             }
                         }
+                        is_date_style = false;
                         match style_index {
                             Some(v) => {
                                 let mut style = cell_xfs_vec.get(v).unwrap().clone();
+                                is_date_style = match style.get_number_format() {
+                                    Some(number_format) => is_date_format(number_format.get_format_code()),
+                                    None => false
+                                };
                                 let coordinate_upper = coordinate.to_uppercase();
                                 let split = index_from_coordinate(&coordinate_upper);
                                 let col = split[0];
@@ -117,6 +133,10 @@ pub(crate) fn read(
                         }
                         style_index = None;
                     },
+                    b"is" => {
+                        let value = get_inline_string(&mut reader, theme);
+                        worksheet.get_cell_mut(&coordinate.to_string()).set_formula_result(value);
+                    },
                     b"conditionalFormatting" => {
                         let mut conditional_set = ConditionalSet::default();
                         let sqref = get_attribute(e, b"sqref").unwrap();
@@ -237,7 +257,7 @@ pub(crate) fn read(
                     },
                     b"hyperlink" => {
                         let (coor, rid, hyperlink) = get_hyperlink(e);
-                        let _ = worksheet.get_cell_mut(&coor.to_string()).set_hyperlink(hyperlink);
+                        let _ = worksheet.add_hyperlink(coor.as_str(), hyperlink);
                         if &rid != "" {
                             hyperlink_vec.push((coor, rid));
                         }
@@ -248,18 +268,35 @@ pub(crate) fn read(
             Ok(Event::Text(e)) => string_value = e.unescape_and_decode(&reader).unwrap(),
             Ok(Event::End(ref e)) => {
                 match e.name() {
-                    b"f" => {worksheet.get_cell_mut(&coordinate.to_string()).set_formula(string_value.clone());},
+                    b"f" => {
+                        worksheet.get_cell_mut(&coordinate.to_string()).set_formula(string_value.clone());
+                        string_value = String::from("");
+                    },
                     b"v" => {
-                        if type_value == "s" {
-                            let index = string_value.parse::<usize>().unwrap();
-                            let (value, rich_text) = shared_strings.get(index).unwrap();
-                            let _ = worksheet.get_cell_mut(&coordinate.to_string()).set_all_param(value, rich_text.clone(), &type_value, &"".into());
-                        } else if type_value == "b" {
-                            let prm = if &string_value == "1" {"TRUE"} else {"FALSE"};
-                            let _ = worksheet.get_cell_mut(&coordinate.to_string()).set_value_and_data_type(prm, &type_value);
-                        } else if type_value == "" || type_value == "n" {
-                            let _ = worksheet.get_cell_mut(&coordinate.to_string()).set_value_crate(&string_value);
+                        let value = match type_value.as_str() {
+                            "s" => {
+                                let index = string_value.parse::<usize>().unwrap();
+                                match shared_strings.get(index).unwrap() {
+                                    (_, Some(rich_text)) => CellValue::RichText(rich_text.clone()),
+                                    (value, None) => CellValue::String(value.clone()),
+                                }
+                            },
+                            "b" => CellValue::Bool(&string_value == "1"),
+                            "e" => match string_value.parse::<CellErrorType>() {
+                                Ok(v) => CellValue::Error(v),
+                                Err(_) => CellValue::Error(CellErrorType::Other(string_value.clone())),
+                            },
+                            "str" => CellValue::String(string_value.clone()),
+                            _ => match string_value.parse::<f64>() {
+                                Ok(v) if is_date_style && v >= 0f64 && v < MAX_DATE_SERIAL => {
+                                    CellValue::DateTime(excel_to_date_time_object_with_calendar(&v, calendar))
+                                },
+                                Ok(v) => CellValue::Number(v),
+                                Err(_) => CellValue::String(string_value.clone()),
+                            },
                         };
+                        worksheet.get_cell_mut(&coordinate.to_string()).set_formula_result(value);
+                        string_value = String::from("");
                     },
                     b"c" => type_value = String::from(""),
                     _ => (),
@@ -275,6 +312,55 @@ pub(crate) fn read(
     Ok((is_active_sheet, drawing, legacy_drawing, hyperlink_vec))
 }
 
+fn get_inline_string(
+    reader:&mut quick_xml::Reader<std::io::BufReader<std::fs::File>>,
+    theme: &Theme
+) -> CellValue
+{
+    let mut buf = Vec::new();
+    let mut value = String::from("");
+    let mut text = String::from("");
+    let mut text_element_vec: Vec<TextElement> = Vec::new();
+
+    // keep leading and trailing spaces of the text
+    reader.trim_text(false);
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                match e.name() {
+                    b"r" => text_element_vec.push(get_text_element(reader, theme)),
+                    b"rPh" => get_rubi(reader),
+                    _ => (),
+                }
+            },
+            Ok(Event::Text(e)) => value = e.unescape_and_decode(&reader).unwrap(),
+            Ok(Event::End(ref e)) => {
+                match e.name() {
+                    b"t" => {
+                        text = value.clone();
+                        value = String::from("");
+                    },
+                    b"is" => break,
+                    _ => (),
+                }
+            },
+            Ok(Event::Eof) => panic!("Error not find {} end element", "is"),
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            _ => (),
+        }
+        buf.clear();
+    }
+    reader.trim_text(true);
+
+    if text_element_vec.len() > 0 {
+        let mut rich_text = RichText::default();
+        rich_text.set_rich_text_elements(text_element_vec);
+        CellValue::RichText(rich_text)
+    } else {
+        CellValue::String(text)
+    }
+}
+
 fn get_conditional_formatting(
     reader:&mut quick_xml::Reader<std::io::BufReader<std::fs::File>>,
     dxf_vec: &Vec<Style>,
@@ -490,12 +576,20 @@ fn get_hyperlink(e:&quick_xml::events::BytesStart<'_>,)->(String, String, Hyperl
     let coordition = get_attribute(e, b"ref").unwrap();
     match get_attribute(e, b"location") {
         Some(v) => {
-            let _ = hyperlink.set_url(v);
+            let _ = hyperlink.set_url(condvert_character_reference(&v));
             let _ = hyperlink.set_location(true);
         },
         None => {
             rid = get_attribute(e, b"r:id").unwrap();
         }
     }
+    match get_attribute(e, b"tooltip") {
+        Some(v) => {let _ = hyperlink.set_tooltip(condvert_character_reference(&v));},
+        None => {}
+    }
+    match get_attribute(e, b"display") {
+        Some(v) => {let _ = hyperlink.set_display(condvert_character_reference(&v));},
+        None => {}
+    }
     (coordition, rid, hyperlink)
 }
@@ -34,7 +34,9 @@ pub(crate) fn read(
                         if &type_value == "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" {
                             for (coordinate, rid) in hyperlink_vec {
                                 if &id_value == rid {
-                                    worksheet.get_cell_mut(coordinate).get_hyperlink_mut().set_url(target_value);
+                                    if let Some(v) = worksheet.get_hyperlink_mut(coordinate.as_str()) {
+                                        v.set_url(condvert_character_reference(&target_value));
+                                    }
                                     break;
                                 }
                             }
@@ -2,6 +2,9 @@
 
 pub mod drawing;
 
+          mod chart_type;
+pub use self::chart_type::*;
+
           mod spreadsheet;
 pub use self::spreadsheet::*;
 
@@ -11,9 +14,18 @@ pub use self::worksheet::*;
           mod properties;
 pub use self::properties::*;
 
+          mod custom_property;
+pub use self::custom_property::*;
+
           mod cell;
 pub use self::cell::*;
 
+          mod cell_value;
+pub use self::cell_value::*;
+
+          mod cell_error_type;
+pub use self::cell_error_type::*;
+
           mod cells;
 pub use self::cells::*;
 
@@ -59,6 +71,9 @@ pub use self::security::*;
           mod calculation;
 pub use self::calculation::*;
 
+          mod workbook_view;
+pub use self::workbook_view::*;
+
           mod style;
 pub use self::style::*;
 
@@ -107,6 +122,15 @@ pub use self::defined_name::*;
           mod comment;
 pub use self::comment::*;
 
+          mod threaded_comment;
+pub use self::threaded_comment::*;
+
+          mod threaded_comment_mention;
+pub use self::threaded_comment_mention::*;
+
+          mod person;
+pub use self::person::*;
+
           mod styles;
 pub use self::styles::*;
 
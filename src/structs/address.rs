@@ -1,4 +1,5 @@
 use super::Range;
+use helper::formula::*;
 
 #[derive(Clone, Default, Debug)]
 pub struct Address {
@@ -54,21 +55,31 @@ impl Address {
     }
 
     pub(crate) fn adjustment_insert_coordinate(&mut self, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
-        if &self.sheet_name == sheet_name {
+        if self.is_sheet(sheet_name) {
             self.range.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
         }
     }
 
     pub(crate) fn adjustment_remove_coordinate(&mut self, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
-        if &self.sheet_name == sheet_name {
+        if self.is_sheet(sheet_name) {
             self.range.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
         }
     }
 
+    pub(crate) fn adjustment_rename_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        if self.is_sheet(old_sheet_name) {
+            self.sheet_name = quote_sheet_name(new_sheet_name);
+        }
+    }
+
     pub(crate) fn is_remove(&self, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize)->bool {
-        if &self.sheet_name == sheet_name {
+        if self.is_sheet(sheet_name) {
            return self.range.is_remove(root_col_num, offset_col_num, root_row_num, offset_row_num);
         }
         false
     }
-}
\ No newline at end of file
+
+    fn is_sheet(&self, sheet_name:&str)->bool {
+        self.sheet_name != "" && unquote_sheet_name(&self.sheet_name) == sheet_name
+    }
+}
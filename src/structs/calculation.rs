@@ -1,7 +1,156 @@
-#[derive(Default, Debug)]
-pub(crate) struct Calculation {
-    pub(crate) calculation_cache: Vec<String>,
-    pub(crate) calculation_cache_enabled: bool,
-    pub(crate) branch_store_key_counter: i32,
-    pub(crate) branch_pruning_enabled : bool,
+/// Calculation properties of the workbook (calcPr).
+#[derive(Debug, Clone)]
+pub struct Calculation {
+    calc_id: u32,
+    calc_mode: String,
+    full_calc_on_load: bool,
+    ref_mode: String,
+    iterate: bool,
+    iterate_count: u32,
+    iterate_delta: f64,
+    full_precision: bool,
+    calc_completed: bool,
+    calc_on_save: bool,
+    concurrent_calc: bool,
+    force_full_calc: bool,
+}
+impl Default for Calculation {
+    fn default() -> Self {
+        Self {
+            calc_id: 122211,
+            calc_mode: Calculation::CALC_MODE_AUTO.to_string(),
+            full_calc_on_load: false,
+            ref_mode: Calculation::REF_MODE_A1.to_string(),
+            iterate: false,
+            iterate_count: 100,
+            iterate_delta: 0.001,
+            full_precision: true,
+            calc_completed: true,
+            calc_on_save: true,
+            concurrent_calc: true,
+            force_full_calc: false,
+        }
+    }
+}
+impl Calculation {
+    pub const CALC_MODE_AUTO: &'static str = "auto";
+    pub const CALC_MODE_AUTO_NO_TABLE: &'static str = "autoNoTable";
+    pub const CALC_MODE_MANUAL: &'static str = "manual";
+
+    pub const REF_MODE_A1: &'static str = "A1";
+    pub const REF_MODE_R1C1: &'static str = "R1C1";
+
+    /// Version of the calculation engine that last calculated the workbook.
+    pub fn get_calc_id(&self)-> &u32 {
+        &self.calc_id
+    }
+
+    pub fn set_calc_id(&mut self, value:u32)-> &mut Calculation {
+        self.calc_id = value;
+        self
+    }
+
+    /// see `CALC_MODE_*`.
+    pub fn get_calc_mode(&self)-> &str {
+        &self.calc_mode
+    }
+
+    pub fn set_calc_mode<S: Into<String>>(&mut self, value:S)-> &mut Calculation {
+        self.calc_mode = value.into();
+        self
+    }
+
+    /// Whether all formulas are calculated when the workbook is opened.
+    pub fn get_full_calc_on_load(&self)-> &bool {
+        &self.full_calc_on_load
+    }
+
+    pub fn set_full_calc_on_load(&mut self, value:bool)-> &mut Calculation {
+        self.full_calc_on_load = value;
+        self
+    }
+
+    /// see `REF_MODE_*`.
+    pub fn get_ref_mode(&self)-> &str {
+        &self.ref_mode
+    }
+
+    pub fn set_ref_mode<S: Into<String>>(&mut self, value:S)-> &mut Calculation {
+        self.ref_mode = value.into();
+        self
+    }
+
+    /// Whether circular references are calculated by iteration.
+    pub fn get_iterate(&self)-> &bool {
+        &self.iterate
+    }
+
+    pub fn set_iterate(&mut self, value:bool)-> &mut Calculation {
+        self.iterate = value;
+        self
+    }
+
+    pub fn get_iterate_count(&self)-> &u32 {
+        &self.iterate_count
+    }
+
+    pub fn set_iterate_count(&mut self, value:u32)-> &mut Calculation {
+        self.iterate_count = value;
+        self
+    }
+
+    pub fn get_iterate_delta(&self)-> &f64 {
+        &self.iterate_delta
+    }
+
+    pub fn set_iterate_delta(&mut self, value:f64)-> &mut Calculation {
+        self.iterate_delta = value;
+        self
+    }
+
+    /// Whether values are calculated with full precision instead of as displayed.
+    pub fn get_full_precision(&self)-> &bool {
+        &self.full_precision
+    }
+
+    pub fn set_full_precision(&mut self, value:bool)-> &mut Calculation {
+        self.full_precision = value;
+        self
+    }
+
+    pub fn get_calc_completed(&self)-> &bool {
+        &self.calc_completed
+    }
+
+    pub fn set_calc_completed(&mut self, value:bool)-> &mut Calculation {
+        self.calc_completed = value;
+        self
+    }
+
+    pub fn get_calc_on_save(&self)-> &bool {
+        &self.calc_on_save
+    }
+
+    pub fn set_calc_on_save(&mut self, value:bool)-> &mut Calculation {
+        self.calc_on_save = value;
+        self
+    }
+
+    pub fn get_concurrent_calc(&self)-> &bool {
+        &self.concurrent_calc
+    }
+
+    pub fn set_concurrent_calc(&mut self, value:bool)-> &mut Calculation {
+        self.concurrent_calc = value;
+        self
+    }
+
+    pub fn get_force_full_calc(&self)-> &bool {
+        &self.force_full_calc
+    }
+
+    pub fn set_force_full_calc(&mut self, value:bool)-> &mut Calculation {
+        self.force_full_calc = value;
+        self
+    }
 }
@@ -1,15 +1,15 @@
 use super::RichText;
+use super::CellValue;
+use super::CellErrorType;
 use super::Hyperlink;
 use super::Coordinate;
 use ::helper::formula::*;
+use chrono::NaiveDateTime;
 
 #[derive(Default, Debug)]
 pub struct Cell {
     coordinate: Coordinate,
-    value: String,
-    rich_text: Option<RichText>,
-    data_type: String,
-    formula: String,
+    value: CellValue,
     hyperlink: Option<Hyperlink>,
 }
 impl Cell {
@@ -49,138 +49,226 @@ impl Cell {
         self
     }
 
-    pub fn get_value(&self)-> &String {
+    pub fn remove_hyperlink(&mut self)-> &mut Cell {
+        self.hyperlink = None;
+        self
+    }
+
+    pub fn get_value(&self)-> String {
+        self.value.to_string()
+    }
+
+    pub fn get_cell_value(&self)-> &CellValue {
         &self.value
     }
 
-    pub fn get_rich_text(&self)-> &Option<RichText> {
-        &self.rich_text
+    pub fn get_cell_value_mut(&mut self)-> &mut CellValue {
+        &mut self.value
     }
 
-    pub fn set_value<S: Into<String>>(&mut self, value:S)-> &mut Cell {
-        let v = value.into();
-        self.data_type = Cell::data_type_for_value(&v).to_string();
-        self.set_value_crate(v);
+    pub fn set_cell_value(&mut self, value:CellValue)-> &mut Cell {
+        self.value = value;
         self
     }
 
-    pub(crate) fn set_value_crate<S: Into<String>>(&mut self, value:S)-> &mut Cell {
+    pub fn get_rich_text(&self)-> Option<&RichText> {
+        self.value.get_rich_text()
+    }
+
+    pub fn get_value_number(&self)-> Option<f64> {
+        self.value.get_number()
+    }
+
+    pub fn get_value_bool(&self)-> Option<bool> {
+        self.value.get_bool()
+    }
+
+    pub fn get_value_string(&self)-> Option<&str> {
+        self.value.get_string()
+    }
+
+    /// Set value.
+    /// # Arguments
+    /// * `value` - Anything that converts into a CellValue. Strings are always stored as strings.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// worksheet.get_cell_mut("A1").set_value("00123");  // string
+    /// worksheet.get_cell_mut("A2").set_value(123);  // number
+    /// worksheet.get_cell_mut("A3").set_value(true);  // bool
+    /// ```
+    pub fn set_value<V: Into<CellValue>>(&mut self, value:V)-> &mut Cell {
         self.value = value.into();
-        self.rich_text = None;
         self
     }
 
-    pub(crate) fn set_all_param<S: Into<String>>(&mut self, value:S, rich_text:Option<RichText>, data_type:S, formula_attributes:S)-> &mut Cell {
-        self.value = value.into();
-        self.rich_text = rich_text;
-        self.data_type = data_type.into();
-        self.formula = formula_attributes.into();
+    pub fn set_value_string<S: Into<String>>(&mut self, value:S)-> &mut Cell {
+        self.value = CellValue::String(value.into());
         self
     }
 
-    pub fn get_data_type(&self)-> &str {
-        &self.data_type
-    }
-
-    pub fn set_value_and_data_type<S: Into<String>>(&mut self, value:S, data_type:S)-> &mut Cell {
-        let v = value.into();
-        let d = data_type.into();
-        match Cell::check_data_type(&v, &d) {
-            Ok(_) => {
-                self.set_value_crate(v);
-                if &d == Cell::TYPE_STRING2 {
-                    self.data_type = Cell::TYPE_STRING.to_string();
-                } else {
-                    self.data_type = d;
-                }
-            },
-            Err(e) => panic!("Error at set_value_and_data_type {:?}", e),
-        }
+    pub fn set_value_number<T: Into<f64>>(&mut self, value:T)-> &mut Cell {
+        self.value = CellValue::Number(value.into());
         self
     }
 
-    pub fn set_data_type<S: Into<String>>(&mut self, value:S)-> &mut Cell {
-        let data_type = value.into();
-        match Cell::check_data_type(&self.value, &data_type) {
-            Ok(_) => self.data_type = data_type.into(),
-            Err(e) => panic!("Error at set_data_type {:?}", e),
-        }
+    pub fn set_value_bool(&mut self, value:bool)-> &mut Cell {
+        self.value = CellValue::Bool(value);
         self
     }
 
-    pub(crate) fn check_data_type<S: Into<String>>(value:S, data_type:S)->Result<(), &'static str> {
-        match data_type.into().as_str() {
-            Cell::TYPE_STRING2 => return Ok(()),
-            Cell::TYPE_STRING => return Ok(()),
-            Cell::TYPE_FORMULA => return Ok(()),
-            Cell::TYPE_NUMERIC => {
-                match &value.into().parse::<f64>() {
-                    Ok(_) => return Ok(()),
-                    Err(_) => return Err("Invalid numeric value for datatype Numeric")
-                }
-            },
-            Cell::TYPE_BOOL => {
-                let check_value = &value.into().to_uppercase();
-                if check_value == "TRUE" || check_value == "FALSE" {
-                    return Ok(());
-                } else {
-                    return Err("Invalid value for datatype Bool")
-                }
-            },
-            Cell::TYPE_NULL => return Ok(()),
-            _ => return Err("Invalid datatype")
+    /// Get the error value, e.g. the cached #DIV/0! of a formula.
+    pub fn get_value_error(&self)-> Option<CellErrorType> {
+        self.value.get_error()
+    }
+
+    pub fn set_value_error(&mut self, value:CellErrorType)-> &mut Cell {
+        self.value = CellValue::Error(value);
+        self
+    }
+
+    pub fn get_date_time(&self)-> Option<NaiveDateTime> {
+        self.value.get_date_time()
+    }
+
+    /// Set date and time.
+    /// The value is written as a serial number of the workbook's date system,
+    /// with a date number format unless the cell style already has one.
+    /// # Arguments
+    /// * `value` - Date and time.
+    /// # Examples
+    /// ```
+    /// extern crate chrono;
+    /// extern crate umya_spreadsheet;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// let date_time = chrono::NaiveDate::from_ymd_opt(2021, 6, 2).unwrap().and_hms_opt(5, 4, 2).unwrap();
+    /// worksheet.get_cell_mut("A1").set_date_time(date_time);
+    /// ```
+    pub fn set_date_time(&mut self, value:NaiveDateTime)-> &mut Cell {
+        self.value = CellValue::DateTime(value);
+        self
+    }
+
+    pub fn set_rich_text(&mut self, value:RichText)-> &mut Cell {
+        self.value = CellValue::RichText(value);
+        self
+    }
+
+    pub fn set_value_empty(&mut self)-> &mut Cell {
+        self.value = CellValue::Empty;
+        self
+    }
+
+    pub fn get_data_type(&self)-> &str {
+        self.value.get_data_type()
+    }
+
+    /// Set value and data type.
+    /// # Arguments
+    /// * `value` - Text of the value. ex) "1"
+    /// * `data_type` - Data type of the value. ex) Cell::TYPE_NUMERIC
+    /// # Return value
+    /// * `Result` - Err if the value does not match the data type.
+    pub fn set_value_and_data_type<S: Into<String>>(&mut self, value:S, data_type:S)-> Result<&mut Cell, &'static str> {
+        self.value = CellValue::from_string_and_data_type(value.into(), &data_type.into())?;
+        Ok(self)
+    }
+
+    /// Convert the current value into a data type.
+    /// # Arguments
+    /// * `value` - Data type. ex) Cell::TYPE_NUMERIC
+    /// # Return value
+    /// * `Result` - Err if the value can not be converted.
+    pub fn set_data_type<S: Into<String>>(&mut self, value:S)-> Result<&mut Cell, &'static str> {
+        let data_type = value.into();
+        if data_type == self.get_data_type() {
+            return Ok(self);
         }
+        self.value = CellValue::from_string_and_data_type(self.value.to_string(), &data_type)?;
+        Ok(self)
     }
 
     pub fn is_formula(&self) -> bool {
-        &self.data_type == Cell::TYPE_FORMULA
+        self.value.is_formula()
     }
 
-    pub fn get_formula(&self)-> &String {
-        &self.formula
+    pub fn get_formula(&self)-> &str {
+        self.value.get_formula()
     }
 
     pub fn set_formula<S: Into<String>>(&mut self, value:S)-> &mut Cell {
-        self.data_type = Cell::TYPE_FORMULA.to_string();
-        self.formula = value.into();
+        let result = match &self.value {
+            CellValue::Formula { result, .. } => *result.clone(),
+            _ => CellValue::Empty,
+        };
+        self.value = CellValue::Formula {
+            formula: value.into(),
+            result: Box::new(result),
+        };
         self
     }
 
-    pub(crate) fn data_type_for_value(value:&str)-> &str {
-        let check_value = value.to_uppercase();
+    /// Set the cached result of the formula.
+    /// If the cell has no formula, the value itself is set.
+    pub fn set_formula_cached_value<V: Into<CellValue>>(&mut self, value:V)-> &mut Cell {
+        self.set_formula_result(value.into())
+    }
 
-        // Match the value against a few data types
-        if check_value == "NULL" {
-            return Cell::TYPE_NULL;
-        }
-        match check_value.parse::<f64>() {
-            Ok(_) => return Cell::TYPE_NUMERIC,
-            Err(_) => {}
-        }
-        if check_value == "TRUE" || check_value == "FALSE" {
-            return Cell::TYPE_BOOL;
+    pub(crate) fn set_formula_result(&mut self, value:CellValue)-> &mut Cell {
+        match &mut self.value {
+            CellValue::Formula { result, .. } => {
+                **result = value;
+            },
+            _ => {
+                self.value = value;
+            }
         }
-        Cell::TYPE_STRING
+        self
     }
-    
+
     pub(crate) fn get_hash_code_by_value(&self)-> String {
-        format!("{:x}", md5::compute(format!("{}{}",
-            &self.value,
-            match &self.rich_text {Some(v) => {v.get_hash_code()}, None => {"None".into()}},
-        )))
+        self.value.get_result().get_hash_code()
     }
 
     pub(crate) fn adjustment_insert_formula_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
         if self.is_formula() {
-            let formula = adjustment_insert_formula_coordinate(&self.formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, self_sheet_name);
-            self.formula = formula;
+            let formula = adjustment_insert_formula_references(self.get_formula(), root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, self_sheet_name);
+            self.set_formula(formula);
         }
     }
 
     pub(crate) fn adjustment_remove_formula_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
         if self.is_formula() {
-            let formula = adjustment_remove_formula_coordinate(&self.formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, self_sheet_name);
-            self.formula = formula;
+            let formula = adjustment_remove_formula_references(self.get_formula(), root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, self_sheet_name);
+            self.set_formula(formula);
+        }
+    }
+
+    pub(crate) fn adjustment_rename_formula_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        if self.is_formula() {
+            let formula = adjustment_rename_formula_sheet(self.get_formula(), old_sheet_name, new_sheet_name);
+            self.set_formula(formula);
+        }
+    }
+
+    pub(crate) fn adjustment_insert_hyperlink_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        if let Some(v) = &mut self.hyperlink {
+            v.adjustment_insert_coordinate(self_sheet_name, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+        }
+    }
+
+    pub(crate) fn adjustment_remove_hyperlink_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        if let Some(v) = &mut self.hyperlink {
+            v.adjustment_remove_coordinate(self_sheet_name, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+        }
+    }
+
+    pub(crate) fn adjustment_rename_hyperlink_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        if let Some(v) = &mut self.hyperlink {
+            v.adjustment_rename_sheet(old_sheet_name, new_sheet_name);
         }
     }
 }
\ No newline at end of file
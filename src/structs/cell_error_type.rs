@@ -0,0 +1,70 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CellErrorType {
+    Null,
+    Div0,
+    Value,
+    Ref,
+    Name,
+    Num,
+    NA,
+    GettingData,
+    Spill,
+    Calc,
+    /// An error literal this library does not know, kept as written in the file.
+    Other(String),
+}
+impl CellErrorType {
+    pub const ERROR_NULL: &'static str = "#NULL!";
+    pub const ERROR_DIV0: &'static str = "#DIV/0!";
+    pub const ERROR_VALUE: &'static str = "#VALUE!";
+    pub const ERROR_REF: &'static str = "#REF!";
+    pub const ERROR_NAME: &'static str = "#NAME?";
+    pub const ERROR_NUM: &'static str = "#NUM!";
+    pub const ERROR_NA: &'static str = "#N/A";
+    pub const ERROR_GETTING_DATA: &'static str = "#GETTING_DATA";
+    pub const ERROR_SPILL: &'static str = "#SPILL!";
+    pub const ERROR_CALC: &'static str = "#CALC!";
+
+    pub fn get_code(&self) -> &str {
+        match self {
+            CellErrorType::Null => CellErrorType::ERROR_NULL,
+            CellErrorType::Div0 => CellErrorType::ERROR_DIV0,
+            CellErrorType::Value => CellErrorType::ERROR_VALUE,
+            CellErrorType::Ref => CellErrorType::ERROR_REF,
+            CellErrorType::Name => CellErrorType::ERROR_NAME,
+            CellErrorType::Num => CellErrorType::ERROR_NUM,
+            CellErrorType::NA => CellErrorType::ERROR_NA,
+            CellErrorType::GettingData => CellErrorType::ERROR_GETTING_DATA,
+            CellErrorType::Spill => CellErrorType::ERROR_SPILL,
+            CellErrorType::Calc => CellErrorType::ERROR_CALC,
+            CellErrorType::Other(v) => v,
+        }
+    }
+}
+impl FromStr for CellErrorType {
+    type Err = &'static str;
+
+    fn from_str(value:&str) -> Result<Self, Self::Err> {
+        match value.to_uppercase().as_str() {
+            CellErrorType::ERROR_NULL => Ok(CellErrorType::Null),
+            CellErrorType::ERROR_DIV0 => Ok(CellErrorType::Div0),
+            CellErrorType::ERROR_VALUE => Ok(CellErrorType::Value),
+            CellErrorType::ERROR_REF => Ok(CellErrorType::Ref),
+            CellErrorType::ERROR_NAME => Ok(CellErrorType::Name),
+            CellErrorType::ERROR_NUM => Ok(CellErrorType::Num),
+            CellErrorType::ERROR_NA => Ok(CellErrorType::NA),
+            CellErrorType::ERROR_GETTING_DATA => Ok(CellErrorType::GettingData),
+            CellErrorType::ERROR_SPILL => Ok(CellErrorType::Spill),
+            CellErrorType::ERROR_CALC => Ok(CellErrorType::Calc),
+            _ => Err("Invalid value for datatype Error"),
+        }
+    }
+}
+impl fmt::Display for CellErrorType {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_code())
+    }
+}
@@ -0,0 +1,263 @@
+use super::Cell;
+use super::CellErrorType;
+use super::RichText;
+use super::TextElement;
+use chrono::NaiveDateTime;
+use std::fmt;
+use helper::date::*;
+
+#[derive(Clone, Debug, Default)]
+pub enum CellValue {
+    #[default]
+    Empty,
+    Number(f64),
+    String(String),
+    RichText(RichText),
+    Bool(bool),
+    Error(CellErrorType),
+    DateTime(NaiveDateTime),
+    Formula {
+        formula: String,
+        result: Box<CellValue>,
+    },
+}
+impl CellValue {
+    /// Build a value from its text and an explicit data type.
+    /// # Arguments
+    /// * `value` - Text of the value. ex) "123"
+    /// * `data_type` - Data type of the value. ex) Cell::TYPE_NUMERIC
+    /// # Return value
+    /// * `Result` - Err if the text is not valid for the data type.
+    pub fn from_string_and_data_type<S: Into<String>>(value:S, data_type:&str) -> Result<CellValue, &'static str> {
+        let value = value.into();
+        match data_type {
+            Cell::TYPE_STRING2 | Cell::TYPE_STRING | Cell::TYPE_INLINE => Ok(CellValue::String(value)),
+            Cell::TYPE_FORMULA => Ok(CellValue::Formula {
+                formula: value,
+                result: Box::new(CellValue::Empty),
+            }),
+            Cell::TYPE_NUMERIC => match value.parse::<f64>() {
+                Ok(v) => Ok(CellValue::Number(v)),
+                Err(_) => Err("Invalid numeric value for datatype Numeric"),
+            },
+            Cell::TYPE_BOOL => match value.to_uppercase().as_str() {
+                "TRUE" | "1" => Ok(CellValue::Bool(true)),
+                "FALSE" | "0" => Ok(CellValue::Bool(false)),
+                _ => Err("Invalid value for datatype Bool"),
+            },
+            Cell::TYPE_ERROR => Ok(CellValue::Error(value.parse::<CellErrorType>()?)),
+            Cell::TYPE_NULL => Ok(CellValue::Empty),
+            _ => Err("Invalid datatype"),
+        }
+    }
+
+    /// Get the data type of the value.
+    /// Formulas report `TYPE_FORMULA`; use `get_result` for the type of the cached result.
+    pub fn get_data_type(&self) -> &'static str {
+        match self {
+            CellValue::Empty => Cell::TYPE_NULL,
+            CellValue::Number(_) => Cell::TYPE_NUMERIC,
+            CellValue::String(_) => Cell::TYPE_STRING,
+            CellValue::RichText(_) => Cell::TYPE_STRING,
+            CellValue::Bool(_) => Cell::TYPE_BOOL,
+            CellValue::Error(_) => Cell::TYPE_ERROR,
+            CellValue::DateTime(_) => Cell::TYPE_NUMERIC,
+            CellValue::Formula { .. } => Cell::TYPE_FORMULA,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, CellValue::Empty)
+    }
+
+    pub fn is_formula(&self) -> bool {
+        matches!(self, CellValue::Formula { .. })
+    }
+
+    /// Get the formula text. Empty if the value is not a formula.
+    pub fn get_formula(&self) -> &str {
+        match self {
+            CellValue::Formula { formula, .. } => formula,
+            _ => "",
+        }
+    }
+
+    /// Get the value itself, or the cached result for a formula.
+    pub fn get_result(&self) -> &CellValue {
+        match self {
+            CellValue::Formula { result, .. } => result,
+            _ => self,
+        }
+    }
+
+    /// Get the number. Date and time values give the serial number of the 1900 date system.
+    pub fn get_number(&self) -> Option<f64> {
+        self.get_number_with_calendar(CALENDAR_WINDOWS_1900)
+    }
+
+    /// Get the number. Date and time values give the serial number of `calendar`.
+    /// # Arguments
+    /// * `calendar` - CALENDAR_WINDOWS_1900 or CALENDAR_MAC_1904.
+    pub fn get_number_with_calendar(&self, calendar:&str) -> Option<f64> {
+        match self.get_result() {
+            CellValue::Number(v) => Some(*v),
+            CellValue::DateTime(v) => Some(date_time_object_to_excel(v, calendar)),
+            _ => None,
+        }
+    }
+
+    /// Same as `to_string`, but date and time values give the serial number of `calendar`.
+    pub fn get_value_with_calendar(&self, calendar:&str) -> String {
+        match self.get_result() {
+            CellValue::DateTime(v) => date_time_object_to_excel(v, calendar).to_string(),
+            v => v.to_string(),
+        }
+    }
+
+    pub fn get_bool(&self) -> Option<bool> {
+        match self.get_result() {
+            CellValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_string(&self) -> Option<&str> {
+        match self.get_result() {
+            CellValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn get_rich_text(&self) -> Option<&RichText> {
+        match self.get_result() {
+            CellValue::RichText(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn get_date_time(&self) -> Option<NaiveDateTime> {
+        match self.get_result() {
+            CellValue::DateTime(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_error(&self) -> Option<CellErrorType> {
+        match self.get_result() {
+            CellValue::Error(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_hash_code(&self) -> String {
+        format!("{:x}", md5::compute(format!("{}{}",
+            self,
+            match self.get_rich_text() {Some(v) => {v.get_hash_code()}, None => {"None".into()}},
+        )))
+    }
+}
+impl PartialEq for CellValue {
+    fn eq(&self, other:&CellValue) -> bool {
+        match (self, other) {
+            (CellValue::Empty, CellValue::Empty) => true,
+            (CellValue::Number(a), CellValue::Number(b)) => a == b,
+            (CellValue::String(a), CellValue::String(b)) => a == b,
+            (CellValue::RichText(a), CellValue::RichText(b)) => a.get_hash_code() == b.get_hash_code(),
+            (CellValue::Bool(a), CellValue::Bool(b)) => a == b,
+            (CellValue::Error(a), CellValue::Error(b)) => a == b,
+            (CellValue::DateTime(a), CellValue::DateTime(b)) => a == b,
+            (CellValue::Formula { formula: a, result: ra }, CellValue::Formula { formula: b, result: rb }) => a == b && ra == rb,
+            _ => false,
+        }
+    }
+}
+impl fmt::Display for CellValue {
+    /// Text of the value as it is stored in the file.
+    /// Bools give "TRUE" or "FALSE".
+    /// Formulas give the text of their cached result.
+    /// Date and time values give the serial number of the 1900 date system; see `get_value_with_calendar`.
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Number(v) => write!(f, "{}", v),
+            CellValue::String(v) => write!(f, "{}", v),
+            CellValue::RichText(v) => write!(f, "{}", v.get_text()),
+            CellValue::Bool(v) => write!(f, "{}", if *v {"TRUE"} else {"FALSE"}),
+            CellValue::Error(v) => write!(f, "{}", v),
+            CellValue::DateTime(v) => write!(f, "{}", date_time_object_to_excel(v, CALENDAR_WINDOWS_1900)),
+            CellValue::Formula { result, .. } => write!(f, "{}", result),
+        }
+    }
+}
+impl From<String> for CellValue {
+    fn from(value:String) -> Self {
+        CellValue::String(value)
+    }
+}
+impl<'a> From<&'a String> for CellValue {
+    fn from(value:&'a String) -> Self {
+        CellValue::String(value.clone())
+    }
+}
+impl<'a> From<&'a str> for CellValue {
+    fn from(value:&'a str) -> Self {
+        CellValue::String(value.to_string())
+    }
+}
+impl From<f64> for CellValue {
+    fn from(value:f64) -> Self {
+        CellValue::Number(value)
+    }
+}
+impl From<f32> for CellValue {
+    fn from(value:f32) -> Self {
+        CellValue::Number(value as f64)
+    }
+}
+impl From<i32> for CellValue {
+    fn from(value:i32) -> Self {
+        CellValue::Number(value as f64)
+    }
+}
+impl From<i64> for CellValue {
+    fn from(value:i64) -> Self {
+        CellValue::Number(value as f64)
+    }
+}
+impl From<u32> for CellValue {
+    fn from(value:u32) -> Self {
+        CellValue::Number(value as f64)
+    }
+}
+impl From<usize> for CellValue {
+    fn from(value:usize) -> Self {
+        CellValue::Number(value as f64)
+    }
+}
+impl From<bool> for CellValue {
+    fn from(value:bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+impl From<CellErrorType> for CellValue {
+    fn from(value:CellErrorType) -> Self {
+        CellValue::Error(value)
+    }
+}
+impl From<RichText> for CellValue {
+    fn from(value:RichText) -> Self {
+        CellValue::RichText(value)
+    }
+}
+impl From<TextElement> for CellValue {
+    fn from(value:TextElement) -> Self {
+        let mut rich_text = RichText::default();
+        rich_text.add_rich_text_elements(value);
+        CellValue::RichText(rich_text)
+    }
+}
+impl From<NaiveDateTime> for CellValue {
+    fn from(value:NaiveDateTime) -> Self {
+        CellValue::DateTime(value)
+    }
+}
@@ -0,0 +1,36 @@
+/// Kind of chart built by `Worksheet::add_chart`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChartType {
+    Line,
+    Pie,
+    Doughnut,
+    Scatter,
+    /// Horizontal bars.
+    Bar,
+    /// Vertical bars.
+    Column,
+    Bar3D,
+    Column3D,
+    Radar,
+    Bubble,
+    /// High-low-close stock chart. Series are added in that order.
+    Stock,
+    Surface,
+    Line3D,
+    Area,
+    Area3D,
+    Pie3D,
+    /// Pie chart with a second pie for the smallest slices.
+    PieOfPie,
+    /// Pie chart with a stacked bar for the smallest slices.
+    BarOfPie,
+}
+impl ChartType {
+    pub(crate) fn has_axes(&self) -> bool {
+        !matches!(self, ChartType::Pie | ChartType::Doughnut | ChartType::Pie3D | ChartType::PieOfPie | ChartType::BarOfPie)
+    }
+
+    pub(crate) fn is_3d(&self) -> bool {
+        matches!(self, ChartType::Bar3D | ChartType::Column3D | ChartType::Surface | ChartType::Line3D | ChartType::Area3D | ChartType::Pie3D)
+    }
+}
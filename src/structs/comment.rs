@@ -2,8 +2,10 @@ use super::RichText;
 use super::Color;
 use super::Coordinate;
 use super::Anchor;
+use super::TextElement;
+use helper::coordinate::*;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct Comment {
     coordinate: Coordinate,
     author: String,
@@ -16,8 +18,34 @@ pub struct Comment {
     fill_color: Color,
     alignment: String,
     anchor: Anchor,
+    shadow: bool,
+    auto_fit: bool,
+}
+impl Default for Comment {
+    fn default() -> Self {
+        Self {
+            coordinate: Coordinate::default(),
+            author: String::from(""),
+            text: RichText::default(),
+            width: String::from(""),
+            margin_left: String::from(""),
+            margin_top: String::from(""),
+            visible: false,
+            height: String::from(""),
+            fill_color: Color::default(),
+            alignment: String::from(""),
+            anchor: Anchor::default(),
+            shadow: true,
+            auto_fit: false,
+        }
+    }
 }
 impl Comment {
+    pub const ALIGNMENT_LEFT: &'static str = "left";
+    pub const ALIGNMENT_CENTER: &'static str = "center";
+    pub const ALIGNMENT_RIGHT: &'static str = "right";
+    pub const ALIGNMENT_JUSTIFY: &'static str = "justify";
+
     pub fn get_coordinate(&self)-> &Coordinate {
         &self.coordinate
     }
@@ -42,6 +70,17 @@ impl Comment {
         self.text = value;
     }
 
+    /// Set the text without formatting.
+    /// # Arguments
+    /// * `value` - text of the note. ex) "Checked."
+    pub fn set_text_string<S: Into<String>>(&mut self, value:S) {
+        let mut text_element = TextElement::default();
+        text_element.set_text(value);
+        let mut rich_text = RichText::default();
+        rich_text.add_rich_text_elements(text_element);
+        self.text = rich_text;
+    }
+
     pub fn get_width(&self)->&str {
         &self.width
     }
@@ -90,6 +129,79 @@ impl Comment {
         self.fill_color = value;
     }
 
+    /// Width of the note box in points.
+    pub fn get_width_pt(&self)->f64 {
+        length_to_pt(&self.width)
+    }
+
+    pub fn set_width_pt(&mut self, value:f64) {
+        self.width = format!("{}pt", value);
+    }
+
+    /// Width of the note box in EMU.
+    pub fn get_width_emu(&self)->usize {
+        pt_to_emu(&self.get_width_pt())
+    }
+
+    pub fn set_width_emu(&mut self, value:usize) {
+        self.set_width_pt(emu_to_pt(&value));
+    }
+
+    /// Height of the note box in points.
+    pub fn get_height_pt(&self)->f64 {
+        length_to_pt(&self.height)
+    }
+
+    pub fn set_height_pt(&mut self, value:f64) {
+        self.height = format!("{}pt", value);
+    }
+
+    /// Height of the note box in EMU.
+    pub fn get_height_emu(&self)->usize {
+        pt_to_emu(&self.get_height_pt())
+    }
+
+    pub fn set_height_emu(&mut self, value:usize) {
+        self.set_height_pt(emu_to_pt(&value));
+    }
+
+    /// Left margin of the note box in points.
+    pub fn get_margin_left_pt(&self)->f64 {
+        length_to_pt(&self.margin_left)
+    }
+
+    pub fn set_margin_left_pt(&mut self, value:f64) {
+        self.margin_left = format!("{}pt", value);
+    }
+
+    /// Top margin of the note box in points.
+    pub fn get_margin_top_pt(&self)->f64 {
+        length_to_pt(&self.margin_top)
+    }
+
+    pub fn set_margin_top_pt(&mut self, value:f64) {
+        self.margin_top = format!("{}pt", value);
+    }
+
+    /// Whether the note box has a shadow.
+    pub fn get_shadow(&self)->&bool {
+        &self.shadow
+    }
+
+    pub fn set_shadow(&mut self, value:bool) {
+        self.shadow = value;
+    }
+
+    /// Whether the note box is resized to fit its text.
+    pub fn get_auto_fit(&self)->&bool {
+        &self.auto_fit
+    }
+
+    pub fn set_auto_fit(&mut self, value:bool) {
+        self.auto_fit = value;
+    }
+
+    /// Horizontal alignment of the text. see `ALIGNMENT_*`.
     pub fn get_alignment(&self)->&str {
         &self.alignment
     }
@@ -110,6 +222,45 @@ impl Comment {
         self.anchor = value;
     }
 
+    /// Place the note box over a range of cells.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "C2:E6"
+    pub fn set_anchor_range<S: Into<String>>(&mut self, range:S) {
+        let range_upper = range.into().to_uppercase();
+        let coordinates: Vec<&str> = range_upper.split(':').collect();
+        let from = index_from_coordinate(coordinates[0]);
+        let to = index_from_coordinate(coordinates.last().unwrap().to_string());
+        let mut anchor = Anchor::default();
+        anchor.set_left_column(from[0] - 1);
+        anchor.set_top_row(from[1] - 1);
+        anchor.set_right_column(to[0]);
+        anchor.set_bottom_row(to[1]);
+        self.anchor = anchor;
+    }
+
+    /// Make a hidden note with the size and position Excel uses by default.
+    pub(crate) fn new_note(col_num:&usize, row_num:&usize) -> Comment {
+        let mut comment = Comment::default();
+        comment.get_coordinate_mut().set_col_num(*col_num).set_row_num(*row_num);
+        comment.set_width_pt(108f64);
+        comment.set_height_pt(59.25);
+        comment.set_margin_left_pt(59.25);
+        comment.set_margin_top_pt(1.5);
+        let mut fill_color = Color::default();
+        let _ = fill_color.set_argb("ffffe1");
+        comment.set_fill_color(fill_color);
+        let top_row = if row_num > &1 { row_num - 2 } else { 0 };
+        comment.anchor.set_left_column(*col_num);
+        comment.anchor.set_left_offset(15);
+        comment.anchor.set_top_row(top_row);
+        comment.anchor.set_top_offset(10);
+        comment.anchor.set_right_column(col_num + 2);
+        comment.anchor.set_right_offset(15);
+        comment.anchor.set_bottom_row(top_row + 4);
+        comment.anchor.set_bottom_offset(4);
+        comment
+    }
+
     pub(crate) fn adjustment_insert_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
         let org_col_num = self.coordinate.get_col_num().clone();
         let org_row_num = self.coordinate.get_row_num().clone();
@@ -134,3 +285,23 @@ impl Comment {
         }
     }
 }
+
+fn length_to_pt(value:&str) -> f64 {
+    let value = value.trim();
+    let units: [(&str, f64); 6] = [("pt", 1f64), ("px", 0.75), ("in", 72f64), ("cm", 72f64 / 2.54), ("mm", 72f64 / 25.4), ("pc", 12f64)];
+    for (unit, rate) in &units {
+        if value.ends_with(unit) {
+            return value.trim_end_matches(unit).parse::<f64>().unwrap_or(0f64) * rate;
+        }
+    }
+    // VML lengths without a unit are pixels
+    value.parse::<f64>().unwrap_or(0f64) * 0.75
+}
+
+fn pt_to_emu(value:&f64) -> usize {
+    (value * 12700f64).round() as usize
+}
+
+fn emu_to_pt(value:&usize) -> f64 {
+    *value as f64 / 12700f64
+}
@@ -1,5 +1,6 @@
 use super::Style;
 use super::Color;
+use helper::formula::*;
 
 #[derive(Clone, Debug)]
 pub struct Conditional {
@@ -174,4 +175,34 @@ impl Conditional {
         self.style = Some(value);
         self
     }
+
+    pub(crate) fn adjustment_insert_formula_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.update_formulas(|formula| {
+            adjustment_insert_formula_references(formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, self_sheet_name)
+        });
+    }
+
+    pub(crate) fn adjustment_remove_formula_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.update_formulas(|formula| {
+            adjustment_remove_formula_references(formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, self_sheet_name)
+        });
+    }
+
+    pub(crate) fn adjustment_rename_formula_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        self.update_formulas(|formula| {
+            adjustment_rename_formula_sheet(formula, old_sheet_name, new_sheet_name)
+        });
+    }
+
+    // Rewrite the condition formulas and the values of the data bar, color scale and icon set thresholds.
+    fn update_formulas<F: Fn(&str)-> String>(&mut self, f:F) {
+        for condition in &mut self.condition {
+            *condition = f(condition);
+        }
+        for (_, value, _) in &mut self.cfvo_collection {
+            if let Some(v) = value {
+                *v = f(v);
+            }
+        }
+    }
 }
@@ -0,0 +1,75 @@
+/// Value of a custom document property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomPropertyValue {
+    Text(String),
+    Integer(i64),
+    Number(f64),
+    Bool(bool),
+    /// Date time in W3CDTF. ex) "2021-04-01T00:00:00Z"
+    Date(String),
+}
+impl From<&str> for CustomPropertyValue {
+    fn from(value: &str) -> Self {
+        CustomPropertyValue::Text(value.to_string())
+    }
+}
+impl From<String> for CustomPropertyValue {
+    fn from(value: String) -> Self {
+        CustomPropertyValue::Text(value)
+    }
+}
+impl From<i32> for CustomPropertyValue {
+    fn from(value: i32) -> Self {
+        CustomPropertyValue::Integer(value as i64)
+    }
+}
+impl From<i64> for CustomPropertyValue {
+    fn from(value: i64) -> Self {
+        CustomPropertyValue::Integer(value)
+    }
+}
+impl From<f64> for CustomPropertyValue {
+    fn from(value: f64) -> Self {
+        CustomPropertyValue::Number(value)
+    }
+}
+impl From<bool> for CustomPropertyValue {
+    fn from(value: bool) -> Self {
+        CustomPropertyValue::Bool(value)
+    }
+}
+
+/// A custom document property (docProps/custom.xml).
+#[derive(Debug, Clone)]
+pub struct CustomProperty {
+    name: String,
+    value: CustomPropertyValue,
+}
+impl CustomProperty {
+    pub(crate) const FMTID: &'static str = "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}";
+
+    pub fn new<S: Into<String>, V: Into<CustomPropertyValue>>(name:S, value:V) -> CustomProperty {
+        CustomProperty {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn get_name(&self)-> &str {
+        &self.name
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value:S)-> &mut CustomProperty {
+        self.name = value.into();
+        self
+    }
+
+    pub fn get_value(&self)-> &CustomPropertyValue {
+        &self.value
+    }
+
+    pub fn set_value<V: Into<CustomPropertyValue>>(&mut self, value:V)-> &mut CustomProperty {
+        self.value = value.into();
+        self
+    }
+}
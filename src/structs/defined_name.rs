@@ -1,43 +1,117 @@
-use super::Address;
+use helper::formula::*;
 
-#[derive(Default, Debug)]
+/// A defined name (definedName). Holds a reference, a constant or a formula.
+#[derive(Clone, Default, Debug)]
 pub struct DefinedName {
     name: String,
-    address: Address,
-    is_local_only: bool,
+    formula: String,
+    local_sheet_id: Option<usize>,
+    hidden: bool,
+    comment: String,
 }
 impl DefinedName {
+    /// Create a defined name.
+    /// # Arguments
+    /// * `name` - name. ex) "TaxRate"
+    /// * `formula` - reference, constant or formula. ex) "Sheet1!$A$1:$B$2", "0.19", "=OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)"
+    pub fn new<S: Into<String>, F: Into<String>>(name:S, formula:F)-> DefinedName {
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(name);
+        defined_name.set_formula(formula);
+        defined_name
+    }
+
     pub fn get_name(&self)-> &str {
         &self.name
     }
 
-    pub(crate) fn set_name<S: Into<String>>(&mut self, value:S) {
+    pub fn set_name<S: Into<String>>(&mut self, value:S)-> &mut DefinedName {
         self.name = value.into();
+        self
+    }
+
+    /// Get the formula without the leading "=".
+    pub fn get_formula(&self)-> &str {
+        &self.formula
+    }
+
+    pub fn set_formula<S: Into<String>>(&mut self, value:S)-> &mut DefinedName {
+        let value = value.into();
+        self.formula = match value.starts_with("=") {
+            true => value[1..].to_string(),
+            false => value,
+        };
+        self
     }
 
+    /// Get the formula. Same as `get_formula`, kept for names holding a reference.
     pub fn get_address(&self)-> String {
-        self.address.get_address()
+        self.formula.clone()
     }
 
-    pub(crate) fn get_address_obj(&self)-> &Address {
-        &self.address
+    /// Whether the name refers to a single range. ex) "Sheet1!$A$1:$B$2"
+    pub fn is_reference(&self)-> bool {
+        is_reference_formula(&self.formula)
     }
 
-    pub(crate) fn get_address_obj_mut(&mut self)-> &mut Address {
-        &mut self.address
+    /// Get the index of the sheet the name is scoped to. `None` for workbook scope.
+    pub fn get_local_sheet_id(&self)-> &Option<usize> {
+        &self.local_sheet_id
     }
 
-    pub(crate) fn set_address<S: Into<String>>(&mut self, value:S) {
-        let mut address = Address::default();
-        address.set_address(value);
-        self.address = address;
+    pub fn set_local_sheet_id(&mut self, value:usize)-> &mut DefinedName {
+        self.local_sheet_id = Some(value);
+        self
+    }
+
+    pub fn remove_local_sheet_id(&mut self)-> &mut DefinedName {
+        self.local_sheet_id = None;
+        self
     }
 
     pub fn get_is_local_only(&self)-> &bool {
-        &self.is_local_only
+        match self.local_sheet_id {
+            Some(_) => &true,
+            None => &false,
+        }
+    }
+
+    pub fn get_hidden(&self)-> &bool {
+        &self.hidden
+    }
+
+    pub fn set_hidden(&mut self, value:bool)-> &mut DefinedName {
+        self.hidden = value;
+        self
     }
 
-    pub(crate) fn set_is_local_only(&mut self, value:bool) {
-        self.is_local_only = value;
+    pub fn get_comment(&self)-> &str {
+        &self.comment
     }
-}
\ No newline at end of file
+
+    pub fn set_comment<S: Into<String>>(&mut self, value:S)-> &mut DefinedName {
+        self.comment = value.into();
+        self
+    }
+
+    pub(crate) fn adjustment_insert_coordinate(&mut self, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.formula = adjustment_insert_formula_references(&self.formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, "");
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(&mut self, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.formula = adjustment_remove_formula_references(&self.formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, "");
+    }
+
+    pub(crate) fn adjustment_rename_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        self.formula = adjustment_rename_formula_sheet(&self.formula, old_sheet_name, new_sheet_name);
+    }
+
+    /// Whether a name referring to a single range loses it all on the removal.
+    pub(crate) fn is_remove(&self, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize)-> bool {
+        if !self.is_reference() {
+            return false;
+        }
+        let formula = adjustment_remove_formula_references(&self.formula, root_col_num, offset_col_num, root_row_num, offset_row_num, sheet_name, "");
+        formula.ends_with("#REF!")
+    }
+}
@@ -30,6 +30,9 @@ pub use self::style_matrix_reference_type::*;
 mod outline;
 pub use self::outline::*;
 
+mod head_end;
+pub use self::head_end::*;
+
 mod tail_end;
 pub use self::tail_end::*;
 
@@ -104,3 +107,36 @@ pub use self::east_asian_font::*;
 
 mod no_fill;
 pub use self::no_fill::*;
+
+mod shape_guide_list;
+pub use self::shape_guide_list::*;
+
+mod geometry_rectangle;
+pub use self::geometry_rectangle::*;
+
+mod path_point;
+pub use self::path_point::*;
+
+mod path_command;
+pub use self::path_command::*;
+
+mod path;
+pub use self::path::*;
+
+mod path_list;
+pub use self::path_list::*;
+
+mod adjust_handle;
+pub use self::adjust_handle::*;
+
+mod adjust_handle_list;
+pub use self::adjust_handle_list::*;
+
+mod connection_site;
+pub use self::connection_site::*;
+
+mod connection_site_list;
+pub use self::connection_site_list::*;
+
+mod custom_geometry;
+pub use self::custom_geometry::*;
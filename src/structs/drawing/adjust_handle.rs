@@ -0,0 +1,76 @@
+use super::PathPoint;
+use writer::driver::*;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// One adjust handle of a custom geometry, kept in document order.
+/// Attributes not given in the file are empty.
+#[derive(Clone, Debug)]
+pub enum AdjustHandle {
+    /// a:ahXY
+    XY {
+        x_reference: String,
+        min_x: String,
+        max_x: String,
+        y_reference: String,
+        min_y: String,
+        max_y: String,
+        position: PathPoint,
+    },
+    /// a:ahPolar
+    Polar {
+        radius_reference: String,
+        min_radius: String,
+        max_radius: String,
+        angle_reference: String,
+        min_angle: String,
+        max_angle: String,
+        position: PathPoint,
+    },
+}
+impl AdjustHandle {
+    pub fn get_position(&self) -> &PathPoint {
+        match self {
+            AdjustHandle::XY { position, .. } => position,
+            AdjustHandle::Polar { position, .. } => position,
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let (tag_name, attributes, position) = match self {
+            AdjustHandle::XY { x_reference, min_x, max_x, y_reference, min_y, max_y, position } => {
+                ("a:ahXY", vec![
+                    ("gdRefX", x_reference),
+                    ("minX", min_x),
+                    ("maxX", max_x),
+                    ("gdRefY", y_reference),
+                    ("minY", min_y),
+                    ("maxY", max_y),
+                ], position)
+            },
+            AdjustHandle::Polar { radius_reference, min_radius, max_radius, angle_reference, min_angle, max_angle, position } => {
+                ("a:ahPolar", vec![
+                    ("gdRefR", radius_reference),
+                    ("minR", min_radius),
+                    ("maxR", max_radius),
+                    ("gdRefAng", angle_reference),
+                    ("minAng", min_angle),
+                    ("maxAng", max_angle),
+                ], position)
+            },
+        };
+        let attributes: Vec<(&str, &str)> = attributes.into_iter()
+            .filter(|(_, v)| v.as_str() != "")
+            .map(|(k, v)| (k, v.as_str()))
+            .collect();
+        write_start_tag(writer, tag_name, attributes, false);
+
+        // a:pos
+        write_start_tag(writer, "a:pos", vec![
+            ("x", position.get_x()),
+            ("y", position.get_y()),
+        ], true);
+
+        write_end_tag(writer, tag_name);
+    }
+}
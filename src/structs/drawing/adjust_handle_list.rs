@@ -0,0 +1,117 @@
+// a:ahLst
+use super::AdjustHandle;
+use super::PathPoint;
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct AdjustHandleList {
+    adjust_handle_collection: Vec<AdjustHandle>,
+}
+impl AdjustHandleList {
+    pub fn get_adjust_handle_collection(&self) -> &Vec<AdjustHandle> {
+        &self.adjust_handle_collection
+    }
+
+    pub fn get_adjust_handle_collection_mut(&mut self) -> &mut Vec<AdjustHandle> {
+        &mut self.adjust_handle_collection
+    }
+
+    pub fn add_adjust_handle_collection(&mut self, value:AdjustHandle) {
+        self.adjust_handle_collection.push(value);
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        let mut attributes: Vec<String> = Vec::new();
+        let mut position = PathPoint::default();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"a:ahXY" => {
+                            attributes = get_attributes(e, &[b"gdRefX", b"minX", b"maxX", b"gdRefY", b"minY", b"maxY"]);
+                            position = PathPoint::default();
+                        },
+                        b"a:ahPolar" => {
+                            attributes = get_attributes(e, &[b"gdRefR", b"minR", b"maxR", b"gdRefAng", b"minAng", b"maxAng"]);
+                            position = PathPoint::default();
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:pos" => {
+                            position.set_attributes(e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:ahXY" => {
+                            let mut values = attributes.drain(..);
+                            &mut self.add_adjust_handle_collection(AdjustHandle::XY {
+                                x_reference: values.next().unwrap_or_default(),
+                                min_x: values.next().unwrap_or_default(),
+                                max_x: values.next().unwrap_or_default(),
+                                y_reference: values.next().unwrap_or_default(),
+                                min_y: values.next().unwrap_or_default(),
+                                max_y: values.next().unwrap_or_default(),
+                                position: position.clone(),
+                            });
+                        },
+                        b"a:ahPolar" => {
+                            let mut values = attributes.drain(..);
+                            &mut self.add_adjust_handle_collection(AdjustHandle::Polar {
+                                radius_reference: values.next().unwrap_or_default(),
+                                min_radius: values.next().unwrap_or_default(),
+                                max_radius: values.next().unwrap_or_default(),
+                                angle_reference: values.next().unwrap_or_default(),
+                                min_angle: values.next().unwrap_or_default(),
+                                max_angle: values.next().unwrap_or_default(),
+                                position: position.clone(),
+                            });
+                        },
+                        b"a:ahLst" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:ahLst"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:ahLst
+        if self.adjust_handle_collection.is_empty() {
+            write_start_tag(writer, "a:ahLst", vec![], true);
+            return;
+        }
+        write_start_tag(writer, "a:ahLst", vec![], false);
+
+        // a:ahXY, a:ahPolar
+        for adjust_handle in &self.adjust_handle_collection {
+            adjust_handle.write_to(writer);
+        }
+
+        write_end_tag(writer, "a:ahLst");
+    }
+}
+
+// Values of the attributes in the order of `keys`, empty when not given.
+fn get_attributes(e:&BytesStart, keys:&[&[u8]])-> Vec<String> {
+    keys.iter().map(|key| get_attribute(e, key).unwrap_or_default()).collect()
+}
@@ -9,10 +9,12 @@ use tempdir::TempDir;
 use reader::xlsx::drawing_rels;
 use reader::xlsx::media;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Blip {
     image_name: String,
     image_data: Option<Vec<u8>>,
+    svg_image_name: String,
+    svg_image_data: Option<Vec<u8>>,
     cstate: String,
 }
 impl Blip {
@@ -32,6 +34,27 @@ impl Blip {
         self.image_data = Some(value);
     }
 
+    /// File name of the SVG image shown by Excel 2016 or later. The image of `get_image_name` is the PNG fallback.
+    pub fn get_svg_image_name(&self) -> &String {
+        &self.svg_image_name
+    }
+
+    pub fn set_svg_image_name<S: Into<String>>(&mut self, value:S) {
+        self.svg_image_name = value.into();
+    }
+
+    pub fn get_svg_image_data(&self) -> &Option<Vec<u8>> {
+        &self.svg_image_data
+    }
+
+    pub fn set_svg_image_data(&mut self, value:Vec<u8>) {
+        self.svg_image_data = Some(value);
+    }
+
+    pub fn has_svg_image(&self) -> bool {
+        self.svg_image_data.is_some()
+    }
+
     pub fn get_cstate(&self) -> &String {
         &self.cstate
     }
@@ -47,24 +70,9 @@ impl Blip {
         extension_lower
     }
 
-    pub(crate) fn is_jpeg(&self)-> bool
-    {
-        self.get_extension() == "jpeg"
-    }
-
-    pub(crate) fn is_jpg(&self)-> bool
-    {
-        self.get_extension() == "jpg"
-    }
-
-    pub(crate) fn is_png(&self)-> bool
-    {
-        self.get_extension() == "png"
-    }
-
-    pub(crate) fn is_tiff(&self)-> bool
-    {
-        self.get_extension() == "tiff"
+    pub(crate) fn get_svg_extension(&self)->String {
+        let v: Vec<&str> = self.svg_image_name.split('.').collect();
+        v.last().unwrap().to_lowercase()
     }
 
     pub(crate) fn set_attributes(
@@ -80,20 +88,40 @@ impl Blip {
         }
         
         let picture_id = get_attribute(e, b"r:embed").unwrap();
-        let drawing_rel = drawing_rels::read(dir, target).unwrap();
-        for (drawing_id, _, drawing_target) in &drawing_rel {
-            if &picture_id == drawing_id {
-                let v: Vec<&str> = drawing_target.split('/').collect();
-                let image_name = v.last().unwrap().clone();
+        match read_image(&picture_id, dir, target) {
+            Some((image_name, image_data)) => {
                 &mut self.set_image_name(image_name);
-                &mut self.set_image_data(media::read(&dir, &drawing_target).unwrap());
-            }
+                &mut self.set_image_data(image_data);
+            },
+            None => {}
+        }
+    }
+
+    // asvg:svgBlip
+    pub(crate) fn set_svg_attributes(
+        &mut self,
+        e:&BytesStart,
+        dir: &TempDir,
+        target: &str,
+    ) {
+        let picture_id = match get_attribute(e, b"r:embed") {
+            Some(v) => v,
+            None => return,
+        };
+        match read_image(&picture_id, dir, target) {
+            Some((image_name, image_data)) => {
+                &mut self.set_svg_image_name(image_name);
+                &mut self.set_svg_image_data(image_data);
+            },
+            None => {}
         }
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &i32) {
+    // The PNG takes `r_id` and the SVG the next one.
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32) {
         // a:blip
         let r_id_str = format!("rId{}", r_id);
+        *r_id += 1;
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         attributes.push(("xmlns:r" , "http://schemas.openxmlformats.org/officeDocument/2006/relationships"));
         attributes.push(("r:embed", r_id_str.as_str()));
@@ -116,7 +144,36 @@ impl Blip {
             ("val", "0"),
         ], true);
         write_end_tag(writer, "a:ext");
+
+        if self.has_svg_image() {
+            // a:ext
+            write_start_tag(writer, "a:ext", vec![
+                ("uri", "{96DAC541-7B7A-43D3-8B79-37D633B846F1}"),
+            ], false);
+
+            // asvg:svgBlip
+            let svg_r_id_str = format!("rId{}", r_id);
+            *r_id += 1;
+            write_start_tag(writer, "asvg:svgBlip", vec![
+                ("xmlns:asvg", "http://schemas.microsoft.com/office/drawing/2016/SVG/main"),
+                ("r:embed", svg_r_id_str.as_str()),
+            ], true);
+            write_end_tag(writer, "a:ext");
+        }
         write_end_tag(writer, "a:extLst");
         write_end_tag(writer, "a:blip");
     }
 }
+
+// File name and content of the image of a relationship of the drawing.
+fn read_image(picture_id:&str, dir:&TempDir, target:&str)-> Option<(String, Vec<u8>)> {
+    let drawing_rel = drawing_rels::read(dir, target).unwrap();
+    for (drawing_id, _, drawing_target) in &drawing_rel {
+        if picture_id == drawing_id {
+            let v: Vec<&str> = drawing_target.split('/').collect();
+            let image_name = v.last().unwrap().to_string();
+            return Some((image_name, media::read(&dir, &drawing_target).unwrap()));
+        }
+    }
+    None
+}
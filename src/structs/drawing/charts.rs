@@ -16,6 +16,9 @@ pub use self::legend::*;
           mod plot_area;
 pub use self::plot_area::*;
 
+          mod chart_group;
+pub use self::chart_group::*;
+
           mod title;
 pub use self::title::*;
 
@@ -324,3 +327,204 @@ pub use self::side_wall::*;
 
           mod back_wall;
 pub use self::back_wall::*;
+
+          mod series_text;
+pub use self::series_text::*;
+
+          mod radar_style;
+pub use self::radar_style::*;
+
+          mod radar_chart;
+pub use self::radar_chart::*;
+
+          mod bubble_3d;
+pub use self::bubble_3d::*;
+
+          mod bubble_scale;
+pub use self::bubble_scale::*;
+
+          mod show_negative_bubbles;
+pub use self::show_negative_bubbles::*;
+
+          mod size_represents;
+pub use self::size_represents::*;
+
+          mod bubble_size;
+pub use self::bubble_size::*;
+
+          mod bubble_chart;
+pub use self::bubble_chart::*;
+
+          mod high_low_lines;
+pub use self::high_low_lines::*;
+
+          mod drop_lines;
+pub use self::drop_lines::*;
+
+          mod up_bars;
+pub use self::up_bars::*;
+
+          mod down_bars;
+pub use self::down_bars::*;
+
+          mod up_down_bars;
+pub use self::up_down_bars::*;
+
+          mod stock_chart;
+pub use self::stock_chart::*;
+
+          mod wireframe;
+pub use self::wireframe::*;
+
+          mod surface_chart;
+pub use self::surface_chart::*;
+
+          mod surface_3d_chart;
+pub use self::surface_3d_chart::*;
+
+          mod series_axis;
+pub use self::series_axis::*;
+
+          mod explosion;
+pub use self::explosion::*;
+
+          mod gap_depth;
+pub use self::gap_depth::*;
+
+          mod area_chart;
+pub use self::area_chart::*;
+
+          mod area_3d_chart;
+pub use self::area_3d_chart::*;
+
+          mod line_3d_chart;
+pub use self::line_3d_chart::*;
+
+          mod pie_3d_chart;
+pub use self::pie_3d_chart::*;
+
+          mod of_pie_type;
+pub use self::of_pie_type::*;
+
+          mod split_type;
+pub use self::split_type::*;
+
+          mod split_position;
+pub use self::split_position::*;
+
+          mod second_pie_size;
+pub use self::second_pie_size::*;
+
+          mod second_pie_point;
+pub use self::second_pie_point::*;
+
+          mod custom_split;
+pub use self::custom_split::*;
+
+          mod series_lines;
+pub use self::series_lines::*;
+
+          mod of_pie_chart;
+pub use self::of_pie_chart::*;
+
+          mod base_time_unit;
+pub use self::base_time_unit::*;
+
+          mod major_unit;
+pub use self::major_unit::*;
+
+          mod major_time_unit;
+pub use self::major_time_unit::*;
+
+          mod minor_unit;
+pub use self::minor_unit::*;
+
+          mod minor_time_unit;
+pub use self::minor_time_unit::*;
+
+          mod date_axis;
+pub use self::date_axis::*;
+
+          mod log_base;
+pub use self::log_base::*;
+
+          mod max_axis_value;
+pub use self::max_axis_value::*;
+
+          mod min_axis_value;
+pub use self::min_axis_value::*;
+
+          mod built_in_unit;
+pub use self::built_in_unit::*;
+
+          mod custom_display_unit;
+pub use self::custom_display_unit::*;
+
+          mod display_units_label;
+pub use self::display_units_label::*;
+
+          mod display_units;
+pub use self::display_units::*;
+
+          mod tick_label_skip;
+pub use self::tick_label_skip::*;
+
+          mod tick_mark_skip;
+pub use self::tick_mark_skip::*;
+
+          mod data_point;
+pub use self::data_point::*;
+
+          mod trendline_name;
+pub use self::trendline_name::*;
+
+          mod trendline_type;
+pub use self::trendline_type::*;
+
+          mod polynomial_order;
+pub use self::polynomial_order::*;
+
+          mod period;
+pub use self::period::*;
+
+          mod forward;
+pub use self::forward::*;
+
+          mod backward;
+pub use self::backward::*;
+
+          mod intercept;
+pub use self::intercept::*;
+
+          mod display_r_squared_value;
+pub use self::display_r_squared_value::*;
+
+          mod display_equation;
+pub use self::display_equation::*;
+
+          mod trendline;
+pub use self::trendline::*;
+
+          mod error_direction;
+pub use self::error_direction::*;
+
+          mod error_bar_type;
+pub use self::error_bar_type::*;
+
+          mod error_bar_value_type;
+pub use self::error_bar_value_type::*;
+
+          mod no_end_cap;
+pub use self::no_end_cap::*;
+
+          mod plus;
+pub use self::plus::*;
+
+          mod minus;
+pub use self::minus::*;
+
+          mod error_bar_value;
+pub use self::error_bar_value::*;
+
+          mod error_bars;
+pub use self::error_bars::*;
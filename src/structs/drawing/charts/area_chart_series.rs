@@ -1,32 +1,51 @@
 // c:ser
 use super::Index;
 use super::Order;
+use super::SeriesText;
 use super::InvertIfNegative;
 use super::Marker;
+use super::Explosion;
+use super::DataPoint;
+use super::DataLabels;
+use super::Trendline;
+use super::ErrorBars;
 use super::ShapeProperties;
 use super::CategoryAxisData;
 use super::Values;
 use super::XValues;
 use super::YValues;
+use super::BubbleSize;
+use super::Bubble3D;
 use super::Smooth;
 use super::Formula;
+use super::super::super::Spreadsheet;
+use super::super::SolidFill;
+use super::super::RgbColorModelHex;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct AreaChartSeries {
     index: Index,
     order: Order,
+    series_text: Option<SeriesText>,
     invert_if_negative: Option<InvertIfNegative>,
     marker: Option<Marker>,
+    explosion: Option<Explosion>,
+    data_point: Vec<DataPoint>,
+    data_labels: Option<DataLabels>,
+    trendline: Vec<Trendline>,
+    error_bars: Vec<ErrorBars>,
     shape_properties: Option<ShapeProperties>,
     category_axis_data: Option<CategoryAxisData>,
     values: Option<Values>,
     x_values: Option<XValues>,
     y_values: Option<YValues>,
+    bubble_size: Option<BubbleSize>,
+    bubble_3d: Option<Bubble3D>,
     smooth: Option<Smooth>,
 }
 impl AreaChartSeries {
@@ -34,7 +53,7 @@ impl AreaChartSeries {
         &self.index
     }
 
-    pub fn get_index_mut(&mut self)-> &Index {
+    pub fn get_index_mut(&mut self)-> &mut Index {
         &mut self.index
     }
 
@@ -47,7 +66,7 @@ impl AreaChartSeries {
         &self.order
     }
 
-    pub fn get_order_mut(&mut self)-> &Order {
+    pub fn get_order_mut(&mut self)-> &mut Order {
         &mut self.order
     }
 
@@ -56,11 +75,24 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_series_text(&self)-> &Option<SeriesText> {
+        &self.series_text
+    }
+
+    pub fn get_series_text_mut(&mut self)-> &mut Option<SeriesText> {
+        &mut self.series_text
+    }
+
+    pub fn set_series_text(&mut self, value:SeriesText)-> &mut AreaChartSeries {
+        self.series_text = Some(value);
+        self
+    }
+
     pub fn get_invert_if_negative(&self)-> &Option<InvertIfNegative> {
         &self.invert_if_negative
     }
 
-    pub fn get_invert_if_negative_mut(&mut self)-> &Option<InvertIfNegative> {
+    pub fn get_invert_if_negative_mut(&mut self)-> &mut Option<InvertIfNegative> {
         &mut self.invert_if_negative
     }
 
@@ -73,7 +105,7 @@ impl AreaChartSeries {
         &self.marker
     }
 
-    pub fn get_marker_mut(&mut self)-> &Option<Marker> {
+    pub fn get_marker_mut(&mut self)-> &mut Option<Marker> {
         &mut self.marker
     }
 
@@ -82,11 +114,24 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_explosion(&self)-> &Option<Explosion> {
+        &self.explosion
+    }
+
+    pub fn get_explosion_mut(&mut self)-> &mut Option<Explosion> {
+        &mut self.explosion
+    }
+
+    pub fn set_explosion(&mut self, value:Explosion)-> &mut AreaChartSeries {
+        self.explosion = Some(value);
+        self
+    }
+
     pub fn get_shape_properties(&self)-> &Option<ShapeProperties> {
         &self.shape_properties
     }
 
-    pub fn get_shape_properties_mut(&mut self)-> &Option<ShapeProperties> {
+    pub fn get_shape_properties_mut(&mut self)-> &mut Option<ShapeProperties> {
         &mut self.shape_properties
     }
 
@@ -95,11 +140,113 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_data_point(&self)-> &Vec<DataPoint> {
+        &self.data_point
+    }
+
+    pub fn get_data_point_mut(&mut self)-> &mut Vec<DataPoint> {
+        &mut self.data_point
+    }
+
+    pub fn set_data_point(&mut self, value:Vec<DataPoint>)-> &mut AreaChartSeries {
+        self.data_point = value;
+        self
+    }
+
+    pub fn add_data_point(&mut self, value:DataPoint)-> &mut AreaChartSeries {
+        self.data_point.push(value);
+        self
+    }
+
+    pub fn get_data_labels(&self)-> &Option<DataLabels> {
+        &self.data_labels
+    }
+
+    pub fn get_data_labels_mut(&mut self)-> &mut Option<DataLabels> {
+        &mut self.data_labels
+    }
+
+    pub fn set_data_labels(&mut self, value:DataLabels)-> &mut AreaChartSeries {
+        self.data_labels = Some(value);
+        self
+    }
+
+    pub fn get_trendline(&self)-> &Vec<Trendline> {
+        &self.trendline
+    }
+
+    pub fn get_trendline_mut(&mut self)-> &mut Vec<Trendline> {
+        &mut self.trendline
+    }
+
+    pub fn set_trendline(&mut self, value:Vec<Trendline>)-> &mut AreaChartSeries {
+        self.trendline = value;
+        self
+    }
+
+    pub fn add_trendline(&mut self, value:Trendline)-> &mut AreaChartSeries {
+        self.trendline.push(value);
+        self
+    }
+
+    pub fn get_error_bars(&self)-> &Vec<ErrorBars> {
+        &self.error_bars
+    }
+
+    pub fn get_error_bars_mut(&mut self)-> &mut Vec<ErrorBars> {
+        &mut self.error_bars
+    }
+
+    pub fn set_error_bars(&mut self, value:Vec<ErrorBars>)-> &mut AreaChartSeries {
+        self.error_bars = value;
+        self
+    }
+
+    pub fn add_error_bars(&mut self, value:ErrorBars)-> &mut AreaChartSeries {
+        self.error_bars.push(value);
+        self
+    }
+
+    /// Fill one data point of the series with a color.
+    /// # Arguments
+    /// * `point_index` - index of the point in the series, from 0.
+    /// * `color_rgb` - color. ex) "FF0000"
+    pub fn set_data_point_color<S: Into<String>>(&mut self, point_index:usize, color_rgb:S)-> &mut AreaChartSeries {
+        let index = point_index.to_string();
+        if !self.data_point.iter().any(|v| v.get_index().get_val() == index) {
+            let mut data_point = DataPoint::default();
+            data_point.get_index_mut().set_val(index.clone());
+            if self.invert_if_negative.is_some() {
+                let mut invert_if_negative = InvertIfNegative::default();
+                invert_if_negative.set_val("0");
+                data_point.set_invert_if_negative(invert_if_negative);
+            }
+            self.data_point.push(data_point);
+            self.data_point.sort_by_key(|v| v.get_index().get_val().parse::<usize>().unwrap_or(0));
+        }
+        let mut rgb_color_model_hex = RgbColorModelHex::default();
+        rgb_color_model_hex.set_val(color_rgb);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+        let data_point = self.data_point.iter_mut().find(|v| v.get_index().get_val() == index).unwrap();
+        match data_point.get_shape_properties_mut() {
+            Some(v) => {
+                v.set_solid_fill(solid_fill);
+            },
+            None => {
+                let mut shape_properties = ShapeProperties::default();
+                shape_properties.set_solid_fill(solid_fill);
+                data_point.set_shape_properties(shape_properties);
+            }
+        }
+        self
+    }
+
     pub fn get_category_axis_data(&self)-> &Option<CategoryAxisData> {
         &self.category_axis_data
     }
 
-    pub fn get_category_axis_data_mut(&mut self)-> &Option<CategoryAxisData> {
+    pub fn get_category_axis_data_mut(&mut self)-> &mut Option<CategoryAxisData> {
         &mut self.category_axis_data
     }
 
@@ -147,11 +294,37 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_bubble_size(&self)-> &Option<BubbleSize> {
+        &self.bubble_size
+    }
+
+    pub fn get_bubble_size_mut(&mut self)-> &mut Option<BubbleSize> {
+        &mut self.bubble_size
+    }
+
+    pub fn set_bubble_size(&mut self, value:BubbleSize)-> &mut AreaChartSeries {
+        self.bubble_size = Some(value);
+        self
+    }
+
+    pub fn get_bubble_3d(&self)-> &Option<Bubble3D> {
+        &self.bubble_3d
+    }
+
+    pub fn get_bubble_3d_mut(&mut self)-> &mut Option<Bubble3D> {
+        &mut self.bubble_3d
+    }
+
+    pub fn set_bubble_3d(&mut self, value:Bubble3D)-> &mut AreaChartSeries {
+        self.bubble_3d = Some(value);
+        self
+    }
+
     pub fn get_smooth(&self)-> &Option<Smooth> {
         &self.smooth
     }
 
-    pub fn get_smooth_mut(&mut self)-> &Option<Smooth> {
+    pub fn get_smooth_mut(&mut self)-> &mut Option<Smooth> {
         &mut self.smooth
     }
 
@@ -163,6 +336,12 @@ impl AreaChartSeries {
     pub fn get_formula_mut(&mut self)-> Vec<&mut Formula> {
         let mut result:Vec<&mut Formula> = Vec::default();
 
+        match &mut self.series_text {
+            Some(v) => {
+                result.push(v.get_string_reference_mut().get_formula_mut());
+            }
+            None => {}
+        }
         match &mut self.category_axis_data {
             Some(v) => {
                 result.push(v.get_string_reference_mut().get_formula_mut());
@@ -187,9 +366,48 @@ impl AreaChartSeries {
             }
             None => {}
         }
+        match &mut self.bubble_size {
+            Some(v) => {
+                result.push(v.get_number_reference_mut().get_formula_mut());
+            }
+            None => {}
+        }
+        for error_bars in &mut self.error_bars {
+            result.extend(error_bars.get_formula_mut());
+        }
         result
     }
 
+    pub(crate) fn update_caches(&mut self, spreadsheet:&Spreadsheet, is_overwrite:&bool) {
+        match &mut self.series_text {
+            Some(v) => v.get_string_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        match &mut self.category_axis_data {
+            Some(v) => v.get_string_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        match &mut self.values {
+            Some(v) => v.get_number_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        match &mut self.x_values {
+            Some(v) => v.get_number_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        match &mut self.y_values {
+            Some(v) => v.get_number_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        match &mut self.bubble_size {
+            Some(v) => v.get_number_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        for error_bars in &mut self.error_bars {
+            error_bars.update_caches(spreadsheet, is_overwrite);
+        }
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -200,6 +418,11 @@ impl AreaChartSeries {
             match reader.read_event(&mut buf) {
                 Ok(Event::Start(ref e)) => {
                     match e.name() {
+                        b"c:tx" => {
+                            let mut obj = SeriesText::default();
+                            obj.set_attributes(reader, e);
+                            self.set_series_text(obj);
+                        },
                         b"c:marker" => {
                             let mut obj = Marker::default();
                             obj.set_attributes(reader, e, false);
@@ -210,6 +433,26 @@ impl AreaChartSeries {
                             obj.set_attributes(reader, e);
                             self.set_shape_properties(obj);
                         },
+                        b"c:dPt" => {
+                            let mut obj = DataPoint::default();
+                            obj.set_attributes(reader, e);
+                            self.add_data_point(obj);
+                        },
+                        b"c:dLbls" => {
+                            let mut obj = DataLabels::default();
+                            obj.set_attributes(reader, e);
+                            self.set_data_labels(obj);
+                        },
+                        b"c:trendline" => {
+                            let mut obj = Trendline::default();
+                            obj.set_attributes(reader, e);
+                            self.add_trendline(obj);
+                        },
+                        b"c:errBars" => {
+                            let mut obj = ErrorBars::default();
+                            obj.set_attributes(reader, e);
+                            self.add_error_bars(obj);
+                        },
                         b"c:cat" => {
                             let mut obj = CategoryAxisData::default();
                             obj.set_attributes(reader, e);
@@ -230,6 +473,11 @@ impl AreaChartSeries {
                             obj.set_attributes(reader, e);
                             self.set_y_values(obj);
                         },
+                        b"c:bubbleSize" => {
+                            let mut obj = BubbleSize::default();
+                            obj.set_attributes(reader, e);
+                            self.set_bubble_size(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -251,6 +499,16 @@ impl AreaChartSeries {
                             obj.set_attributes(reader, e);
                             self.set_smooth(obj);
                         },
+                        b"c:explosion" => {
+                            let mut obj = Explosion::default();
+                            obj.set_attributes(reader, e);
+                            self.set_explosion(obj);
+                        },
+                        b"c:bubble3D" => {
+                            let mut obj = Bubble3D::default();
+                            obj.set_attributes(reader, e);
+                            self.set_bubble_3d(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -278,6 +536,18 @@ impl AreaChartSeries {
         // c:order
         &self.order.write_to(writer);
 
+        // c:tx
+        match &self.series_text {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:spPr
+        match &self.shape_properties {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
         // c:invertIfNegative
         match &self.invert_if_negative {
             Some(v) => {v.write_to(writer);},
@@ -290,12 +560,33 @@ impl AreaChartSeries {
             None => {}
         }
 
-        // c:spPr
-        match &self.shape_properties {
+        // c:explosion
+        match &self.explosion {
             Some(v) => {v.write_to(writer);},
             None => {}
         }
 
+        // c:dPt
+        for v in &self.data_point {
+            v.write_to(writer);
+        }
+
+        // c:dLbls
+        match &self.data_labels {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:trendline
+        for v in &self.trendline {
+            v.write_to(writer);
+        }
+
+        // c:errBars
+        for v in &self.error_bars {
+            v.write_to(writer);
+        }
+
         // c:cat
         match &self.category_axis_data {
             Some(v) => {v.write_to(writer);},
@@ -320,6 +611,18 @@ impl AreaChartSeries {
             None => {}
         }
 
+        // c:bubbleSize
+        match &self.bubble_size {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:bubble3D
+        match &self.bubble_3d {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
         // c:smooth
         match &self.smooth {
             Some(v) => {v.write_to(writer);},
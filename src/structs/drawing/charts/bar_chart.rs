@@ -13,7 +13,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct BarChart {
     bar_direction: BarDirection,
     grouping: Grouping,
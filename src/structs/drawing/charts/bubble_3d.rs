@@ -0,0 +1,37 @@
+// c:bubble3D
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Bubble3D {
+    val: String,
+}
+impl Bubble3D {
+    pub fn get_val(&self)-> &str {
+        &self.val
+    }
+
+    pub fn set_val<S: Into<String>>(&mut self, value:S)-> &mut Bubble3D {
+        self.val = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart
+    ) {
+        self.val = get_attribute(e, b"val").unwrap();
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:bubble3D
+        write_start_tag(writer, "c:bubble3D", vec![
+            ("val", &self.val),
+        ], true);
+    }
+}
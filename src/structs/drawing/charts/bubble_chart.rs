@@ -0,0 +1,261 @@
+// c:bubbleChart
+use super::VaryColors;
+use super::AreaChartSeries;
+use super::DataLabels;
+use super::Bubble3D;
+use super::BubbleScale;
+use super::ShowNegativeBubbles;
+use super::SizeRepresents;
+use super::AxisId;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct BubbleChart {
+    vary_colors: VaryColors,
+    area_chart_series: Vec<AreaChartSeries>,
+    data_labels: Option<DataLabels>,
+    bubble_3d: Option<Bubble3D>,
+    bubble_scale: Option<BubbleScale>,
+    show_negative_bubbles: Option<ShowNegativeBubbles>,
+    size_represents: Option<SizeRepresents>,
+    axis_id: Vec<AxisId>,
+}
+impl BubbleChart {
+    pub fn get_vary_colors(&self)-> &VaryColors {
+        &self.vary_colors
+    }
+
+    pub fn get_vary_colors_mut(&mut self)-> &mut VaryColors {
+        &mut self.vary_colors
+    }
+
+    pub fn set_vary_colors(&mut self, value:VaryColors)-> &mut BubbleChart {
+        self.vary_colors = value;
+        self
+    }
+
+    pub fn get_area_chart_series(&self)-> &Vec<AreaChartSeries> {
+        &self.area_chart_series
+    }
+
+    pub fn get_area_chart_series_mut(&mut self)-> &mut Vec<AreaChartSeries> {
+        &mut self.area_chart_series
+    }
+
+    pub fn set_area_chart_series(&mut self, value:Vec<AreaChartSeries>)-> &mut BubbleChart {
+        self.area_chart_series = value;
+        self
+    }
+
+    pub fn add_area_chart_series(&mut self, value:AreaChartSeries)-> &mut BubbleChart {
+        self.area_chart_series.push(value);
+        self
+    }
+
+    pub fn get_data_labels(&self)-> &Option<DataLabels> {
+        &self.data_labels
+    }
+
+    pub fn get_data_labels_mut(&mut self)-> &mut Option<DataLabels> {
+        &mut self.data_labels
+    }
+
+    pub fn set_data_labels(&mut self, value:DataLabels)-> &mut BubbleChart {
+        self.data_labels = Some(value);
+        self
+    }
+
+    pub fn get_bubble_3d(&self)-> &Option<Bubble3D> {
+        &self.bubble_3d
+    }
+
+    pub fn get_bubble_3d_mut(&mut self)-> &mut Option<Bubble3D> {
+        &mut self.bubble_3d
+    }
+
+    pub fn set_bubble_3d(&mut self, value:Bubble3D)-> &mut BubbleChart {
+        self.bubble_3d = Some(value);
+        self
+    }
+
+    pub fn get_bubble_scale(&self)-> &Option<BubbleScale> {
+        &self.bubble_scale
+    }
+
+    pub fn get_bubble_scale_mut(&mut self)-> &mut Option<BubbleScale> {
+        &mut self.bubble_scale
+    }
+
+    pub fn set_bubble_scale(&mut self, value:BubbleScale)-> &mut BubbleChart {
+        self.bubble_scale = Some(value);
+        self
+    }
+
+    pub fn get_show_negative_bubbles(&self)-> &Option<ShowNegativeBubbles> {
+        &self.show_negative_bubbles
+    }
+
+    pub fn get_show_negative_bubbles_mut(&mut self)-> &mut Option<ShowNegativeBubbles> {
+        &mut self.show_negative_bubbles
+    }
+
+    pub fn set_show_negative_bubbles(&mut self, value:ShowNegativeBubbles)-> &mut BubbleChart {
+        self.show_negative_bubbles = Some(value);
+        self
+    }
+
+    pub fn get_size_represents(&self)-> &Option<SizeRepresents> {
+        &self.size_represents
+    }
+
+    pub fn get_size_represents_mut(&mut self)-> &mut Option<SizeRepresents> {
+        &mut self.size_represents
+    }
+
+    pub fn set_size_represents(&mut self, value:SizeRepresents)-> &mut BubbleChart {
+        self.size_represents = Some(value);
+        self
+    }
+
+    pub fn get_axis_id(&self)-> &Vec<AxisId> {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self)-> &mut Vec<AxisId> {
+        &mut self.axis_id
+    }
+
+    pub fn set_axis_id(&mut self, value:Vec<AxisId>)-> &mut BubbleChart {
+        self.axis_id = value;
+        self
+    }
+
+    pub fn add_axis_id(&mut self, value:AxisId)-> &mut BubbleChart {
+        self.axis_id.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:ser" => {
+                            let mut obj = AreaChartSeries::default();
+                            obj.set_attributes(reader, e);
+                            self.add_area_chart_series(obj);
+                        },
+                        b"c:dLbls" => {
+                            let mut obj = DataLabels::default();
+                            obj.set_attributes(reader, e);
+                            self.set_data_labels(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:varyColors" => {
+                            self.vary_colors.set_attributes(reader, e);
+                        },
+                        b"c:bubble3D" => {
+                            let mut obj = Bubble3D::default();
+                            obj.set_attributes(reader, e);
+                            self.set_bubble_3d(obj);
+                        },
+                        b"c:bubbleScale" => {
+                            let mut obj = BubbleScale::default();
+                            obj.set_attributes(reader, e);
+                            self.set_bubble_scale(obj);
+                        },
+                        b"c:showNegBubbles" => {
+                            let mut obj = ShowNegativeBubbles::default();
+                            obj.set_attributes(reader, e);
+                            self.set_show_negative_bubbles(obj);
+                        },
+                        b"c:sizeRepresents" => {
+                            let mut obj = SizeRepresents::default();
+                            obj.set_attributes(reader, e);
+                            self.set_size_represents(obj);
+                        },
+                        b"c:axId" => {
+                            let mut obj = AxisId::default();
+                            obj.set_attributes(reader, e);
+                            self.add_axis_id(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:bubbleChart" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:bubbleChart"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:bubbleChart
+        write_start_tag(writer, "c:bubbleChart", vec![], false);
+
+        // c:varyColors
+        &self.vary_colors.write_to(writer);
+
+        // c:ser
+        for v in &self.area_chart_series {
+            v.write_to(writer);
+        }
+
+        // c:dLbls
+        match &self.data_labels {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:bubble3D
+        match &self.bubble_3d {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:bubbleScale
+        match &self.bubble_scale {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:showNegBubbles
+        match &self.show_negative_bubbles {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:sizeRepresents
+        match &self.size_represents {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:axId
+        for v in &self.axis_id {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:bubbleChart");
+    }
+}
@@ -3,6 +3,7 @@ use super::AxisId;
 use super::Scaling;
 use super::Delete;
 use super::AxisPosition;
+use super::Title;
 use super::MajorTickMark;
 use super::MinorTickMark;
 use super::TickLabelPosition;
@@ -18,12 +19,13 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct CategoryAxis {
     axis_id: AxisId,
     scaling: Scaling,
     delete: Delete,
     axis_position: AxisPosition,
+    title: Option<Title>,
     major_tick_mark: MajorTickMark,
     minor_tick_mark: MinorTickMark,
     tick_label_position: TickLabelPosition,
@@ -39,7 +41,7 @@ impl CategoryAxis {
         &self.axis_id
     }
 
-    pub fn get_axis_id_mut(&mut self)-> &AxisId {
+    pub fn get_axis_id_mut(&mut self)-> &mut AxisId {
         &mut self.axis_id
     }
 
@@ -52,7 +54,7 @@ impl CategoryAxis {
         &self.scaling
     }
 
-    pub fn get_scaling_mut(&mut self)-> &Scaling {
+    pub fn get_scaling_mut(&mut self)-> &mut Scaling {
         &mut self.scaling
     }
 
@@ -65,7 +67,7 @@ impl CategoryAxis {
         &self.delete
     }
 
-    pub fn get_delete_mut(&mut self)-> &Delete {
+    pub fn get_delete_mut(&mut self)-> &mut Delete {
         &mut self.delete
     }
 
@@ -78,7 +80,7 @@ impl CategoryAxis {
         &self.axis_position
     }
 
-    pub fn get_axis_position_mut(&mut self)-> &AxisPosition {
+    pub fn get_axis_position_mut(&mut self)-> &mut AxisPosition {
         &mut self.axis_position
     }
 
@@ -87,11 +89,24 @@ impl CategoryAxis {
         self
     }
 
+    pub fn get_title(&self)-> &Option<Title> {
+        &self.title
+    }
+
+    pub fn get_title_mut(&mut self)-> &mut Option<Title> {
+        &mut self.title
+    }
+
+    pub fn set_title(&mut self, value:Title)-> &mut CategoryAxis {
+        self.title = Some(value);
+        self
+    }
+
     pub fn get_major_tick_mark(&self)-> &MajorTickMark {
         &self.major_tick_mark
     }
 
-    pub fn get_major_tick_mark_mut(&mut self)-> &MajorTickMark {
+    pub fn get_major_tick_mark_mut(&mut self)-> &mut MajorTickMark {
         &mut self.major_tick_mark
     }
 
@@ -104,7 +119,7 @@ impl CategoryAxis {
         &self.minor_tick_mark
     }
 
-    pub fn get_minor_tick_mark_mut(&mut self)-> &MinorTickMark {
+    pub fn get_minor_tick_mark_mut(&mut self)-> &mut MinorTickMark {
         &mut self.minor_tick_mark
     }
 
@@ -117,7 +132,7 @@ impl CategoryAxis {
         &self.tick_label_position
     }
 
-    pub fn get_tick_label_position_mut(&mut self)-> &TickLabelPosition {
+    pub fn get_tick_label_position_mut(&mut self)-> &mut TickLabelPosition {
         &mut self.tick_label_position
     }
 
@@ -130,7 +145,7 @@ impl CategoryAxis {
         &self.crossing_axis
     }
 
-    pub fn get_tick_crossing_axis_mut(&mut self)-> &CrossingAxis {
+    pub fn get_tick_crossing_axis_mut(&mut self)-> &mut CrossingAxis {
         &mut self.crossing_axis
     }
 
@@ -143,7 +158,7 @@ impl CategoryAxis {
         &self.crosses
     }
 
-    pub fn get_crosses_mut(&mut self)-> &Crosses {
+    pub fn get_crosses_mut(&mut self)-> &mut Crosses {
         &mut self.crosses
     }
 
@@ -156,7 +171,7 @@ impl CategoryAxis {
         &self.auto_labeled
     }
 
-    pub fn get_auto_labeled_mut(&mut self)-> &AutoLabeled {
+    pub fn get_auto_labeled_mut(&mut self)-> &mut AutoLabeled {
         &mut self.auto_labeled
     }
 
@@ -169,7 +184,7 @@ impl CategoryAxis {
         &self.label_alignment
     }
 
-    pub fn get_label_alignment_mut(&mut self)-> &LabelAlignment {
+    pub fn get_label_alignment_mut(&mut self)-> &mut LabelAlignment {
         &mut self.label_alignment
     }
 
@@ -182,7 +197,7 @@ impl CategoryAxis {
         &self.label_offset
     }
 
-    pub fn get_label_offset_mut(&mut self)-> &LabelOffset {
+    pub fn get_label_offset_mut(&mut self)-> &mut LabelOffset {
         &mut self.label_offset
     }
 
@@ -195,7 +210,7 @@ impl CategoryAxis {
         &self.no_multi_level_labels
     }
 
-    pub fn get_no_multi_level_labels_mut(&mut self)-> &NoMultiLevelLabels {
+    pub fn get_no_multi_level_labels_mut(&mut self)-> &mut NoMultiLevelLabels {
         &mut self.no_multi_level_labels
     }
 
@@ -217,6 +232,11 @@ impl CategoryAxis {
                         b"c:scaling" => {
                             self.scaling.set_attributes(reader, e);
                         },
+                        b"c:title" => {
+                            let mut obj = Title::default();
+                            obj.set_attributes(reader, e);
+                            self.set_title(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -291,6 +311,12 @@ impl CategoryAxis {
         // c:axPos
         &self.axis_position.write_to(writer);
 
+        // c:title
+        match &self.title {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
         // c:majorTickMark
         &self.major_tick_mark.write_to(writer);
 
@@ -6,17 +6,22 @@ use super::SideWall;
 use super::BackWall;
 use super::PlotArea;
 use super::Legend;
+use super::LegendPosition;
 use super::PlotVisibleOnly;
 use super::DisplayBlanksAs;
 use super::ShowDataLabelsOverMaximum;
 use super::Formula;
+use super::RotateX;
+use super::RotateY;
+use super::RightAngleAxes;
+use super::super::super::ChartType;
 use writer::driver::*;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use quick_xml::Reader;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Chart {
     title: Option<Title>,
     auto_title_deleted: AutoTitleDeleted,
@@ -178,6 +183,45 @@ impl Chart {
         self
     }
 
+    pub(crate) fn new_chart(chart_type:&ChartType)-> Chart {
+        let mut chart = Chart::default();
+        chart.auto_title_deleted.set_val("1");
+        if chart_type.is_3d() {
+            let (rotate_x_val, rotate_y_val, right_angle_axes_val) = match chart_type {
+                ChartType::Pie3D => ("30", "0", "0"),
+                ChartType::Bar3D | ChartType::Column3D => ("15", "20", "1"),
+                _ => ("15", "20", "0"),
+            };
+            let mut view_3d = View3D::default();
+            let mut rotate_x = RotateX::default();
+            rotate_x.set_val(rotate_x_val);
+            view_3d.set_rotate_x(rotate_x);
+            let mut rotate_y = RotateY::default();
+            rotate_y.set_val(rotate_y_val);
+            view_3d.set_rotate_y(rotate_y);
+            let mut right_angle_axes = RightAngleAxes::default();
+            right_angle_axes.set_val(right_angle_axes_val);
+            view_3d.set_right_angle_axes(right_angle_axes);
+            chart.set_view_3d(view_3d);
+        }
+        chart.plot_area = PlotArea::new_chart(chart_type);
+        chart.legend.get_legend_position_mut().set_val(LegendPosition::POSITION_RIGHT);
+        chart.legend.get_overlay_mut().set_val("0");
+        chart.plot_visible_only.set_val("1");
+        chart.display_blanks_as.set_val("gap");
+        chart.show_data_labels_over_maximum.set_val("0");
+        chart
+    }
+
+    /// Set the chart title as plain text.
+    pub fn set_title_text<S: Into<String>>(&mut self, value:S)-> &mut Chart {
+        let mut title = Title::default();
+        title.set_text(value);
+        self.set_title(title);
+        self.auto_title_deleted.set_val("0");
+        self
+    }
+
     pub fn get_formula_mut(&mut self)-> Vec<&mut Formula> {
         self.get_plot_area_mut().get_formula_mut()
     }
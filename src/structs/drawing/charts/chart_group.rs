@@ -0,0 +1,165 @@
+// c:areaChart, c:barChart, c:lineChart and the other chart groups of c:plotArea
+use super::AreaChart;
+use super::Area3DChart;
+use super::LineChart;
+use super::Line3DChart;
+use super::StockChart;
+use super::RadarChart;
+use super::ScatterChart;
+use super::PieChart;
+use super::Pie3DChart;
+use super::DoughnutChart;
+use super::BarChart;
+use super::Bar3DChart;
+use super::OfPieChart;
+use super::SurfaceChart;
+use super::Surface3DChart;
+use super::BubbleChart;
+use super::AreaChartSeries;
+use super::AxisId;
+use super::super::super::ChartType;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// One chart group of a plot area, kept in document order.
+#[derive(Clone, Debug)]
+pub enum ChartGroup {
+    /// c:areaChart
+    Area(AreaChart),
+    /// c:area3DChart
+    Area3D(Area3DChart),
+    /// c:lineChart
+    Line(LineChart),
+    /// c:line3DChart
+    Line3D(Line3DChart),
+    /// c:stockChart
+    Stock(StockChart),
+    /// c:radarChart
+    Radar(RadarChart),
+    /// c:scatterChart
+    Scatter(ScatterChart),
+    /// c:pieChart
+    Pie(PieChart),
+    /// c:pie3DChart
+    Pie3D(Pie3DChart),
+    /// c:doughnutChart
+    Doughnut(DoughnutChart),
+    /// c:barChart
+    Bar(BarChart),
+    /// c:bar3DChart
+    Bar3D(Bar3DChart),
+    /// c:ofPieChart
+    OfPie(OfPieChart),
+    /// c:surfaceChart
+    Surface(SurfaceChart),
+    /// c:surface3DChart
+    Surface3D(Surface3DChart),
+    /// c:bubbleChart
+    Bubble(BubbleChart),
+}
+impl ChartGroup {
+    pub fn get_chart_type(&self)-> ChartType {
+        match self {
+            ChartGroup::Area(_) => ChartType::Area,
+            ChartGroup::Area3D(_) => ChartType::Area3D,
+            ChartGroup::Line(_) => ChartType::Line,
+            ChartGroup::Line3D(_) => ChartType::Line3D,
+            ChartGroup::Stock(_) => ChartType::Stock,
+            ChartGroup::Radar(_) => ChartType::Radar,
+            ChartGroup::Scatter(_) => ChartType::Scatter,
+            ChartGroup::Pie(_) => ChartType::Pie,
+            ChartGroup::Pie3D(_) => ChartType::Pie3D,
+            ChartGroup::Doughnut(_) => ChartType::Doughnut,
+            ChartGroup::Bar(v) => if v.get_bar_direction().get_val() == "bar" {ChartType::Bar} else {ChartType::Column},
+            ChartGroup::Bar3D(v) => if v.get_bar_direction().get_val() == "bar" {ChartType::Bar3D} else {ChartType::Column3D},
+            ChartGroup::OfPie(v) => if v.get_of_pie_type().get_val() == "bar" {ChartType::BarOfPie} else {ChartType::PieOfPie},
+            ChartGroup::Surface(_) | ChartGroup::Surface3D(_) => ChartType::Surface,
+            ChartGroup::Bubble(_) => ChartType::Bubble,
+        }
+    }
+
+    pub fn get_area_chart_series(&self)-> &Vec<AreaChartSeries> {
+        match self {
+            ChartGroup::Area(v) => v.get_area_chart_series(),
+            ChartGroup::Area3D(v) => v.get_area_chart_series(),
+            ChartGroup::Line(v) => v.get_area_chart_series(),
+            ChartGroup::Line3D(v) => v.get_area_chart_series(),
+            ChartGroup::Stock(v) => v.get_area_chart_series(),
+            ChartGroup::Radar(v) => v.get_area_chart_series(),
+            ChartGroup::Scatter(v) => v.get_area_chart_series(),
+            ChartGroup::Pie(v) => v.get_area_chart_series(),
+            ChartGroup::Pie3D(v) => v.get_area_chart_series(),
+            ChartGroup::Doughnut(v) => v.get_area_chart_series(),
+            ChartGroup::Bar(v) => v.get_area_chart_series(),
+            ChartGroup::Bar3D(v) => v.get_area_chart_series(),
+            ChartGroup::OfPie(v) => v.get_area_chart_series(),
+            ChartGroup::Surface(v) => v.get_area_chart_series(),
+            ChartGroup::Surface3D(v) => v.get_area_chart_series(),
+            ChartGroup::Bubble(v) => v.get_area_chart_series(),
+        }
+    }
+
+    pub fn get_area_chart_series_mut(&mut self)-> &mut Vec<AreaChartSeries> {
+        match self {
+            ChartGroup::Area(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Area3D(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Line(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Line3D(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Stock(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Radar(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Scatter(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Pie(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Pie3D(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Doughnut(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Bar(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Bar3D(v) => v.get_area_chart_series_mut(),
+            ChartGroup::OfPie(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Surface(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Surface3D(v) => v.get_area_chart_series_mut(),
+            ChartGroup::Bubble(v) => v.get_area_chart_series_mut(),
+        }
+    }
+
+    /// Pie, 3D pie, doughnut and of-pie groups have no axes and give an empty slice.
+    pub fn get_axis_id(&self)-> &[AxisId] {
+        match self {
+            ChartGroup::Area(v) => v.get_axis_id(),
+            ChartGroup::Area3D(v) => v.get_axis_id(),
+            ChartGroup::Line(v) => v.get_axis_id(),
+            ChartGroup::Line3D(v) => v.get_axis_id(),
+            ChartGroup::Stock(v) => v.get_axis_id(),
+            ChartGroup::Radar(v) => v.get_axis_id(),
+            ChartGroup::Scatter(v) => v.get_axis_id(),
+            ChartGroup::Pie(_) => &[],
+            ChartGroup::Pie3D(_) => &[],
+            ChartGroup::Doughnut(_) => &[],
+            ChartGroup::Bar(v) => v.get_axis_id(),
+            ChartGroup::Bar3D(v) => v.get_axis_id(),
+            ChartGroup::OfPie(_) => &[],
+            ChartGroup::Surface(v) => v.get_axis_id(),
+            ChartGroup::Surface3D(v) => v.get_axis_id(),
+            ChartGroup::Bubble(v) => v.get_axis_id(),
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        match self {
+            ChartGroup::Area(v) => v.write_to(writer),
+            ChartGroup::Area3D(v) => v.write_to(writer),
+            ChartGroup::Line(v) => v.write_to(writer),
+            ChartGroup::Line3D(v) => v.write_to(writer),
+            ChartGroup::Stock(v) => v.write_to(writer),
+            ChartGroup::Radar(v) => v.write_to(writer),
+            ChartGroup::Scatter(v) => v.write_to(writer),
+            ChartGroup::Pie(v) => v.write_to(writer),
+            ChartGroup::Pie3D(v) => v.write_to(writer),
+            ChartGroup::Doughnut(v) => v.write_to(writer),
+            ChartGroup::Bar(v) => v.write_to(writer),
+            ChartGroup::Bar3D(v) => v.write_to(writer),
+            ChartGroup::OfPie(v) => v.write_to(writer),
+            ChartGroup::Surface(v) => v.write_to(writer),
+            ChartGroup::Surface3D(v) => v.write_to(writer),
+            ChartGroup::Bubble(v) => v.write_to(writer),
+        }
+    }
+}
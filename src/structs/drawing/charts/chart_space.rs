@@ -6,13 +6,20 @@ use super::super::super::AlternateContent;
 use super::Chart;
 use super::ShapeProperties;
 use super::PrintSettings;
+use super::super::super::ChartType;
+use super::super::super::Spreadsheet;
+use super::AreaChartSeries;
+use super::Trendline;
+use super::TrendlineType;
+use super::ErrorBars;
+use super::ErrorDirection;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct ChartSpace {
     date1904: Date1904,
     editing_language: EditingLanguage,
@@ -114,6 +121,207 @@ impl ChartSpace {
         self
     }
 
+    pub(crate) fn new_chart(chart_type:&ChartType)-> ChartSpace {
+        let mut chart_space = ChartSpace::default();
+        chart_space.date1904.set_val("0");
+        chart_space.editing_language.set_val("en-US");
+        chart_space.rounded_corners.set_val("0");
+        chart_space.alternate_content.get_alternate_content_choice_mut().get_style_mut().set_val("102");
+        chart_space.alternate_content.get_alternate_content_fallback_mut().get_style_mut().set_val("2");
+        chart_space.chart = Chart::new_chart(chart_type);
+        chart_space.print_settings.get_page_margins_mut()
+            .set_bottom("0.75")
+            .set_left("0.7")
+            .set_right("0.7")
+            .set_top("0.75")
+            .set_header("0.3")
+            .set_footer("0.3");
+        chart_space
+    }
+
+    /// Add a series to the chart.
+    /// # Arguments
+    /// * `name_ref` - reference to the series name. ex) "Sheet1!$B$1". Empty for no name.
+    /// * `categories_ref` - reference to the categories, or the x values of a scatter or bubble chart. ex) "Sheet1!$A$2:$A$6". Empty for none.
+    /// * `values_ref` - reference to the values. ex) "Sheet1!$B$2:$B$6"
+    /// References without a sheet name point to the sheet that holds the chart.
+    pub fn add_series(&mut self, name_ref:&str, categories_ref:&str, values_ref:&str)-> &mut ChartSpace {
+        self.chart.get_plot_area_mut().add_series(name_ref, categories_ref, values_ref);
+        self
+    }
+
+    /// Add a series drawn as `chart_type`, on the primary or the secondary axes.
+    /// Use it to build combination charts, such as columns with a line on a secondary value axis.
+    /// # Arguments
+    /// * `chart_type` - kind of chart that draws the series.
+    /// * `is_secondary_axis` - draw the series against the secondary value axis.
+    /// * `name_ref` - reference to the series name. Empty for no name.
+    /// * `categories_ref` - reference to the categories. Empty for none.
+    /// * `values_ref` - reference to the values.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// worksheet.add_chart(ChartType::Column, "E2:L20").unwrap()
+    ///     .add_series("$B$1", "$A$2:$A$6", "$B$2:$B$6")
+    ///     .add_series_with_chart_type(ChartType::Line, true, "$C$1", "$A$2:$A$6", "$C$2:$C$6")
+    ///     .set_secondary_value_axis_title_text("Margin %");
+    /// ```
+    pub fn add_series_with_chart_type(&mut self, chart_type:ChartType, is_secondary_axis:bool, name_ref:&str, categories_ref:&str, values_ref:&str)-> &mut ChartSpace {
+        self.chart.get_plot_area_mut().add_series_with_chart_type(chart_type, is_secondary_axis, name_ref, categories_ref, values_ref);
+        self
+    }
+
+    /// Add a trendline to a series.
+    /// # Arguments
+    /// * `series_index` - index of the series, in the order the series were added from 0.
+    /// * `trendline_type` - ex) TrendlineType::TYPE_LINEAR
+    /// * `is_display_r_squared` - show the R-squared value on the chart.
+    /// * `is_display_equation` - show the equation on the chart.
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the series is not found.
+    pub fn add_trendline(&mut self, series_index:usize, trendline_type:&str, is_display_r_squared:bool, is_display_equation:bool)-> Result<&mut ChartSpace, &'static str> {
+        match self.chart.get_plot_area_mut().get_area_chart_series_by_index_mut(series_index) {
+            Some(v) => {
+                v.add_trendline(Trendline::new_trendline(trendline_type, is_display_r_squared, is_display_equation));
+            },
+            None => return Err("Not found."),
+        }
+        Ok(self)
+    }
+
+    /// Add a polynomial trendline to a series.
+    /// # Arguments
+    /// * `series_index` - index of the series, in the order the series were added from 0.
+    /// * `order` - order of the polynomial, from 2 to 6.
+    /// * `is_display_r_squared` - show the R-squared value on the chart.
+    /// * `is_display_equation` - show the equation on the chart.
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the order is out of range or the series is not found.
+    pub fn add_polynomial_trendline(&mut self, series_index:usize, order:u32, is_display_r_squared:bool, is_display_equation:bool)-> Result<&mut ChartSpace, &'static str> {
+        let mut trendline = Trendline::new_trendline(TrendlineType::TYPE_POLYNOMIAL, is_display_r_squared, is_display_equation);
+        trendline.set_polynomial_order_value(order)?;
+        match self.chart.get_plot_area_mut().get_area_chart_series_by_index_mut(series_index) {
+            Some(v) => {
+                v.add_trendline(trendline);
+            },
+            None => return Err("Not found."),
+        }
+        Ok(self)
+    }
+
+    /// Add a moving average trendline to a series.
+    /// # Arguments
+    /// * `series_index` - index of the series, in the order the series were added from 0.
+    /// * `period` - number of points averaged, 2 or more.
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the period is less than 2 or the series is not found.
+    pub fn add_moving_average_trendline(&mut self, series_index:usize, period:u32)-> Result<&mut ChartSpace, &'static str> {
+        let mut trendline = Trendline::new_trendline(TrendlineType::TYPE_MOVING_AVERAGE, false, false);
+        trendline.set_period_value(period)?;
+        match self.chart.get_plot_area_mut().get_area_chart_series_by_index_mut(series_index) {
+            Some(v) => {
+                v.add_trendline(trendline);
+            },
+            None => return Err("Not found."),
+        }
+        Ok(self)
+    }
+
+    /// Set the error bars of a series.
+    /// # Arguments
+    /// * `series_index` - index of the series, in the order the series were added from 0.
+    /// * `error_bar_value_type` - ex) ErrorBarValueType::TYPE_PERCENTAGE
+    /// * `value` - fixed amount, percentage or number of standard deviations.
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the series is not found.
+    pub fn set_error_bars(&mut self, series_index:usize, error_bar_value_type:&str, value:f64)-> Result<&mut ChartSpace, &'static str> {
+        match self.chart.get_plot_area_mut().get_area_chart_series_by_index_mut(series_index) {
+            Some(v) => {
+                let mut error_bars = ErrorBars::new_error_bars(error_bar_value_type, value);
+                set_error_direction(v, &mut error_bars);
+                v.set_error_bars(vec![error_bars]);
+            },
+            None => return Err("Not found."),
+        }
+        Ok(self)
+    }
+
+    /// Set error bars whose amounts come from cells.
+    /// # Arguments
+    /// * `series_index` - index of the series, in the order the series were added from 0.
+    /// * `plus_ref` - reference to the amounts above the values. ex) "Sheet1!$C$2:$C$6"
+    /// * `minus_ref` - reference to the amounts below the values. ex) "Sheet1!$D$2:$D$6"
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the series is not found.
+    pub fn set_custom_error_bars(&mut self, series_index:usize, plus_ref:&str, minus_ref:&str)-> Result<&mut ChartSpace, &'static str> {
+        match self.chart.get_plot_area_mut().get_area_chart_series_by_index_mut(series_index) {
+            Some(v) => {
+                let mut error_bars = ErrorBars::new_custom_error_bars(plus_ref, minus_ref);
+                set_error_direction(v, &mut error_bars);
+                v.set_error_bars(vec![error_bars]);
+            },
+            None => return Err("Not found."),
+        }
+        Ok(self)
+    }
+
+    /// Fill one data point of a series with a color.
+    /// # Arguments
+    /// * `series_index` - index of the series, in the order the series were added from 0.
+    /// * `point_index` - index of the point in the series, from 0.
+    /// * `color_rgb` - color. ex) "FF0000"
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the series is not found.
+    pub fn set_data_point_color<S: Into<String>>(&mut self, series_index:usize, point_index:usize, color_rgb:S)-> Result<&mut ChartSpace, &'static str> {
+        match self.chart.get_plot_area_mut().get_area_chart_series_by_index_mut(series_index) {
+            Some(v) => {
+                v.set_data_point_color(point_index, color_rgb);
+            },
+            None => return Err("Not found."),
+        }
+        Ok(self)
+    }
+
+    /// Set the chart title as plain text.
+    pub fn set_title_text<S: Into<String>>(&mut self, value:S)-> &mut ChartSpace {
+        self.chart.set_title_text(value);
+        self
+    }
+
+    /// Set the legend position. ex) LegendPosition::POSITION_BOTTOM
+    pub fn set_legend_position<S: Into<String>>(&mut self, value:S)-> &mut ChartSpace {
+        self.chart.get_legend_mut().get_legend_position_mut().set_val(value);
+        self
+    }
+
+    pub fn set_category_axis_title_text<S: Into<String>>(&mut self, value:S)-> &mut ChartSpace {
+        self.chart.get_plot_area_mut().set_category_axis_title_text(value);
+        self
+    }
+
+    pub fn set_value_axis_title_text<S: Into<String>>(&mut self, value:S)-> &mut ChartSpace {
+        self.chart.get_plot_area_mut().set_value_axis_title_text(value);
+        self
+    }
+
+    pub fn set_secondary_value_axis_title_text<S: Into<String>>(&mut self, value:S)-> &mut ChartSpace {
+        self.chart.get_plot_area_mut().set_secondary_value_axis_title_text(value);
+        self
+    }
+
+    /// Fill the caches of the series from the referenced cells.
+    /// References without a sheet name get `sheet_name`.
+    pub(crate) fn update_caches(&mut self, spreadsheet:&Spreadsheet, sheet_name:&str, is_overwrite:&bool) {
+        for formula in self.chart.get_formula_mut() {
+            formula.set_default_sheet_name(sheet_name);
+        }
+        for series in self.chart.get_plot_area_mut().get_area_chart_series_mut() {
+            series.update_caches(spreadsheet, is_overwrite);
+        }
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -204,3 +412,12 @@ impl ChartSpace {
         write_end_tag(writer, "c:chartSpace");
     }
 }
+
+// Error bars of scatter and bubble series run along the y axis.
+fn set_error_direction(series:&AreaChartSeries, error_bars:&mut ErrorBars) {
+    if series.get_y_values().is_some() {
+        let mut error_direction = ErrorDirection::default();
+        error_direction.set_val("y");
+        error_bars.set_error_direction(error_direction);
+    }
+}
@@ -0,0 +1,75 @@
+// c:custSplit
+use super::SecondPiePoint;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomSplit {
+    second_pie_point: Vec<SecondPiePoint>,
+}
+impl CustomSplit {
+    pub fn get_second_pie_point(&self)-> &Vec<SecondPiePoint> {
+        &self.second_pie_point
+    }
+
+    pub fn get_second_pie_point_mut(&mut self)-> &mut Vec<SecondPiePoint> {
+        &mut self.second_pie_point
+    }
+
+    pub fn set_second_pie_point(&mut self, value:Vec<SecondPiePoint>)-> &mut CustomSplit {
+        self.second_pie_point = value;
+        self
+    }
+
+    pub fn add_second_pie_point(&mut self, value:SecondPiePoint)-> &mut CustomSplit {
+        self.second_pie_point.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:secondPiePt" => {
+                            let mut obj = SecondPiePoint::default();
+                            obj.set_attributes(reader, e);
+                            self.add_second_pie_point(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:custSplit" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:custSplit"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:custSplit
+        write_start_tag(writer, "c:custSplit", vec![], false);
+
+        // c:secondPiePt
+        for v in &self.second_pie_point {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:custSplit");
+    }
+}
@@ -12,7 +12,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct DataLabels {
     show_legend_key: ShowLegendKey,
     show_value: ShowValue,
@@ -27,7 +27,7 @@ impl DataLabels {
         &self.show_legend_key
     }
 
-    pub fn get_show_legend_key_mut(&mut self)-> &ShowLegendKey {
+    pub fn get_show_legend_key_mut(&mut self)-> &mut ShowLegendKey {
         &mut self.show_legend_key
     }
 
@@ -40,7 +40,7 @@ impl DataLabels {
         &self.show_value
     }
 
-    pub fn get_show_value_mut(&mut self)-> &ShowValue {
+    pub fn get_show_value_mut(&mut self)-> &mut ShowValue {
         &mut self.show_value
     }
 
@@ -53,7 +53,7 @@ impl DataLabels {
         &self.show_category_name
     }
 
-    pub fn get_show_category_name_mut(&mut self)-> &ShowCategoryName {
+    pub fn get_show_category_name_mut(&mut self)-> &mut ShowCategoryName {
         &mut self.show_category_name
     }
 
@@ -66,7 +66,7 @@ impl DataLabels {
         &self.show_series_name
     }
 
-    pub fn get_show_series_name_mut(&mut self)-> &ShowSeriesName {
+    pub fn get_show_series_name_mut(&mut self)-> &mut ShowSeriesName {
         &mut self.show_series_name
     }
 
@@ -79,7 +79,7 @@ impl DataLabels {
         &self.show_percent
     }
 
-    pub fn get_show_percent_mut(&mut self)-> &ShowPercent {
+    pub fn get_show_percent_mut(&mut self)-> &mut ShowPercent {
         &mut self.show_percent
     }
 
@@ -92,7 +92,7 @@ impl DataLabels {
         &self.show_bubble_size
     }
 
-    pub fn get_show_bubble_size_mut(&mut self)-> &ShowBubbleSize {
+    pub fn get_show_bubble_size_mut(&mut self)-> &mut ShowBubbleSize {
         &mut self.show_bubble_size
     }
 
@@ -105,7 +105,7 @@ impl DataLabels {
         &self.show_leader_lines
     }
 
-    pub fn get_show_leader_lines_mut(&mut self)-> &Option<ShowLeaderLines> {
+    pub fn get_show_leader_lines_mut(&mut self)-> &mut Option<ShowLeaderLines> {
         &mut self.show_leader_lines
     }
 
@@ -0,0 +1,206 @@
+// c:dPt
+use super::Index;
+use super::InvertIfNegative;
+use super::Marker;
+use super::Bubble3D;
+use super::Explosion;
+use super::ShapeProperties;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DataPoint {
+    index: Index,
+    invert_if_negative: Option<InvertIfNegative>,
+    marker: Option<Marker>,
+    bubble_3d: Option<Bubble3D>,
+    explosion: Option<Explosion>,
+    shape_properties: Option<ShapeProperties>,
+}
+impl DataPoint {
+    pub fn get_index(&self)-> &Index {
+        &self.index
+    }
+
+    pub fn get_index_mut(&mut self)-> &mut Index {
+        &mut self.index
+    }
+
+    pub fn set_index(&mut self, value:Index)-> &mut DataPoint {
+        self.index = value;
+        self
+    }
+
+    pub fn get_invert_if_negative(&self)-> &Option<InvertIfNegative> {
+        &self.invert_if_negative
+    }
+
+    pub fn get_invert_if_negative_mut(&mut self)-> &mut Option<InvertIfNegative> {
+        &mut self.invert_if_negative
+    }
+
+    pub fn set_invert_if_negative(&mut self, value:InvertIfNegative)-> &mut DataPoint {
+        self.invert_if_negative = Some(value);
+        self
+    }
+
+    pub fn get_marker(&self)-> &Option<Marker> {
+        &self.marker
+    }
+
+    pub fn get_marker_mut(&mut self)-> &mut Option<Marker> {
+        &mut self.marker
+    }
+
+    pub fn set_marker(&mut self, value:Marker)-> &mut DataPoint {
+        self.marker = Some(value);
+        self
+    }
+
+    pub fn get_bubble_3d(&self)-> &Option<Bubble3D> {
+        &self.bubble_3d
+    }
+
+    pub fn get_bubble_3d_mut(&mut self)-> &mut Option<Bubble3D> {
+        &mut self.bubble_3d
+    }
+
+    pub fn set_bubble_3d(&mut self, value:Bubble3D)-> &mut DataPoint {
+        self.bubble_3d = Some(value);
+        self
+    }
+
+    pub fn get_explosion(&self)-> &Option<Explosion> {
+        &self.explosion
+    }
+
+    pub fn get_explosion_mut(&mut self)-> &mut Option<Explosion> {
+        &mut self.explosion
+    }
+
+    pub fn set_explosion(&mut self, value:Explosion)-> &mut DataPoint {
+        self.explosion = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self)-> &Option<ShapeProperties> {
+        &self.shape_properties
+    }
+
+    pub fn get_shape_properties_mut(&mut self)-> &mut Option<ShapeProperties> {
+        &mut self.shape_properties
+    }
+
+    pub fn set_shape_properties(&mut self, value:ShapeProperties)-> &mut DataPoint {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:marker" => {
+                            let mut obj = Marker::default();
+                            obj.set_attributes(reader, e, false);
+                            self.set_marker(obj);
+                        },
+                        b"c:spPr" => {
+                            let mut obj = ShapeProperties::default();
+                            obj.set_attributes(reader, e);
+                            self.set_shape_properties(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:idx" => {
+                            self.index.set_attributes(reader, e);
+                        },
+                        b"c:invertIfNegative" => {
+                            let mut obj = InvertIfNegative::default();
+                            obj.set_attributes(reader, e);
+                            self.set_invert_if_negative(obj);
+                        },
+                        b"c:marker" => {
+                            let mut obj = Marker::default();
+                            obj.set_attributes(reader, e, true);
+                            self.set_marker(obj);
+                        },
+                        b"c:bubble3D" => {
+                            let mut obj = Bubble3D::default();
+                            obj.set_attributes(reader, e);
+                            self.set_bubble_3d(obj);
+                        },
+                        b"c:explosion" => {
+                            let mut obj = Explosion::default();
+                            obj.set_attributes(reader, e);
+                            self.set_explosion(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:dPt" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:dPt"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dPt
+        write_start_tag(writer, "c:dPt", vec![], false);
+
+        // c:idx
+        &self.index.write_to(writer);
+
+        // c:invertIfNegative
+        match &self.invert_if_negative {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:marker
+        match &self.marker {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:bubble3D
+        match &self.bubble_3d {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:explosion
+        match &self.explosion {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:spPr
+        match &self.shape_properties {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        write_end_tag(writer, "c:dPt");
+    }
+}
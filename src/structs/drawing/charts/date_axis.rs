@@ -0,0 +1,489 @@
+// c:dateAx
+use super::AxisId;
+use super::Scaling;
+use super::Delete;
+use super::AxisPosition;
+use super::MajorGridlines;
+use super::Title;
+use super::NumberingFormat;
+use super::MajorTickMark;
+use super::MinorTickMark;
+use super::TickLabelPosition;
+use super::CrossingAxis;
+use super::Crosses;
+use super::AutoLabeled;
+use super::LabelOffset;
+use super::BaseTimeUnit;
+use super::MajorUnit;
+use super::MajorTimeUnit;
+use super::MinorUnit;
+use super::MinorTimeUnit;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DateAxis {
+    axis_id: AxisId,
+    scaling: Scaling,
+    delete: Delete,
+    axis_position: AxisPosition,
+    major_gridlines: Option<MajorGridlines>,
+    title: Option<Title>,
+    numbering_format: Option<NumberingFormat>,
+    major_tick_mark: MajorTickMark,
+    minor_tick_mark: MinorTickMark,
+    tick_label_position: TickLabelPosition,
+    crossing_axis: CrossingAxis,
+    crosses: Crosses,
+    auto_labeled: AutoLabeled,
+    label_offset: LabelOffset,
+    base_time_unit: Option<BaseTimeUnit>,
+    major_unit: Option<MajorUnit>,
+    major_time_unit: Option<MajorTimeUnit>,
+    minor_unit: Option<MinorUnit>,
+    minor_time_unit: Option<MinorTimeUnit>,
+}
+impl DateAxis {
+    pub fn get_axis_id(&self)-> &AxisId {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self)-> &mut AxisId {
+        &mut self.axis_id
+    }
+
+    pub fn set_axis_id(&mut self, value:AxisId)-> &mut DateAxis {
+        self.axis_id = value;
+        self
+    }
+
+    pub fn get_scaling(&self)-> &Scaling {
+        &self.scaling
+    }
+
+    pub fn get_scaling_mut(&mut self)-> &mut Scaling {
+        &mut self.scaling
+    }
+
+    pub fn set_scaling(&mut self, value:Scaling)-> &mut DateAxis {
+        self.scaling = value;
+        self
+    }
+
+    pub fn get_delete(&self)-> &Delete {
+        &self.delete
+    }
+
+    pub fn get_delete_mut(&mut self)-> &mut Delete {
+        &mut self.delete
+    }
+
+    pub fn set_delete(&mut self, value:Delete)-> &mut DateAxis {
+        self.delete = value;
+        self
+    }
+
+    pub fn get_axis_position(&self)-> &AxisPosition {
+        &self.axis_position
+    }
+
+    pub fn get_axis_position_mut(&mut self)-> &mut AxisPosition {
+        &mut self.axis_position
+    }
+
+    pub fn set_axis_position(&mut self, value:AxisPosition)-> &mut DateAxis {
+        self.axis_position = value;
+        self
+    }
+
+    pub fn get_major_gridlines(&self)-> &Option<MajorGridlines> {
+        &self.major_gridlines
+    }
+
+    pub fn get_major_gridlines_mut(&mut self)-> &mut Option<MajorGridlines> {
+        &mut self.major_gridlines
+    }
+
+    pub fn set_major_gridlines(&mut self, value:MajorGridlines)-> &mut DateAxis {
+        self.major_gridlines = Some(value);
+        self
+    }
+
+    pub fn get_title(&self)-> &Option<Title> {
+        &self.title
+    }
+
+    pub fn get_title_mut(&mut self)-> &mut Option<Title> {
+        &mut self.title
+    }
+
+    pub fn set_title(&mut self, value:Title)-> &mut DateAxis {
+        self.title = Some(value);
+        self
+    }
+
+    pub fn get_numbering_format(&self)-> &Option<NumberingFormat> {
+        &self.numbering_format
+    }
+
+    pub fn get_numbering_format_mut(&mut self)-> &mut Option<NumberingFormat> {
+        &mut self.numbering_format
+    }
+
+    pub fn set_numbering_format(&mut self, value:NumberingFormat)-> &mut DateAxis {
+        self.numbering_format = Some(value);
+        self
+    }
+
+    pub fn get_major_tick_mark(&self)-> &MajorTickMark {
+        &self.major_tick_mark
+    }
+
+    pub fn get_major_tick_mark_mut(&mut self)-> &mut MajorTickMark {
+        &mut self.major_tick_mark
+    }
+
+    pub fn set_major_tick_mark(&mut self, value:MajorTickMark)-> &mut DateAxis {
+        self.major_tick_mark = value;
+        self
+    }
+
+    pub fn get_minor_tick_mark(&self)-> &MinorTickMark {
+        &self.minor_tick_mark
+    }
+
+    pub fn get_minor_tick_mark_mut(&mut self)-> &mut MinorTickMark {
+        &mut self.minor_tick_mark
+    }
+
+    pub fn set_minor_tick_mark(&mut self, value:MinorTickMark)-> &mut DateAxis {
+        self.minor_tick_mark = value;
+        self
+    }
+
+    pub fn get_tick_label_position(&self)-> &TickLabelPosition {
+        &self.tick_label_position
+    }
+
+    pub fn get_tick_label_position_mut(&mut self)-> &mut TickLabelPosition {
+        &mut self.tick_label_position
+    }
+
+    pub fn set_tick_label_position(&mut self, value:TickLabelPosition)-> &mut DateAxis {
+        self.tick_label_position = value;
+        self
+    }
+
+    pub fn get_tick_crossing_axis(&self)-> &CrossingAxis {
+        &self.crossing_axis
+    }
+
+    pub fn get_tick_crossing_axis_mut(&mut self)-> &mut CrossingAxis {
+        &mut self.crossing_axis
+    }
+
+    pub fn set_tick_crossing_axis(&mut self, value:CrossingAxis)-> &mut DateAxis {
+        self.crossing_axis = value;
+        self
+    }
+
+    pub fn get_crosses(&self)-> &Crosses {
+        &self.crosses
+    }
+
+    pub fn get_crosses_mut(&mut self)-> &mut Crosses {
+        &mut self.crosses
+    }
+
+    pub fn set_crosses(&mut self, value:Crosses)-> &mut DateAxis {
+        self.crosses = value;
+        self
+    }
+
+    pub fn get_auto_labeled(&self)-> &AutoLabeled {
+        &self.auto_labeled
+    }
+
+    pub fn get_auto_labeled_mut(&mut self)-> &mut AutoLabeled {
+        &mut self.auto_labeled
+    }
+
+    pub fn set_auto_labeled(&mut self, value:AutoLabeled)-> &mut DateAxis {
+        self.auto_labeled = value;
+        self
+    }
+
+    pub fn get_label_offset(&self)-> &LabelOffset {
+        &self.label_offset
+    }
+
+    pub fn get_label_offset_mut(&mut self)-> &mut LabelOffset {
+        &mut self.label_offset
+    }
+
+    pub fn set_label_offset(&mut self, value:LabelOffset)-> &mut DateAxis {
+        self.label_offset = value;
+        self
+    }
+
+    pub fn get_base_time_unit(&self)-> &Option<BaseTimeUnit> {
+        &self.base_time_unit
+    }
+
+    pub fn get_base_time_unit_mut(&mut self)-> &mut Option<BaseTimeUnit> {
+        &mut self.base_time_unit
+    }
+
+    pub fn set_base_time_unit(&mut self, value:BaseTimeUnit)-> &mut DateAxis {
+        self.base_time_unit = Some(value);
+        self
+    }
+
+    pub fn get_major_unit(&self)-> &Option<MajorUnit> {
+        &self.major_unit
+    }
+
+    pub fn get_major_unit_mut(&mut self)-> &mut Option<MajorUnit> {
+        &mut self.major_unit
+    }
+
+    pub fn set_major_unit(&mut self, value:MajorUnit)-> &mut DateAxis {
+        self.major_unit = Some(value);
+        self
+    }
+
+    pub fn get_major_time_unit(&self)-> &Option<MajorTimeUnit> {
+        &self.major_time_unit
+    }
+
+    pub fn get_major_time_unit_mut(&mut self)-> &mut Option<MajorTimeUnit> {
+        &mut self.major_time_unit
+    }
+
+    pub fn set_major_time_unit(&mut self, value:MajorTimeUnit)-> &mut DateAxis {
+        self.major_time_unit = Some(value);
+        self
+    }
+
+    pub fn get_minor_unit(&self)-> &Option<MinorUnit> {
+        &self.minor_unit
+    }
+
+    pub fn get_minor_unit_mut(&mut self)-> &mut Option<MinorUnit> {
+        &mut self.minor_unit
+    }
+
+    pub fn set_minor_unit(&mut self, value:MinorUnit)-> &mut DateAxis {
+        self.minor_unit = Some(value);
+        self
+    }
+
+    pub fn get_minor_time_unit(&self)-> &Option<MinorTimeUnit> {
+        &self.minor_time_unit
+    }
+
+    pub fn get_minor_time_unit_mut(&mut self)-> &mut Option<MinorTimeUnit> {
+        &mut self.minor_time_unit
+    }
+
+    pub fn set_minor_time_unit(&mut self, value:MinorTimeUnit)-> &mut DateAxis {
+        self.minor_time_unit = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:scaling" => {
+                            self.scaling.set_attributes(reader, e);
+                        },
+                        b"c:title" => {
+                            let mut obj = Title::default();
+                            obj.set_attributes(reader, e);
+                            self.set_title(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:axId" => {
+                            self.axis_id.set_attributes(reader, e);
+                        },
+                        b"c:delete" => {
+                            self.delete.set_attributes(reader, e);
+                        },
+                        b"c:axPos" => {
+                            self.axis_position.set_attributes(reader, e);
+                        },
+                        b"c:majorGridlines" => {
+                            let mut obj = MajorGridlines::default();
+                            obj.set_attributes(reader, e);
+                            self.set_major_gridlines(obj);
+                        },
+                        b"c:numFmt" => {
+                            let mut obj = NumberingFormat::default();
+                            obj.set_attributes(reader, e);
+                            self.set_numbering_format(obj);
+                        },
+                        b"c:majorTickMark" => {
+                            self.major_tick_mark.set_attributes(reader, e);
+                        },
+                        b"c:minorTickMark" => {
+                            self.minor_tick_mark.set_attributes(reader, e);
+                        },
+                        b"c:tickLblPos" => {
+                            self.tick_label_position.set_attributes(reader, e);
+                        },
+                        b"c:crossAx" => {
+                            self.crossing_axis.set_attributes(reader, e);
+                        },
+                        b"c:crosses" => {
+                            self.crosses.set_attributes(reader, e);
+                        },
+                        b"c:auto" => {
+                            self.auto_labeled.set_attributes(reader, e);
+                        },
+                        b"c:lblOffset" => {
+                            self.label_offset.set_attributes(reader, e);
+                        },
+                        b"c:baseTimeUnit" => {
+                            let mut obj = BaseTimeUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_base_time_unit(obj);
+                        },
+                        b"c:majorUnit" => {
+                            let mut obj = MajorUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_major_unit(obj);
+                        },
+                        b"c:majorTimeUnit" => {
+                            let mut obj = MajorTimeUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_major_time_unit(obj);
+                        },
+                        b"c:minorUnit" => {
+                            let mut obj = MinorUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_minor_unit(obj);
+                        },
+                        b"c:minorTimeUnit" => {
+                            let mut obj = MinorTimeUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_minor_time_unit(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:dateAx" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:dateAx"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dateAx
+        write_start_tag(writer, "c:dateAx", vec![], false);
+
+        // c:axId
+        &self.axis_id.write_to(writer);
+
+        // c:scaling
+        &self.scaling.write_to(writer);
+
+        // c:delete
+        &self.delete.write_to(writer);
+
+        // c:axPos
+        &self.axis_position.write_to(writer);
+
+        // c:majorGridlines
+        match &self.major_gridlines {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:title
+        match &self.title {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:numFmt
+        match &self.numbering_format {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:majorTickMark
+        &self.major_tick_mark.write_to(writer);
+
+        // c:minorTickMark
+        &self.minor_tick_mark.write_to(writer);
+
+        // c:tickLblPos
+        &self.tick_label_position.write_to(writer);
+
+        // c:crossAx
+        &self.crossing_axis.write_to(writer);
+
+        // c:crosses
+        &self.crosses.write_to(writer);
+
+        // c:auto
+        &self.auto_labeled.write_to(writer);
+
+        // c:lblOffset
+        &self.label_offset.write_to(writer);
+
+        // c:baseTimeUnit
+        match &self.base_time_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:majorUnit
+        match &self.major_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:majorTimeUnit
+        match &self.major_time_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:minorUnit
+        match &self.minor_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:minorTimeUnit
+        match &self.minor_time_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        write_end_tag(writer, "c:dateAx");
+    }
+}
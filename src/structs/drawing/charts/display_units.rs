@@ -0,0 +1,133 @@
+// c:dispUnits
+use super::CustomDisplayUnit;
+use super::BuiltInUnit;
+use super::DisplayUnitsLabel;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DisplayUnits {
+    custom_display_unit: Option<CustomDisplayUnit>,
+    built_in_unit: Option<BuiltInUnit>,
+    display_units_label: Option<DisplayUnitsLabel>,
+}
+impl DisplayUnits {
+    pub fn get_custom_display_unit(&self)-> &Option<CustomDisplayUnit> {
+        &self.custom_display_unit
+    }
+
+    pub fn get_custom_display_unit_mut(&mut self)-> &mut Option<CustomDisplayUnit> {
+        &mut self.custom_display_unit
+    }
+
+    pub fn set_custom_display_unit(&mut self, value:CustomDisplayUnit)-> &mut DisplayUnits {
+        self.custom_display_unit = Some(value);
+        self
+    }
+
+    pub fn get_built_in_unit(&self)-> &Option<BuiltInUnit> {
+        &self.built_in_unit
+    }
+
+    pub fn get_built_in_unit_mut(&mut self)-> &mut Option<BuiltInUnit> {
+        &mut self.built_in_unit
+    }
+
+    pub fn set_built_in_unit(&mut self, value:BuiltInUnit)-> &mut DisplayUnits {
+        self.built_in_unit = Some(value);
+        self
+    }
+
+    pub fn get_display_units_label(&self)-> &Option<DisplayUnitsLabel> {
+        &self.display_units_label
+    }
+
+    pub fn get_display_units_label_mut(&mut self)-> &mut Option<DisplayUnitsLabel> {
+        &mut self.display_units_label
+    }
+
+    pub fn set_display_units_label(&mut self, value:DisplayUnitsLabel)-> &mut DisplayUnits {
+        self.display_units_label = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:dispUnitsLbl" => {
+                            let mut obj = DisplayUnitsLabel::default();
+                            obj.set_attributes(reader, e, false);
+                            self.set_display_units_label(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:custUnit" => {
+                            let mut obj = CustomDisplayUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_custom_display_unit(obj);
+                        },
+                        b"c:builtInUnit" => {
+                            let mut obj = BuiltInUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_built_in_unit(obj);
+                        },
+                        b"c:dispUnitsLbl" => {
+                            let mut obj = DisplayUnitsLabel::default();
+                            obj.set_attributes(reader, e, true);
+                            self.set_display_units_label(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:dispUnits" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:dispUnits"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dispUnits
+        write_start_tag(writer, "c:dispUnits", vec![], false);
+
+        // c:custUnit
+        match &self.custom_display_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:builtInUnit
+        match &self.built_in_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:dispUnitsLbl
+        match &self.display_units_label {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        write_end_tag(writer, "c:dispUnits");
+    }
+}
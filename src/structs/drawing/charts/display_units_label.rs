@@ -0,0 +1,79 @@
+// c:dispUnitsLbl
+use super::Layout;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DisplayUnitsLabel {
+    layout: Layout,
+}
+impl DisplayUnitsLabel {
+    pub fn get_layout(&self)-> &Layout {
+        &self.layout
+    }
+
+    pub fn get_layout_mut(&mut self)-> &mut Layout {
+        &mut self.layout
+    }
+
+    pub fn set_layout(&mut self, value:Layout)-> &mut DisplayUnitsLabel {
+        self.layout = value;
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart,
+        empty_flag:bool,
+    ) {
+        if empty_flag {
+            return;
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:layout" => {
+                            self.layout.set_attributes(reader, e, false);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:layout" => {
+                            self.layout.set_attributes(reader, e, true);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:dispUnitsLbl" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:dispUnitsLbl"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dispUnitsLbl
+        write_start_tag(writer, "c:dispUnitsLbl", vec![], false);
+
+        // c:layout
+        &self.layout.write_to(writer);
+
+        write_end_tag(writer, "c:dispUnitsLbl");
+    }
+}
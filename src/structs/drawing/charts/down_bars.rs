@@ -0,0 +1,80 @@
+// c:downBars
+use super::ShapeProperties;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DownBars {
+    shape_properties: Option<ShapeProperties>,
+}
+impl DownBars {
+    pub fn get_shape_properties(&self)-> &Option<ShapeProperties> {
+        &self.shape_properties
+    }
+
+    pub fn get_shape_properties_mut(&mut self)-> &mut Option<ShapeProperties> {
+        &mut self.shape_properties
+    }
+
+    pub fn set_shape_properties(&mut self, value:ShapeProperties)-> &mut DownBars {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart,
+        empty_flag:bool,
+    ) {
+        if empty_flag {
+            return;
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:spPr" => {
+                            let mut obj = ShapeProperties::default();
+                            obj.set_attributes(reader, e);
+                            self.set_shape_properties(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:downBars" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:downBars"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:downBars
+        match &self.shape_properties {
+            Some(v) => {
+                write_start_tag(writer, "c:downBars", vec![], false);
+
+                // c:spPr
+                v.write_to(writer);
+
+                write_end_tag(writer, "c:downBars");
+            },
+            None => {
+                write_start_tag(writer, "c:downBars", vec![], true);
+            }
+        }
+    }
+}
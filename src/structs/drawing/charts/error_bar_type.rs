@@ -0,0 +1,41 @@
+// c:errBarType
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ErrorBarType {
+    val: String,
+}
+impl ErrorBarType {
+    pub const TYPE_BOTH: &'static str = "both";
+    pub const TYPE_MINUS: &'static str = "minus";
+    pub const TYPE_PLUS: &'static str = "plus";
+
+    pub fn get_val(&self)-> &str {
+        &self.val
+    }
+
+    pub fn set_val<S: Into<String>>(&mut self, value:S)-> &mut ErrorBarType {
+        self.val = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart
+    ) {
+        self.val = get_attribute(e, b"val").unwrap();
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:errBarType
+        write_start_tag(writer, "c:errBarType", vec![
+            ("val", &self.val),
+        ], true);
+    }
+}
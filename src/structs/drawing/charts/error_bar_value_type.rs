@@ -0,0 +1,43 @@
+// c:errValType
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ErrorBarValueType {
+    val: String,
+}
+impl ErrorBarValueType {
+    pub const TYPE_CUSTOM: &'static str = "cust";
+    pub const TYPE_FIXED_VALUE: &'static str = "fixedVal";
+    pub const TYPE_PERCENTAGE: &'static str = "percentage";
+    pub const TYPE_STANDARD_DEVIATION: &'static str = "stdDev";
+    pub const TYPE_STANDARD_ERROR: &'static str = "stdErr";
+
+    pub fn get_val(&self)-> &str {
+        &self.val
+    }
+
+    pub fn set_val<S: Into<String>>(&mut self, value:S)-> &mut ErrorBarValueType {
+        self.val = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart
+    ) {
+        self.val = get_attribute(e, b"val").unwrap();
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:errValType
+        write_start_tag(writer, "c:errValType", vec![
+            ("val", &self.val),
+        ], true);
+    }
+}
@@ -0,0 +1,307 @@
+// c:errBars
+use super::ErrorDirection;
+use super::ErrorBarType;
+use super::ErrorBarValueType;
+use super::NoEndCap;
+use super::Plus;
+use super::Minus;
+use super::ErrorBarValue;
+use super::ShapeProperties;
+use super::Formula;
+use super::super::super::Spreadsheet;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ErrorBars {
+    error_direction: Option<ErrorDirection>,
+    error_bar_type: ErrorBarType,
+    error_bar_value_type: ErrorBarValueType,
+    no_end_cap: Option<NoEndCap>,
+    plus: Option<Plus>,
+    minus: Option<Minus>,
+    error_bar_value: Option<ErrorBarValue>,
+    shape_properties: Option<ShapeProperties>,
+}
+impl ErrorBars {
+    pub fn get_error_direction(&self)-> &Option<ErrorDirection> {
+        &self.error_direction
+    }
+
+    pub fn get_error_direction_mut(&mut self)-> &mut Option<ErrorDirection> {
+        &mut self.error_direction
+    }
+
+    pub fn set_error_direction(&mut self, value:ErrorDirection)-> &mut ErrorBars {
+        self.error_direction = Some(value);
+        self
+    }
+
+    pub fn get_error_bar_type(&self)-> &ErrorBarType {
+        &self.error_bar_type
+    }
+
+    pub fn get_error_bar_type_mut(&mut self)-> &mut ErrorBarType {
+        &mut self.error_bar_type
+    }
+
+    pub fn set_error_bar_type(&mut self, value:ErrorBarType)-> &mut ErrorBars {
+        self.error_bar_type = value;
+        self
+    }
+
+    pub fn get_error_bar_value_type(&self)-> &ErrorBarValueType {
+        &self.error_bar_value_type
+    }
+
+    pub fn get_error_bar_value_type_mut(&mut self)-> &mut ErrorBarValueType {
+        &mut self.error_bar_value_type
+    }
+
+    pub fn set_error_bar_value_type(&mut self, value:ErrorBarValueType)-> &mut ErrorBars {
+        self.error_bar_value_type = value;
+        self
+    }
+
+    pub fn get_no_end_cap(&self)-> &Option<NoEndCap> {
+        &self.no_end_cap
+    }
+
+    pub fn get_no_end_cap_mut(&mut self)-> &mut Option<NoEndCap> {
+        &mut self.no_end_cap
+    }
+
+    pub fn set_no_end_cap(&mut self, value:NoEndCap)-> &mut ErrorBars {
+        self.no_end_cap = Some(value);
+        self
+    }
+
+    pub fn get_plus(&self)-> &Option<Plus> {
+        &self.plus
+    }
+
+    pub fn get_plus_mut(&mut self)-> &mut Option<Plus> {
+        &mut self.plus
+    }
+
+    pub fn set_plus(&mut self, value:Plus)-> &mut ErrorBars {
+        self.plus = Some(value);
+        self
+    }
+
+    pub fn get_minus(&self)-> &Option<Minus> {
+        &self.minus
+    }
+
+    pub fn get_minus_mut(&mut self)-> &mut Option<Minus> {
+        &mut self.minus
+    }
+
+    pub fn set_minus(&mut self, value:Minus)-> &mut ErrorBars {
+        self.minus = Some(value);
+        self
+    }
+
+    pub fn get_error_bar_value(&self)-> &Option<ErrorBarValue> {
+        &self.error_bar_value
+    }
+
+    pub fn get_error_bar_value_mut(&mut self)-> &mut Option<ErrorBarValue> {
+        &mut self.error_bar_value
+    }
+
+    pub fn set_error_bar_value(&mut self, value:ErrorBarValue)-> &mut ErrorBars {
+        self.error_bar_value = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self)-> &Option<ShapeProperties> {
+        &self.shape_properties
+    }
+
+    pub fn get_shape_properties_mut(&mut self)-> &mut Option<ShapeProperties> {
+        &mut self.shape_properties
+    }
+
+    pub fn set_shape_properties(&mut self, value:ShapeProperties)-> &mut ErrorBars {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn new_error_bars(error_bar_value_type:&str, value:f64)-> ErrorBars {
+        let mut error_bars = ErrorBars::default();
+        error_bars.error_bar_type.set_val(ErrorBarType::TYPE_BOTH);
+        error_bars.error_bar_value_type.set_val(error_bar_value_type);
+        let mut no_end_cap = NoEndCap::default();
+        no_end_cap.set_val("0");
+        error_bars.set_no_end_cap(no_end_cap);
+        let mut error_bar_value = ErrorBarValue::default();
+        error_bar_value.set_val(value.to_string());
+        error_bars.set_error_bar_value(error_bar_value);
+        error_bars
+    }
+
+    pub(crate) fn new_custom_error_bars(plus_ref:&str, minus_ref:&str)-> ErrorBars {
+        let mut error_bars = ErrorBars::default();
+        error_bars.error_bar_type.set_val(ErrorBarType::TYPE_BOTH);
+        error_bars.error_bar_value_type.set_val(ErrorBarValueType::TYPE_CUSTOM);
+        let mut no_end_cap = NoEndCap::default();
+        no_end_cap.set_val("0");
+        error_bars.set_no_end_cap(no_end_cap);
+        let mut plus = Plus::default();
+        plus.get_number_reference_mut().get_formula_mut().set_address_str(plus_ref);
+        error_bars.set_plus(plus);
+        let mut minus = Minus::default();
+        minus.get_number_reference_mut().get_formula_mut().set_address_str(minus_ref);
+        error_bars.set_minus(minus);
+        error_bars
+    }
+
+    pub fn get_formula_mut(&mut self)-> Vec<&mut Formula> {
+        let mut result:Vec<&mut Formula> = Vec::default();
+        match &mut self.plus {
+            Some(v) => {
+                result.push(v.get_number_reference_mut().get_formula_mut());
+            }
+            None => {}
+        }
+        match &mut self.minus {
+            Some(v) => {
+                result.push(v.get_number_reference_mut().get_formula_mut());
+            }
+            None => {}
+        }
+        result
+    }
+
+    pub(crate) fn update_caches(&mut self, spreadsheet:&Spreadsheet, is_overwrite:&bool) {
+        match &mut self.plus {
+            Some(v) => v.get_number_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+        match &mut self.minus {
+            Some(v) => v.get_number_reference_mut().update_cache(spreadsheet, is_overwrite),
+            None => {}
+        }
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:plus" => {
+                            let mut obj = Plus::default();
+                            obj.set_attributes(reader, e);
+                            self.set_plus(obj);
+                        },
+                        b"c:minus" => {
+                            let mut obj = Minus::default();
+                            obj.set_attributes(reader, e);
+                            self.set_minus(obj);
+                        },
+                        b"c:spPr" => {
+                            let mut obj = ShapeProperties::default();
+                            obj.set_attributes(reader, e);
+                            self.set_shape_properties(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:errDir" => {
+                            let mut obj = ErrorDirection::default();
+                            obj.set_attributes(reader, e);
+                            self.set_error_direction(obj);
+                        },
+                        b"c:errBarType" => {
+                            self.error_bar_type.set_attributes(reader, e);
+                        },
+                        b"c:errValType" => {
+                            self.error_bar_value_type.set_attributes(reader, e);
+                        },
+                        b"c:noEndCap" => {
+                            let mut obj = NoEndCap::default();
+                            obj.set_attributes(reader, e);
+                            self.set_no_end_cap(obj);
+                        },
+                        b"c:val" => {
+                            let mut obj = ErrorBarValue::default();
+                            obj.set_attributes(reader, e);
+                            self.set_error_bar_value(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:errBars" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:errBars"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:errBars
+        write_start_tag(writer, "c:errBars", vec![], false);
+
+        // c:errDir
+        match &self.error_direction {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:errBarType
+        &self.error_bar_type.write_to(writer);
+
+        // c:errValType
+        &self.error_bar_value_type.write_to(writer);
+
+        // c:noEndCap
+        match &self.no_end_cap {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:plus
+        match &self.plus {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:minus
+        match &self.minus {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:val
+        match &self.error_bar_value {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:spPr
+        match &self.shape_properties {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        write_end_tag(writer, "c:errBars");
+    }
+}
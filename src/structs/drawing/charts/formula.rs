@@ -1,5 +1,8 @@
 // c:f
 use super::super::super::Address;
+use super::super::super::Spreadsheet;
+use super::super::super::Worksheet;
+use helper::formula::*;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
@@ -33,6 +36,35 @@ impl Formula {
         self
     }
 
+    /// Add the sheet name to a reference that has none.
+    pub(crate) fn set_default_sheet_name(&mut self, sheet_name:&str) {
+        if self.address.get_sheet_name() != "" {
+            return;
+        }
+        self.address.set_sheet_name(quote_sheet_name(sheet_name));
+    }
+
+    /// Get the referenced worksheet and the (column, row) of each referenced cell in row order.
+    pub(crate) fn get_referenced_cells<'a>(&self, spreadsheet:&'a Spreadsheet)-> Option<(&'a Worksheet, Vec<(usize, usize)>)> {
+        let sheet_name = unquote_sheet_name(self.address.get_sheet_name());
+        let worksheet = match spreadsheet.get_sheet_by_name(sheet_name) {
+            Ok(v) => v,
+            Err(_) => return None,
+        };
+        let start = self.address.get_range().get_coordinate_start();
+        let end = match self.address.get_range().get_coordinate_end() {
+            Some(v) => v,
+            None => start,
+        };
+        let mut result:Vec<(usize, usize)> = Vec::new();
+        for row in *start.get_row_num()..=*end.get_row_num() {
+            for col in *start.get_col_num()..=*end.get_col_num() {
+                result.push((col, row));
+            }
+        }
+        Some((worksheet, result))
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
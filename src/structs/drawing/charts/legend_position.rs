@@ -6,11 +6,17 @@ use quick_xml::events::{BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct LegendPosition {
     val: String,
 }
 impl LegendPosition {
+    pub const POSITION_BOTTOM: &'static str = "b";
+    pub const POSITION_LEFT: &'static str = "l";
+    pub const POSITION_RIGHT: &'static str = "r";
+    pub const POSITION_TOP: &'static str = "t";
+    pub const POSITION_TOP_RIGHT: &'static str = "tr";
+
     pub fn get_val(&self)-> &str {
         &self.val
     }
@@ -0,0 +1,66 @@
+// c:minus
+use super::NumberReference;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Minus {
+    number_reference: NumberReference,
+}
+impl Minus {
+    pub fn get_number_reference(&self)-> &NumberReference {
+        &self.number_reference
+    }
+
+    pub fn get_number_reference_mut(&mut self)-> &mut NumberReference {
+        &mut self.number_reference
+    }
+
+    pub fn set_number_reference(&mut self, value:NumberReference)-> &mut Minus {
+        self.number_reference = value;
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:numRef" => {
+                            self.number_reference.set_attributes(reader, e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:minus" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:minus"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:minus
+        write_start_tag(writer, "c:minus", vec![], false);
+
+        // c:numRef
+        &self.number_reference.write_to(writer);
+
+        write_end_tag(writer, "c:minus");
+    }
+}
@@ -1,13 +1,16 @@
 // c:numRef
 use super::Formula;
 use super::NumberingCache;
+use super::StringPoint;
+use super::super::super::Spreadsheet;
+use super::super::super::NumberFormat;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct NumberReference {
     formula: Formula,
     numbering_cache: NumberingCache,
@@ -39,6 +42,41 @@ impl NumberReference {
         self
     }
 
+    /// Fill the cache with the numbers of the referenced cells.
+    /// Keeps an existing cache unless `is_overwrite` is set.
+    pub(crate) fn update_cache(&mut self, spreadsheet:&Spreadsheet, is_overwrite:&bool) {
+        if !is_overwrite && self.numbering_cache.get_point_count().get_val() != "" {
+            return;
+        }
+        let (worksheet, cells) = match self.formula.get_referenced_cells(spreadsheet) {
+            Some(v) => v,
+            None => return,
+        };
+        let format_code = cells.iter()
+            .filter_map(|(col, row)| worksheet.get_style_by_column_and_row(*col, *row))
+            .filter_map(|style| style.get_number_format().as_ref())
+            .map(|number_format| number_format.get_format_code().to_string())
+            .next()
+            .unwrap_or_else(|| NumberFormat::FORMAT_GENERAL.to_string());
+        let mut numbering_cache = NumberingCache::default();
+        numbering_cache.get_format_code_mut().set_val(format_code);
+        numbering_cache.get_point_count_mut().set_val(cells.len().to_string());
+        for (index, (col, row)) in cells.iter().enumerate() {
+            let value = match worksheet.get_cell_by_column_and_row(*col, *row) {
+                Some(cell) => match cell.get_cell_value().get_number_with_calendar(spreadsheet.get_calendar()) {
+                    Some(v) => v,
+                    None => continue,
+                },
+                None => continue,
+            };
+            let mut string_point = StringPoint::default();
+            string_point.set_index(index.to_string());
+            string_point.get_numeric_value_mut().set_val(value.to_string());
+            numbering_cache.get_string_point_mut().push(string_point);
+        }
+        self.numbering_cache = numbering_cache;
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
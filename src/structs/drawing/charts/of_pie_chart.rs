@@ -0,0 +1,313 @@
+// c:ofPieChart
+use super::OfPieType;
+use super::VaryColors;
+use super::AreaChartSeries;
+use super::DataLabels;
+use super::GapWidth;
+use super::SplitType;
+use super::SplitPosition;
+use super::CustomSplit;
+use super::SecondPieSize;
+use super::SeriesLines;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct OfPieChart {
+    of_pie_type: OfPieType,
+    vary_colors: VaryColors,
+    area_chart_series: Vec<AreaChartSeries>,
+    data_labels: Option<DataLabels>,
+    gap_width: Option<GapWidth>,
+    split_type: Option<SplitType>,
+    split_position: Option<SplitPosition>,
+    custom_split: Option<CustomSplit>,
+    second_pie_size: Option<SecondPieSize>,
+    series_lines: Vec<SeriesLines>,
+}
+impl OfPieChart {
+    pub fn get_of_pie_type(&self)-> &OfPieType {
+        &self.of_pie_type
+    }
+
+    pub fn get_of_pie_type_mut(&mut self)-> &mut OfPieType {
+        &mut self.of_pie_type
+    }
+
+    pub fn set_of_pie_type(&mut self, value:OfPieType)-> &mut OfPieChart {
+        self.of_pie_type = value;
+        self
+    }
+
+    pub fn get_vary_colors(&self)-> &VaryColors {
+        &self.vary_colors
+    }
+
+    pub fn get_vary_colors_mut(&mut self)-> &mut VaryColors {
+        &mut self.vary_colors
+    }
+
+    pub fn set_vary_colors(&mut self, value:VaryColors)-> &mut OfPieChart {
+        self.vary_colors = value;
+        self
+    }
+
+    pub fn get_area_chart_series(&self)-> &Vec<AreaChartSeries> {
+        &self.area_chart_series
+    }
+
+    pub fn get_area_chart_series_mut(&mut self)-> &mut Vec<AreaChartSeries> {
+        &mut self.area_chart_series
+    }
+
+    pub fn set_area_chart_series(&mut self, value:Vec<AreaChartSeries>)-> &mut OfPieChart {
+        self.area_chart_series = value;
+        self
+    }
+
+    pub fn add_area_chart_series(&mut self, value:AreaChartSeries)-> &mut OfPieChart {
+        self.area_chart_series.push(value);
+        self
+    }
+
+    pub fn get_data_labels(&self)-> &Option<DataLabels> {
+        &self.data_labels
+    }
+
+    pub fn get_data_labels_mut(&mut self)-> &mut Option<DataLabels> {
+        &mut self.data_labels
+    }
+
+    pub fn set_data_labels(&mut self, value:DataLabels)-> &mut OfPieChart {
+        self.data_labels = Some(value);
+        self
+    }
+
+    pub fn get_gap_width(&self)-> &Option<GapWidth> {
+        &self.gap_width
+    }
+
+    pub fn get_gap_width_mut(&mut self)-> &mut Option<GapWidth> {
+        &mut self.gap_width
+    }
+
+    pub fn set_gap_width(&mut self, value:GapWidth)-> &mut OfPieChart {
+        self.gap_width = Some(value);
+        self
+    }
+
+    pub fn get_split_type(&self)-> &Option<SplitType> {
+        &self.split_type
+    }
+
+    pub fn get_split_type_mut(&mut self)-> &mut Option<SplitType> {
+        &mut self.split_type
+    }
+
+    pub fn set_split_type(&mut self, value:SplitType)-> &mut OfPieChart {
+        self.split_type = Some(value);
+        self
+    }
+
+    pub fn get_split_position(&self)-> &Option<SplitPosition> {
+        &self.split_position
+    }
+
+    pub fn get_split_position_mut(&mut self)-> &mut Option<SplitPosition> {
+        &mut self.split_position
+    }
+
+    pub fn set_split_position(&mut self, value:SplitPosition)-> &mut OfPieChart {
+        self.split_position = Some(value);
+        self
+    }
+
+    pub fn get_custom_split(&self)-> &Option<CustomSplit> {
+        &self.custom_split
+    }
+
+    pub fn get_custom_split_mut(&mut self)-> &mut Option<CustomSplit> {
+        &mut self.custom_split
+    }
+
+    pub fn set_custom_split(&mut self, value:CustomSplit)-> &mut OfPieChart {
+        self.custom_split = Some(value);
+        self
+    }
+
+    pub fn get_second_pie_size(&self)-> &Option<SecondPieSize> {
+        &self.second_pie_size
+    }
+
+    pub fn get_second_pie_size_mut(&mut self)-> &mut Option<SecondPieSize> {
+        &mut self.second_pie_size
+    }
+
+    pub fn set_second_pie_size(&mut self, value:SecondPieSize)-> &mut OfPieChart {
+        self.second_pie_size = Some(value);
+        self
+    }
+
+    pub fn get_series_lines(&self)-> &Vec<SeriesLines> {
+        &self.series_lines
+    }
+
+    pub fn get_series_lines_mut(&mut self)-> &mut Vec<SeriesLines> {
+        &mut self.series_lines
+    }
+
+    pub fn set_series_lines(&mut self, value:Vec<SeriesLines>)-> &mut OfPieChart {
+        self.series_lines = value;
+        self
+    }
+
+    pub fn add_series_lines(&mut self, value:SeriesLines)-> &mut OfPieChart {
+        self.series_lines.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:ser" => {
+                            let mut obj = AreaChartSeries::default();
+                            obj.set_attributes(reader, e);
+                            self.add_area_chart_series(obj);
+                        },
+                        b"c:dLbls" => {
+                            let mut obj = DataLabels::default();
+                            obj.set_attributes(reader, e);
+                            self.set_data_labels(obj);
+                        },
+                        b"c:custSplit" => {
+                            let mut obj = CustomSplit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_custom_split(obj);
+                        },
+                        b"c:serLines" => {
+                            let mut obj = SeriesLines::default();
+                            obj.set_attributes(reader, e, false);
+                            self.add_series_lines(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:ofPieType" => {
+                            self.of_pie_type.set_attributes(reader, e);
+                        },
+                        b"c:varyColors" => {
+                            self.vary_colors.set_attributes(reader, e);
+                        },
+                        b"c:gapWidth" => {
+                            let mut obj = GapWidth::default();
+                            obj.set_attributes(reader, e);
+                            self.set_gap_width(obj);
+                        },
+                        b"c:splitType" => {
+                            let mut obj = SplitType::default();
+                            obj.set_attributes(reader, e);
+                            self.set_split_type(obj);
+                        },
+                        b"c:splitPos" => {
+                            let mut obj = SplitPosition::default();
+                            obj.set_attributes(reader, e);
+                            self.set_split_position(obj);
+                        },
+                        b"c:secondPieSize" => {
+                            let mut obj = SecondPieSize::default();
+                            obj.set_attributes(reader, e);
+                            self.set_second_pie_size(obj);
+                        },
+                        b"c:serLines" => {
+                            let mut obj = SeriesLines::default();
+                            obj.set_attributes(reader, e, true);
+                            self.add_series_lines(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:ofPieChart" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:ofPieChart"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:ofPieChart
+        write_start_tag(writer, "c:ofPieChart", vec![], false);
+
+        // c:ofPieType
+        &self.of_pie_type.write_to(writer);
+
+        // c:varyColors
+        &self.vary_colors.write_to(writer);
+
+        // c:ser
+        for v in &self.area_chart_series {
+            v.write_to(writer);
+        }
+
+        // c:dLbls
+        match &self.data_labels {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:gapWidth
+        match &self.gap_width {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:splitType
+        match &self.split_type {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:splitPos
+        match &self.split_position {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:custSplit
+        match &self.custom_split {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:secondPieSize
+        match &self.second_pie_size {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:serLines
+        for v in &self.series_lines {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:ofPieChart");
+    }
+}
@@ -1,33 +1,70 @@
 // c:plotArea
 use super::Layout;
+use super::AreaChart;
+use super::Area3DChart;
 use super::LineChart;
+use super::Line3DChart;
+use super::StockChart;
+use super::RadarChart;
+use super::ScatterChart;
 use super::PieChart;
+use super::Pie3DChart;
 use super::DoughnutChart;
-use super::ScatterChart;
 use super::BarChart;
 use super::Bar3DChart;
+use super::OfPieChart;
+use super::SurfaceChart;
+use super::Surface3DChart;
+use super::BubbleChart;
+use super::ChartGroup;
 use super::CategoryAxis;
 use super::ValueAxis;
+use super::DateAxis;
+use super::SeriesAxis;
 use super::Formula;
+use super::AreaChartSeries;
+use super::AxisId;
+use super::DataLabels;
+use super::MajorGridlines;
+use super::SeriesText;
+use super::CategoryAxisData;
+use super::Values;
+use super::XValues;
+use super::YValues;
+use super::InvertIfNegative;
+use super::Smooth;
+use super::Bubble3D;
+use super::BubbleScale;
+use super::ShowNegativeBubbles;
+use super::HighLowLines;
+use super::Wireframe;
+use super::GapWidth;
+use super::SecondPieSize;
+use super::SeriesLines;
+use super::Title;
+use super::super::super::ChartType;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct PlotArea {
     layout: Layout,
-    line_chart: Option<LineChart>,
-    pie_chart: Option<PieChart>,
-    doughnut_chart: Option<DoughnutChart>,
-    scatter_chart: Option<ScatterChart>,
-    bar_chart: Option<BarChart>,
-    bar_3d_chart: Option<Bar3DChart>,
+    chart_group: Vec<ChartGroup>,
     category_axis: Vec<CategoryAxis>,
     value_axis: Vec<ValueAxis>,
+    date_axis: Vec<DateAxis>,
+    series_axis: Vec<SeriesAxis>,
 }
 impl PlotArea {
+    pub(crate) const CATEGORY_AXIS_ID: &'static str = "500000001";
+    pub(crate) const VALUE_AXIS_ID: &'static str = "500000002";
+    pub(crate) const SERIES_AXIS_ID: &'static str = "500000003";
+    pub(crate) const SECONDARY_CATEGORY_AXIS_ID: &'static str = "500000004";
+    pub(crate) const SECONDARY_VALUE_AXIS_ID: &'static str = "500000005";
+
     pub fn get_layout(&self)-> &Layout {
         &self.layout
     }
@@ -41,81 +78,396 @@ impl PlotArea {
         self
     }
 
-    pub fn get_line_chart(&self)-> &Option<LineChart> {
-        &self.line_chart
+    /// Chart groups in the order they are drawn and written.
+    pub fn get_chart_group(&self)-> &Vec<ChartGroup> {
+        &self.chart_group
+    }
+
+    pub fn get_chart_group_mut(&mut self)-> &mut Vec<ChartGroup> {
+        &mut self.chart_group
+    }
+
+    pub fn get_area_chart(&self)-> Option<&AreaChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Area(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_area_chart_mut(&mut self)-> Option<&mut AreaChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Area(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first AreaChart, or add one after the other chart groups.
+    pub fn set_area_chart(&mut self, value:AreaChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Area(value))
+    }
+
+    pub fn add_area_chart(&mut self, value:AreaChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Area(value));
+        self
+    }
+
+    pub fn get_area_3d_chart(&self)-> Option<&Area3DChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Area3D(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_area_3d_chart_mut(&mut self)-> Option<&mut Area3DChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Area3D(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn get_line_chart_mut(&mut self)-> &mut Option<LineChart> {
-        &mut self.line_chart
+    /// Replace the first Area3DChart, or add one after the other chart groups.
+    pub fn set_area_3d_chart(&mut self, value:Area3DChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Area3D(value))
     }
 
+    pub fn add_area_3d_chart(&mut self, value:Area3DChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Area3D(value));
+        self
+    }
+
+    pub fn get_line_chart(&self)-> Option<&LineChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Line(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_line_chart_mut(&mut self)-> Option<&mut LineChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Line(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first LineChart, or add one after the other chart groups.
     pub fn set_line_chart(&mut self, value:LineChart)-> &mut PlotArea {
-        self.line_chart = Some(value);
+        self.set_chart_group(ChartGroup::Line(value))
+    }
+
+    pub fn add_line_chart(&mut self, value:LineChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Line(value));
         self
     }
 
-    pub fn get_pie_chart(&self)-> &Option<PieChart> {
-        &self.pie_chart
+    pub fn get_line_3d_chart(&self)-> Option<&Line3DChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Line3D(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn get_pie_chart_mut(&mut self)-> &mut Option<PieChart> {
-        &mut self.pie_chart
+    pub fn get_line_3d_chart_mut(&mut self)-> Option<&mut Line3DChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Line3D(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn set_pie_chart(&mut self, value:PieChart)-> &mut PlotArea {
-        self.pie_chart = Some(value);
+    /// Replace the first Line3DChart, or add one after the other chart groups.
+    pub fn set_line_3d_chart(&mut self, value:Line3DChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Line3D(value))
+    }
+
+    pub fn add_line_3d_chart(&mut self, value:Line3DChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Line3D(value));
         self
     }
 
-    pub fn get_doughnut_chart(&self)-> &Option<DoughnutChart> {
-        &self.doughnut_chart
+    pub fn get_stock_chart(&self)-> Option<&StockChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Stock(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn get_doughnut_chart_mut(&mut self)-> &mut Option<DoughnutChart> {
-        &mut self.doughnut_chart
+    pub fn get_stock_chart_mut(&mut self)-> Option<&mut StockChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Stock(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn set_doughnut_chart(&mut self, value:DoughnutChart)-> &mut PlotArea {
-        self.doughnut_chart = Some(value);
+    /// Replace the first StockChart, or add one after the other chart groups.
+    pub fn set_stock_chart(&mut self, value:StockChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Stock(value))
+    }
+
+    pub fn add_stock_chart(&mut self, value:StockChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Stock(value));
+        self
+    }
+
+    pub fn get_radar_chart(&self)-> Option<&RadarChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Radar(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_radar_chart_mut(&mut self)-> Option<&mut RadarChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Radar(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first RadarChart, or add one after the other chart groups.
+    pub fn set_radar_chart(&mut self, value:RadarChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Radar(value))
+    }
+
+    pub fn add_radar_chart(&mut self, value:RadarChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Radar(value));
         self
     }
 
-    pub fn get_scatter_chart(&self)-> &Option<ScatterChart> {
-        &self.scatter_chart
+    pub fn get_scatter_chart(&self)-> Option<&ScatterChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Scatter(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn get_scatter_chart_mut(&mut self)-> &mut Option<ScatterChart> {
-        &mut self.scatter_chart
+    pub fn get_scatter_chart_mut(&mut self)-> Option<&mut ScatterChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Scatter(v) => Some(v),
+            _ => None,
+        })
     }
 
+    /// Replace the first ScatterChart, or add one after the other chart groups.
     pub fn set_scatter_chart(&mut self, value:ScatterChart)-> &mut PlotArea {
-        self.scatter_chart = Some(value);
+        self.set_chart_group(ChartGroup::Scatter(value))
+    }
+
+    pub fn add_scatter_chart(&mut self, value:ScatterChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Scatter(value));
         self
     }
 
-    pub fn get_bar_chart(&self)-> &Option<BarChart> {
-        &self.bar_chart
+    pub fn get_pie_chart(&self)-> Option<&PieChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Pie(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn get_bar_chart_mut(&mut self)-> &mut Option<BarChart> {
-        &mut self.bar_chart
+    pub fn get_pie_chart_mut(&mut self)-> Option<&mut PieChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Pie(v) => Some(v),
+            _ => None,
+        })
     }
 
+    /// Replace the first PieChart, or add one after the other chart groups.
+    pub fn set_pie_chart(&mut self, value:PieChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Pie(value))
+    }
+
+    pub fn add_pie_chart(&mut self, value:PieChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Pie(value));
+        self
+    }
+
+    pub fn get_pie_3d_chart(&self)-> Option<&Pie3DChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Pie3D(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_pie_3d_chart_mut(&mut self)-> Option<&mut Pie3DChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Pie3D(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first Pie3DChart, or add one after the other chart groups.
+    pub fn set_pie_3d_chart(&mut self, value:Pie3DChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Pie3D(value))
+    }
+
+    pub fn add_pie_3d_chart(&mut self, value:Pie3DChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Pie3D(value));
+        self
+    }
+
+    pub fn get_doughnut_chart(&self)-> Option<&DoughnutChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Doughnut(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_doughnut_chart_mut(&mut self)-> Option<&mut DoughnutChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Doughnut(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first DoughnutChart, or add one after the other chart groups.
+    pub fn set_doughnut_chart(&mut self, value:DoughnutChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Doughnut(value))
+    }
+
+    pub fn add_doughnut_chart(&mut self, value:DoughnutChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Doughnut(value));
+        self
+    }
+
+    pub fn get_bar_chart(&self)-> Option<&BarChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Bar(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_bar_chart_mut(&mut self)-> Option<&mut BarChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Bar(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first BarChart, or add one after the other chart groups.
     pub fn set_bar_chart(&mut self, value:BarChart)-> &mut PlotArea {
-        self.bar_chart = Some(value);
+        self.set_chart_group(ChartGroup::Bar(value))
+    }
+
+    pub fn add_bar_chart(&mut self, value:BarChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Bar(value));
         self
     }
 
-    pub fn get_bar_3d_chart(&self)-> &Option<Bar3DChart> {
-        &self.bar_3d_chart
+    pub fn get_bar_3d_chart(&self)-> Option<&Bar3DChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Bar3D(v) => Some(v),
+            _ => None,
+        })
     }
 
-    pub fn get_bar_3d_chart_mut(&mut self)-> &mut Option<Bar3DChart> {
-        &mut self.bar_3d_chart
+    pub fn get_bar_3d_chart_mut(&mut self)-> Option<&mut Bar3DChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Bar3D(v) => Some(v),
+            _ => None,
+        })
     }
 
+    /// Replace the first Bar3DChart, or add one after the other chart groups.
     pub fn set_bar_3d_chart(&mut self, value:Bar3DChart)-> &mut PlotArea {
-        self.bar_3d_chart = Some(value);
+        self.set_chart_group(ChartGroup::Bar3D(value))
+    }
+
+    pub fn add_bar_3d_chart(&mut self, value:Bar3DChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Bar3D(value));
+        self
+    }
+
+    pub fn get_of_pie_chart(&self)-> Option<&OfPieChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::OfPie(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_of_pie_chart_mut(&mut self)-> Option<&mut OfPieChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::OfPie(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first OfPieChart, or add one after the other chart groups.
+    pub fn set_of_pie_chart(&mut self, value:OfPieChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::OfPie(value))
+    }
+
+    pub fn add_of_pie_chart(&mut self, value:OfPieChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::OfPie(value));
+        self
+    }
+
+    pub fn get_surface_chart(&self)-> Option<&SurfaceChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Surface(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_surface_chart_mut(&mut self)-> Option<&mut SurfaceChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Surface(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first SurfaceChart, or add one after the other chart groups.
+    pub fn set_surface_chart(&mut self, value:SurfaceChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Surface(value))
+    }
+
+    pub fn add_surface_chart(&mut self, value:SurfaceChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Surface(value));
+        self
+    }
+
+    pub fn get_surface_3d_chart(&self)-> Option<&Surface3DChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Surface3D(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_surface_3d_chart_mut(&mut self)-> Option<&mut Surface3DChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Surface3D(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first Surface3DChart, or add one after the other chart groups.
+    pub fn set_surface_3d_chart(&mut self, value:Surface3DChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Surface3D(value))
+    }
+
+    pub fn add_surface_3d_chart(&mut self, value:Surface3DChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Surface3D(value));
+        self
+    }
+
+    pub fn get_bubble_chart(&self)-> Option<&BubbleChart> {
+        self.chart_group.iter().find_map(|v| match v {
+            ChartGroup::Bubble(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn get_bubble_chart_mut(&mut self)-> Option<&mut BubbleChart> {
+        self.chart_group.iter_mut().find_map(|v| match v {
+            ChartGroup::Bubble(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Replace the first BubbleChart, or add one after the other chart groups.
+    pub fn set_bubble_chart(&mut self, value:BubbleChart)-> &mut PlotArea {
+        self.set_chart_group(ChartGroup::Bubble(value))
+    }
+
+    pub fn add_bubble_chart(&mut self, value:BubbleChart)-> &mut PlotArea {
+        self.chart_group.push(ChartGroup::Bubble(value));
         self
     }
 
@@ -145,97 +497,405 @@ impl PlotArea {
         self
     }
 
-    pub fn get_formula_mut(&mut self)-> Vec<&mut Formula> {
-        let mut result:Vec<&mut Formula> = Vec::default();
-        match &mut self.line_chart {
-            Some(v) => {
-                for ser in v.get_area_chart_series_mut() {
-                    for formula in ser.get_formula_mut() {
-                        result.push(formula);
-                    }
-                }
-            }
-            None => {}
+    pub fn get_date_axis(&self)-> &Vec<DateAxis> {
+        &self.date_axis
+    }
+
+    pub fn get_date_axis_mut(&mut self)-> &mut Vec<DateAxis> {
+        &mut self.date_axis
+    }
+
+    pub fn set_date_axis(&mut self, value:DateAxis)-> &mut PlotArea {
+        self.date_axis.push(value);
+        self
+    }
+
+    pub fn get_series_axis(&self)-> &Vec<SeriesAxis> {
+        &self.series_axis
+    }
+
+    pub fn get_series_axis_mut(&mut self)-> &mut Vec<SeriesAxis> {
+        &mut self.series_axis
+    }
+
+    pub fn set_series_axis(&mut self, value:SeriesAxis)-> &mut PlotArea {
+        self.series_axis.push(value);
+        self
+    }
+
+    pub fn get_area_chart_series(&self)-> Vec<&AreaChartSeries> {
+        let mut result:Vec<&AreaChartSeries> = Vec::default();
+        for v in &self.chart_group {
+            result.extend(v.get_area_chart_series());
         }
-        match &mut self.pie_chart {
-            Some(v) => {
-                for ser in v.get_area_chart_series_mut() {
-                    for formula in ser.get_formula_mut() {
-                        result.push(formula);
-                    }
-                }
-            }
-            None => {}
+        result
+    }
+
+    pub fn get_area_chart_series_mut(&mut self)-> Vec<&mut AreaChartSeries> {
+        let mut result:Vec<&mut AreaChartSeries> = Vec::default();
+        for v in &mut self.chart_group {
+            result.extend(v.get_area_chart_series_mut());
         }
-        match &mut self.doughnut_chart {
-            Some(v) => {
-                for ser in v.get_area_chart_series_mut() {
-                    for formula in ser.get_formula_mut() {
-                        result.push(formula);
-                    }
-                }
-            }
-            None => {}
+        result
+    }
+
+    /// Get the series whose index is `index`, whatever chart draws it.
+    pub fn get_area_chart_series_by_index_mut(&mut self, index:usize)-> Option<&mut AreaChartSeries> {
+        let index = index.to_string();
+        self.get_area_chart_series_mut().into_iter().find(|v| v.get_index().get_val() == index)
+    }
+
+    /// Add a series to the first chart in the plot area.
+    /// # Arguments
+    /// * `name_ref` - reference to the series name. ex) "Sheet1!$B$1". Empty for no name.
+    /// * `categories_ref` - reference to the categories, or the x values of a scatter or bubble chart. Empty for none.
+    /// * `values_ref` - reference to the values. ex) "Sheet1!$B$2:$B$6"
+    ///
+    /// The bubble sizes of a bubble chart series are set with `AreaChartSeries::set_bubble_size`.
+    pub fn add_series(&mut self, name_ref:&str, categories_ref:&str, values_ref:&str)-> &mut PlotArea {
+        let chart_type = match self.get_first_chart_type() {
+            Some(v) => v,
+            None => return self,
+        };
+        let series = new_series(&chart_type, self.get_area_chart_series().len(), name_ref, categories_ref, values_ref);
+        if let Some(v) = self.get_chart_group_series_mut(&chart_type, &[]) {
+            v.push(series);
         }
-        match &mut self.scatter_chart {
-            Some(v) => {
-                for ser in v.get_area_chart_series_mut() {
-                    for formula in ser.get_formula_mut() {
-                        result.push(formula);
-                    }
-                }
-            }
-            None => {}
+        self
+    }
+
+    /// Add a series to the chart of `chart_type` on the primary or the secondary axes.
+    /// The chart, and the secondary axes, are created when they do not exist yet.
+    /// Pie, doughnut and 3D charts always use the primary axes.
+    /// # Arguments
+    /// * `chart_type` - kind of chart that draws the series.
+    /// * `is_secondary_axis` - draw the series against the secondary value axis.
+    /// * `name_ref` - reference to the series name. ex) "Sheet1!$C$1". Empty for no name.
+    /// * `categories_ref` - reference to the categories, or the x values of a scatter or bubble chart. Empty for none.
+    /// * `values_ref` - reference to the values. ex) "Sheet1!$C$2:$C$6"
+    pub fn add_series_with_chart_type(&mut self, chart_type:ChartType, is_secondary_axis:bool, name_ref:&str, categories_ref:&str, values_ref:&str)-> &mut PlotArea {
+        let is_secondary_axis = is_secondary_axis && chart_type.has_axes() && !chart_type.is_3d();
+        let axis_ids = self.get_axis_id_pair(is_secondary_axis, chart_type.has_axes());
+        let axis_ids:Vec<&str> = axis_ids.iter().map(|v| v.as_str()).collect();
+        if self.get_chart_group_series_mut(&chart_type, &axis_ids).is_none() {
+            self.add_chart_group(&chart_type, &axis_ids, is_secondary_axis);
         }
-        match &mut self.bar_chart {
-            Some(v) => {
-                for ser in v.get_area_chart_series_mut() {
-                    for formula in ser.get_formula_mut() {
-                        result.push(formula);
-                    }
-                }
-            }
-            None => {}
+        let series = new_series(&chart_type, self.get_area_chart_series().len(), name_ref, categories_ref, values_ref);
+        if let Some(v) = self.get_chart_group_series_mut(&chart_type, &axis_ids) {
+            v.push(series);
         }
-        match &mut self.bar_3d_chart {
-            Some(v) => {
-                for ser in v.get_area_chart_series_mut() {
-                    for formula in ser.get_formula_mut() {
-                        result.push(formula);
-                    }
-                }
-            }
-            None => {}
+        self
+    }
+
+    /// Set the title of the category axis, or of the x axis of a scatter chart.
+    pub fn set_category_axis_title_text<S: Into<String>>(&mut self, value:S)-> &mut PlotArea {
+        let axis_ids = self.get_axis_id_pair(false, true);
+        let mut title = Title::default();
+        title.set_text(value);
+        self.set_axis_title(&axis_ids[0], title);
+        self
+    }
+
+    /// Set the title of the value axis, or of the y axis of a scatter chart.
+    pub fn set_value_axis_title_text<S: Into<String>>(&mut self, value:S)-> &mut PlotArea {
+        let axis_ids = self.get_axis_id_pair(false, true);
+        let mut title = Title::default();
+        title.set_text(value);
+        self.set_axis_title(&axis_ids[1], title);
+        self
+    }
+
+    /// Set the title of the secondary value axis.
+    pub fn set_secondary_value_axis_title_text<S: Into<String>>(&mut self, value:S)-> &mut PlotArea {
+        let axis_ids = self.get_axis_id_pair(true, true);
+        let mut title = Title::default();
+        title.set_text(value);
+        self.set_axis_title(&axis_ids[1], title);
+        self
+    }
+
+    pub(crate) fn new_chart(chart_type:&ChartType)-> PlotArea {
+        let mut plot_area = PlotArea::default();
+        let axis_ids = if chart_type.has_axes() {
+            vec![PlotArea::CATEGORY_AXIS_ID, PlotArea::VALUE_AXIS_ID]
+        } else {
+            vec![]
+        };
+        plot_area.add_chart_group(chart_type, &axis_ids, false);
+        plot_area
+    }
+
+    fn get_first_chart_type(&self)-> Option<ChartType> {
+        self.chart_group.first().map(|v| v.get_chart_type())
+    }
+
+    fn get_chart_group_series_mut(&mut self, chart_type:&ChartType, axis_ids:&[&str])-> Option<&mut Vec<AreaChartSeries>> {
+        self.chart_group.iter_mut()
+            .find(|v| &v.get_chart_type() == chart_type && is_axis_id_pair(v.get_axis_id(), axis_ids))
+            .map(|v| v.get_area_chart_series_mut())
+    }
+
+    fn set_chart_group(&mut self, value:ChartGroup)-> &mut PlotArea {
+        let position = self.chart_group.iter().position(|v| std::mem::discriminant(v) == std::mem::discriminant(&value));
+        match position {
+            Some(i) => self.chart_group[i] = value,
+            None => self.chart_group.push(value),
         }
-        result
+        self
     }
 
-    pub(crate) fn is_support(&self) -> bool {
-        match &self.line_chart {
-            Some(_) => {return true;},
-            None => {}
+    // The primary pair is the first one with a visible category axis, the secondary pair any other one.
+    fn get_axis_id_pair(&self, is_secondary_axis:bool, has_axes:bool)-> Vec<String> {
+        if !has_axes {
+            return Vec::new();
+        }
+        let pairs:Vec<Vec<String>> = self.chart_group.iter()
+            .map(|v| v.get_axis_id())
+            .filter(|v| v.len() > 1)
+            .map(|v| vec![v[0].get_val().to_string(), v[1].get_val().to_string()])
+            .collect();
+        let primary = match pairs.iter().find(|v| !self.is_deleted_axis(&v[0])).or_else(|| pairs.first()) {
+            Some(v) => v.clone(),
+            None => vec![PlotArea::CATEGORY_AXIS_ID.to_string(), PlotArea::VALUE_AXIS_ID.to_string()],
+        };
+        if !is_secondary_axis {
+            return primary;
+        }
+        match pairs.iter().find(|v| v != &&primary) {
+            Some(v) => v.clone(),
+            None => vec![PlotArea::SECONDARY_CATEGORY_AXIS_ID.to_string(), PlotArea::SECONDARY_VALUE_AXIS_ID.to_string()],
+        }
+    }
+
+    fn has_axis(&self, axis_id:&str)-> bool {
+        self.category_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id)
+            || self.value_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id)
+            || self.date_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id)
+            || self.series_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id)
+    }
+
+    fn is_deleted_axis(&self, axis_id:&str)-> bool {
+        self.category_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id && v.get_delete().get_val() == "1")
+            || self.value_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id && v.get_delete().get_val() == "1")
+            || self.date_axis.iter().any(|v| v.get_axis_id().get_val() == axis_id && v.get_delete().get_val() == "1")
+    }
+
+    fn set_axis_title(&mut self, axis_id:&str, title:Title) {
+        if let Some(v) = self.category_axis.iter_mut().find(|v| v.get_axis_id().get_val() == axis_id) {
+            v.set_title(title);
+        } else if let Some(v) = self.value_axis.iter_mut().find(|v| v.get_axis_id().get_val() == axis_id) {
+            v.set_title(title);
+        } else if let Some(v) = self.date_axis.iter_mut().find(|v| v.get_axis_id().get_val() == axis_id) {
+            v.set_title(title);
+        }
+    }
+
+    fn add_chart_group(&mut self, chart_type:&ChartType, axis_id_pair:&[&str], is_secondary_axis:bool) {
+        let mut axis_id_list:Vec<&str> = axis_id_pair.to_vec();
+        if matches!(chart_type, ChartType::Surface | ChartType::Line3D | ChartType::Area3D) {
+            axis_id_list.push(PlotArea::SERIES_AXIS_ID);
         }
-        match &self.pie_chart {
-            Some(_) => {return true;},
-            None => {}
+        let mut axis_ids:Vec<AxisId> = Vec::new();
+        for id in &axis_id_list {
+            let mut axis_id = AxisId::default();
+            axis_id.set_val(*id);
+            axis_ids.push(axis_id);
         }
-        match &self.doughnut_chart {
-            Some(_) => {return true;},
-            None => {}
+
+        match chart_type {
+            ChartType::Line => {
+                let mut obj = LineChart::default();
+                obj.get_grouping_mut().set_val("standard");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.get_marker_mut().set_val("1");
+                obj.get_smooth_mut().set_val("0");
+                obj.set_axis_id(axis_ids);
+                self.add_line_chart(obj);
+            },
+            ChartType::Pie => {
+                let mut obj = PieChart::default();
+                obj.get_vary_colors_mut().set_val("1");
+                obj.set_data_labels(new_data_labels());
+                obj.get_first_slice_angle_mut().set_val("0");
+                self.add_pie_chart(obj);
+            },
+            ChartType::Doughnut => {
+                let mut obj = DoughnutChart::default();
+                obj.get_vary_colors_mut().set_val("1");
+                obj.set_data_labels(new_data_labels());
+                obj.get_first_slice_angle_mut().set_val("0");
+                obj.get_hole_size_mut().set_val("50");
+                self.add_doughnut_chart(obj);
+            },
+            ChartType::Scatter => {
+                let mut obj = ScatterChart::default();
+                obj.get_scatter_style_mut().set_val("lineMarker");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.set_axis_id(axis_ids);
+                self.add_scatter_chart(obj);
+            },
+            ChartType::Bar | ChartType::Column => {
+                let mut obj = BarChart::default();
+                obj.get_bar_direction_mut().set_val(if chart_type == &ChartType::Bar {"bar"} else {"col"});
+                obj.get_grouping_mut().set_val("clustered");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.get_gap_width_mut().set_val("150");
+                obj.get_overlap_mut().set_val("0");
+                obj.set_axis_id(axis_ids);
+                self.add_bar_chart(obj);
+            },
+            ChartType::Bar3D | ChartType::Column3D => {
+                let mut obj = Bar3DChart::default();
+                obj.get_bar_direction_mut().set_val(if chart_type == &ChartType::Bar3D {"bar"} else {"col"});
+                obj.get_grouping_mut().set_val("clustered");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.get_gap_width_mut().set_val("150");
+                obj.get_shape_mut().set_val("box");
+                obj.set_axis_id(axis_ids);
+                self.add_bar_3d_chart(obj);
+            },
+            ChartType::Radar => {
+                let mut obj = RadarChart::default();
+                obj.get_radar_style_mut().set_val("marker");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.set_axis_id(axis_ids);
+                self.add_radar_chart(obj);
+            },
+            ChartType::Bubble => {
+                let mut obj = BubbleChart::default();
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                let mut bubble_scale = BubbleScale::default();
+                bubble_scale.set_val("100");
+                obj.set_bubble_scale(bubble_scale);
+                let mut show_negative_bubbles = ShowNegativeBubbles::default();
+                show_negative_bubbles.set_val("0");
+                obj.set_show_negative_bubbles(show_negative_bubbles);
+                obj.set_axis_id(axis_ids);
+                self.add_bubble_chart(obj);
+            },
+            ChartType::Stock => {
+                let mut obj = StockChart::default();
+                obj.set_data_labels(new_data_labels());
+                obj.set_high_low_lines(HighLowLines::default());
+                obj.set_axis_id(axis_ids);
+                self.add_stock_chart(obj);
+            },
+            ChartType::Surface => {
+                let mut obj = Surface3DChart::default();
+                let mut wireframe = Wireframe::default();
+                wireframe.set_val("0");
+                obj.set_wireframe(wireframe);
+                obj.set_axis_id(axis_ids);
+                self.add_surface_3d_chart(obj);
+            },
+            ChartType::Line3D => {
+                let mut obj = Line3DChart::default();
+                obj.get_grouping_mut().set_val("standard");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.set_axis_id(axis_ids);
+                self.add_line_3d_chart(obj);
+            },
+            ChartType::Area => {
+                let mut obj = AreaChart::default();
+                obj.get_grouping_mut().set_val("standard");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.set_axis_id(axis_ids);
+                self.add_area_chart(obj);
+            },
+            ChartType::Area3D => {
+                let mut obj = Area3DChart::default();
+                obj.get_grouping_mut().set_val("standard");
+                obj.get_vary_colors_mut().set_val("0");
+                obj.set_data_labels(new_data_labels());
+                obj.set_axis_id(axis_ids);
+                self.add_area_3d_chart(obj);
+            },
+            ChartType::Pie3D => {
+                let mut obj = Pie3DChart::default();
+                obj.get_vary_colors_mut().set_val("1");
+                obj.set_data_labels(new_data_labels());
+                self.add_pie_3d_chart(obj);
+            },
+            ChartType::PieOfPie | ChartType::BarOfPie => {
+                let mut obj = OfPieChart::default();
+                obj.get_of_pie_type_mut().set_val(if chart_type == &ChartType::PieOfPie {"pie"} else {"bar"});
+                obj.get_vary_colors_mut().set_val("1");
+                obj.set_data_labels(new_data_labels());
+                let mut gap_width = GapWidth::default();
+                gap_width.set_val("100");
+                obj.set_gap_width(gap_width);
+                let mut second_pie_size = SecondPieSize::default();
+                second_pie_size.set_val("75");
+                obj.set_second_pie_size(second_pie_size);
+                obj.add_series_lines(SeriesLines::default());
+                self.add_of_pie_chart(obj);
+            },
         }
-        match &self.scatter_chart {
-            Some(_) => {return true;},
-            None => {}
+
+        if !chart_type.has_axes() {
+            return;
         }
-        match &self.bar_chart {
-            Some(_) => {return true;},
-            None => {}
+        let (category_axis_id, value_axis_id) = (axis_id_pair[0], axis_id_pair[1]);
+        let (category_position, value_position) = match (chart_type, is_secondary_axis) {
+            (ChartType::Bar, false) | (ChartType::Bar3D, false) => ("l", "b"),
+            (ChartType::Bar, true) => ("l", "t"),
+            (_, false) => ("b", "l"),
+            (_, true) => ("b", "r"),
+        };
+        let is_xy = matches!(chart_type, ChartType::Scatter | ChartType::Bubble);
+        if !self.has_axis(category_axis_id) {
+            if is_xy {
+                let mut x_axis = new_value_axis(category_axis_id, value_axis_id, category_position);
+                x_axis.get_cross_between_mut().set_val("midCat");
+                if is_secondary_axis {
+                    x_axis.get_delete_mut().set_val("1");
+                }
+                self.set_value_axis(x_axis);
+            } else {
+                let mut category_axis = new_category_axis(category_axis_id, value_axis_id, category_position);
+                if is_secondary_axis {
+                    category_axis.get_delete_mut().set_val("1");
+                }
+                self.set_category_axis(category_axis);
+            }
         }
-        match &self.bar_3d_chart {
-            Some(_) => {return true;},
-            None => {}
+        if !self.has_axis(value_axis_id) {
+            let mut value_axis = new_value_axis(value_axis_id, category_axis_id, value_position);
+            if is_xy {
+                value_axis.get_cross_between_mut().set_val("midCat");
+            }
+            if is_secondary_axis {
+                value_axis.get_crosses_mut().set_val("max");
+            } else {
+                value_axis.set_major_gridlines(MajorGridlines::default());
+            }
+            self.set_value_axis(value_axis);
         }
-        false
+        if axis_id_list.len() > 2 && !self.has_axis(PlotArea::SERIES_AXIS_ID) {
+            self.set_series_axis(new_series_axis(PlotArea::SERIES_AXIS_ID, value_axis_id, "b"));
+        }
+    }
+
+    pub fn get_formula_mut(&mut self)-> Vec<&mut Formula> {
+        let mut result:Vec<&mut Formula> = Vec::default();
+        for ser in self.get_area_chart_series_mut() {
+            for formula in ser.get_formula_mut() {
+                result.push(formula);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn is_support(&self) -> bool {
+        !self.chart_group.is_empty()
     }
 
     pub(crate) fn set_attributes(
@@ -251,35 +911,85 @@ impl PlotArea {
                         b"c:layout" => {
                             self.layout.set_attributes(reader, e, false);
                         },
+                        b"c:areaChart" => {
+                            let mut obj = AreaChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_area_chart(obj);
+                        },
+                        b"c:area3DChart" => {
+                            let mut obj = Area3DChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_area_3d_chart(obj);
+                        },
                         b"c:lineChart" => {
                             let mut obj = LineChart::default();
                             obj.set_attributes(reader, e);
-                            self.set_line_chart(obj);
+                            self.add_line_chart(obj);
                         },
-                        b"c:pieChart" => {
-                            let mut obj = PieChart::default();
+                        b"c:line3DChart" => {
+                            let mut obj = Line3DChart::default();
                             obj.set_attributes(reader, e);
-                            self.set_pie_chart(obj);
+                            self.add_line_3d_chart(obj);
                         },
-                        b"c:doughnutChart" => {
-                            let mut obj = DoughnutChart::default();
+                        b"c:stockChart" => {
+                            let mut obj = StockChart::default();
                             obj.set_attributes(reader, e);
-                            self.set_doughnut_chart(obj);
+                            self.add_stock_chart(obj);
+                        },
+                        b"c:radarChart" => {
+                            let mut obj = RadarChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_radar_chart(obj);
                         },
                         b"c:scatterChart" => {
                             let mut obj = ScatterChart::default();
                             obj.set_attributes(reader, e);
-                            self.set_scatter_chart(obj);
+                            self.add_scatter_chart(obj);
+                        },
+                        b"c:pieChart" => {
+                            let mut obj = PieChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_pie_chart(obj);
+                        },
+                        b"c:pie3DChart" => {
+                            let mut obj = Pie3DChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_pie_3d_chart(obj);
+                        },
+                        b"c:doughnutChart" => {
+                            let mut obj = DoughnutChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_doughnut_chart(obj);
                         },
                         b"c:barChart" => {
                             let mut obj = BarChart::default();
                             obj.set_attributes(reader, e);
-                            self.set_bar_chart(obj);
+                            self.add_bar_chart(obj);
                         },
                         b"c:bar3DChart" => {
                             let mut obj = Bar3DChart::default();
                             obj.set_attributes(reader, e);
-                            self.set_bar_3d_chart(obj);
+                            self.add_bar_3d_chart(obj);
+                        },
+                        b"c:ofPieChart" => {
+                            let mut obj = OfPieChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_of_pie_chart(obj);
+                        },
+                        b"c:surfaceChart" => {
+                            let mut obj = SurfaceChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_surface_chart(obj);
+                        },
+                        b"c:surface3DChart" => {
+                            let mut obj = Surface3DChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_surface_3d_chart(obj);
+                        },
+                        b"c:bubbleChart" => {
+                            let mut obj = BubbleChart::default();
+                            obj.set_attributes(reader, e);
+                            self.add_bubble_chart(obj);
                         },
                         b"c:catAx" => {
                             let mut obj = CategoryAxis::default();
@@ -291,6 +1001,16 @@ impl PlotArea {
                             obj.set_attributes(reader, e);
                             self.set_value_axis(obj);
                         },
+                        b"c:dateAx" => {
+                            let mut obj = DateAxis::default();
+                            obj.set_attributes(reader, e);
+                            self.set_date_axis(obj);
+                        },
+                        b"c:serAx" => {
+                            let mut obj = SeriesAxis::default();
+                            obj.set_attributes(reader, e);
+                            self.set_series_axis(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -315,52 +1035,143 @@ impl PlotArea {
         // c:layout
         &self.layout.write_to(writer);
 
-        // c:lineChart
-        match &self.line_chart {
-            Some(v) => {v.write_to(writer);},
-            None => {}
+        // c:areaChart, c:barChart, c:lineChart, ...
+        for v in &self.chart_group {
+            v.write_to(writer);
         }
 
-        // c:pieChart
-        match &self.pie_chart {
-            Some(v) => {v.write_to(writer);},
-            None => {}
+        // c:catAx
+        for v in &self.category_axis {
+            v.write_to(writer);
         }
 
-        // c:doughnutChart
-        match &self.doughnut_chart {
-            Some(v) => {v.write_to(writer);},
-            None => {}
+        // c:valAx
+        for v in &self.value_axis {
+            v.write_to(writer);
         }
 
-        // c:scatterChart
-        match &self.scatter_chart {
-            Some(v) => {v.write_to(writer);},
-            None => {}
+        // c:dateAx
+        for v in &self.date_axis {
+            v.write_to(writer);
         }
 
-        // c:barChart
-        match &self.bar_chart {
-            Some(v) => {v.write_to(writer);},
-            None => {}
+        // c:serAx
+        for v in &self.series_axis {
+            v.write_to(writer);
         }
 
-        // c:bar3DChart
-        match &self.bar_3d_chart {
-            Some(v) => {v.write_to(writer);},
-            None => {}
-        }
+        write_end_tag(writer, "c:plotArea");
+    }
+}
 
-        // c:catAx
-        for v in &self.category_axis {
-            v.write_to(writer);
-        }
+fn is_axis_id_pair(axis_ids:&[AxisId], pair:&[&str])-> bool {
+    pair.is_empty() || (axis_ids.len() > 1 && axis_ids[0].get_val() == pair[0] && axis_ids[1].get_val() == pair[1])
+}
 
-        // c:valAx
-        for v in &self.value_axis {
-            v.write_to(writer);
+fn new_series(chart_type:&ChartType, index:usize, name_ref:&str, categories_ref:&str, values_ref:&str)-> AreaChartSeries {
+    let mut series = AreaChartSeries::default();
+    series.get_index_mut().set_val(index.to_string());
+    series.get_order_mut().set_val(index.to_string());
+    if name_ref != "" {
+        let mut series_text = SeriesText::default();
+        series_text.get_string_reference_mut().get_formula_mut().set_address_str(name_ref);
+        series.set_series_text(series_text);
+    }
+
+    if matches!(chart_type, ChartType::Scatter | ChartType::Bubble) {
+        if categories_ref != "" {
+            let mut x_values = XValues::default();
+            x_values.get_number_reference_mut().get_formula_mut().set_address_str(categories_ref);
+            series.set_x_values(x_values);
+        }
+        let mut y_values = YValues::default();
+        y_values.get_number_reference_mut().get_formula_mut().set_address_str(values_ref);
+        series.set_y_values(y_values);
+    } else {
+        if categories_ref != "" {
+            let mut category_axis_data = CategoryAxisData::default();
+            category_axis_data.get_string_reference_mut().get_formula_mut().set_address_str(categories_ref);
+            series.set_category_axis_data(category_axis_data);
         }
+        let mut values = Values::default();
+        values.get_number_reference_mut().get_formula_mut().set_address_str(values_ref);
+        series.set_values(values);
+    }
 
-        write_end_tag(writer, "c:plotArea");
+    if matches!(chart_type, ChartType::Bar | ChartType::Column | ChartType::Bar3D | ChartType::Column3D) {
+        let mut invert_if_negative = InvertIfNegative::default();
+        invert_if_negative.set_val("0");
+        series.set_invert_if_negative(invert_if_negative);
+    }
+    if matches!(chart_type, ChartType::Line | ChartType::Scatter) {
+        let mut smooth = Smooth::default();
+        smooth.set_val("0");
+        series.set_smooth(smooth);
+    }
+    if chart_type == &ChartType::Bubble {
+        let mut bubble_3d = Bubble3D::default();
+        bubble_3d.set_val("0");
+        series.set_bubble_3d(bubble_3d);
     }
+    series
+}
+
+fn new_data_labels()-> DataLabels {
+    let mut data_labels = DataLabels::default();
+    data_labels.get_show_legend_key_mut().set_val("0");
+    data_labels.get_show_value_mut().set_val("0");
+    data_labels.get_show_category_name_mut().set_val("0");
+    data_labels.get_show_series_name_mut().set_val("0");
+    data_labels.get_show_percent_mut().set_val("0");
+    data_labels.get_show_bubble_size_mut().set_val("0");
+    data_labels
+}
+
+fn new_category_axis(axis_id:&str, crossing_axis_id:&str, position:&str)-> CategoryAxis {
+    let mut axis = CategoryAxis::default();
+    axis.get_axis_id_mut().set_val(axis_id);
+    axis.get_scaling_mut().get_orientation_mut().set_val("minMax");
+    axis.get_delete_mut().set_val("0");
+    axis.get_axis_position_mut().set_val(position);
+    axis.get_major_tick_mark_mut().set_val("out");
+    axis.get_minor_tick_mark_mut().set_val("none");
+    axis.get_tick_label_position_mut().set_val("nextTo");
+    axis.get_tick_crossing_axis_mut().set_val(crossing_axis_id);
+    axis.get_crosses_mut().set_val("autoZero");
+    axis.get_auto_labeled_mut().set_val("1");
+    axis.get_label_alignment_mut().set_val("ctr");
+    axis.get_label_offset_mut().set_val("100");
+    axis.get_no_multi_level_labels_mut().set_val("0");
+    axis
+}
+
+fn new_value_axis(axis_id:&str, crossing_axis_id:&str, position:&str)-> ValueAxis {
+    let mut axis = ValueAxis::default();
+    axis.get_axis_id_mut().set_val(axis_id);
+    axis.get_scaling_mut().get_orientation_mut().set_val("minMax");
+    axis.get_delete_mut().set_val("0");
+    axis.get_axis_position_mut().set_val(position);
+    axis.get_numbering_format_mut().set_format_code("General");
+    axis.get_numbering_format_mut().set_source_linked("1");
+    axis.get_major_tick_mark_mut().set_val("out");
+    axis.get_minor_tick_mark_mut().set_val("none");
+    axis.get_tick_label_position_mut().set_val("nextTo");
+    axis.get_tick_crossing_axis_mut().set_val(crossing_axis_id);
+    axis.get_crosses_mut().set_val("autoZero");
+    axis.get_cross_between_mut().set_val("between");
+    axis
+}
+
+fn new_series_axis(axis_id:&str, crossing_axis_id:&str, position:&str)-> SeriesAxis {
+    let mut axis = SeriesAxis::default();
+    axis.get_axis_id_mut().set_val(axis_id);
+    axis.get_scaling_mut().get_orientation_mut().set_val("minMax");
+    axis.get_delete_mut().set_val("0");
+    axis.get_axis_position_mut().set_val(position);
+    axis.get_major_tick_mark_mut().set_val("out");
+    axis.get_minor_tick_mark_mut().set_val("none");
+    axis.get_tick_label_position_mut().set_val("nextTo");
+    axis.get_tick_crossing_axis_mut().set_val(crossing_axis_id);
+    axis.get_crosses_mut().set_val("autoZero");
+    axis
 }
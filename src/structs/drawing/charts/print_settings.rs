@@ -8,7 +8,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct PrintSettings {
     header_footer: HeaderFooter,
     page_margins: PageMargins,
@@ -19,7 +19,7 @@ impl PrintSettings {
         &self.header_footer
     }
 
-    pub fn get_header_footer_mut(&mut self)-> &HeaderFooter {
+    pub fn get_header_footer_mut(&mut self)-> &mut HeaderFooter {
         &mut self.header_footer
     }
 
@@ -32,7 +32,7 @@ impl PrintSettings {
         &self.page_margins
     }
 
-    pub fn get_page_margins_mut(&mut self)-> &PageMargins {
+    pub fn get_page_margins_mut(&mut self)-> &mut PageMargins {
         &mut self.page_margins
     }
 
@@ -45,7 +45,7 @@ impl PrintSettings {
         &self.page_setup
     }
 
-    pub fn get_page_setup_mut(&mut self)-> &PageSetup {
+    pub fn get_page_setup_mut(&mut self)-> &mut PageSetup {
         &mut self.page_setup
     }
 
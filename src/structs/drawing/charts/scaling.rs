@@ -1,21 +1,40 @@
 // c:scaling
+use super::LogBase;
 use super::Orientation;
+use super::MaxAxisValue;
+use super::MinAxisValue;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Scaling {
+    log_base: Option<LogBase>,
     orientation: Orientation,
+    max_axis_value: Option<MaxAxisValue>,
+    min_axis_value: Option<MinAxisValue>,
 }
 impl Scaling {
+    pub fn get_log_base(&self)-> &Option<LogBase> {
+        &self.log_base
+    }
+
+    pub fn get_log_base_mut(&mut self)-> &mut Option<LogBase> {
+        &mut self.log_base
+    }
+
+    pub fn set_log_base(&mut self, value:LogBase)-> &mut Scaling {
+        self.log_base = Some(value);
+        self
+    }
+
     pub fn get_orientation(&self)-> &Orientation {
         &self.orientation
     }
 
-    pub fn get_orientation_mut(&mut self)-> &Orientation {
+    pub fn get_orientation_mut(&mut self)-> &mut Orientation {
         &mut self.orientation
     }
 
@@ -24,6 +43,32 @@ impl Scaling {
         self
     }
 
+    pub fn get_max_axis_value(&self)-> &Option<MaxAxisValue> {
+        &self.max_axis_value
+    }
+
+    pub fn get_max_axis_value_mut(&mut self)-> &mut Option<MaxAxisValue> {
+        &mut self.max_axis_value
+    }
+
+    pub fn set_max_axis_value(&mut self, value:MaxAxisValue)-> &mut Scaling {
+        self.max_axis_value = Some(value);
+        self
+    }
+
+    pub fn get_min_axis_value(&self)-> &Option<MinAxisValue> {
+        &self.min_axis_value
+    }
+
+    pub fn get_min_axis_value_mut(&mut self)-> &mut Option<MinAxisValue> {
+        &mut self.min_axis_value
+    }
+
+    pub fn set_min_axis_value(&mut self, value:MinAxisValue)-> &mut Scaling {
+        self.min_axis_value = Some(value);
+        self
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -34,9 +79,24 @@ impl Scaling {
             match reader.read_event(&mut buf) {
                 Ok(Event::Empty(ref e)) => {
                     match e.name() {
+                        b"c:logBase" => {
+                            let mut obj = LogBase::default();
+                            obj.set_attributes(reader, e);
+                            self.set_log_base(obj);
+                        },
                         b"c:orientation" => {
                             self.orientation.set_attributes(reader, e);
                         },
+                        b"c:max" => {
+                            let mut obj = MaxAxisValue::default();
+                            obj.set_attributes(reader, e);
+                            self.set_max_axis_value(obj);
+                        },
+                        b"c:min" => {
+                            let mut obj = MinAxisValue::default();
+                            obj.set_attributes(reader, e);
+                            self.set_min_axis_value(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -58,9 +118,27 @@ impl Scaling {
         // c:scaling
         write_start_tag(writer, "c:scaling", vec![], false);
 
+        // c:logBase
+        match &self.log_base {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
         // c:orientation
         &self.orientation.write_to(writer);
-        
+
+        // c:max
+        match &self.max_axis_value {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:min
+        match &self.min_axis_value {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
         write_end_tag(writer, "c:scaling");
     }
 }
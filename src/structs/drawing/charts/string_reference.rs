@@ -1,13 +1,15 @@
 // c:strRef
 use super::Formula;
 use super::StringCache;
+use super::StringPoint;
+use super::super::super::Spreadsheet;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct StringReference {
     formula: Formula,
     string_cache: StringCache,
@@ -39,6 +41,31 @@ impl StringReference {
         self
     }
 
+    /// Fill the cache with the text of the referenced cells.
+    /// Keeps an existing cache unless `is_overwrite` is set.
+    pub(crate) fn update_cache(&mut self, spreadsheet:&Spreadsheet, is_overwrite:&bool) {
+        if !is_overwrite && self.string_cache.get_point_count().get_val() != "" {
+            return;
+        }
+        let (worksheet, cells) = match self.formula.get_referenced_cells(spreadsheet) {
+            Some(v) => v,
+            None => return,
+        };
+        let mut string_cache = StringCache::default();
+        string_cache.get_point_count_mut().set_val(cells.len().to_string());
+        for (index, (col, row)) in cells.iter().enumerate() {
+            let value = worksheet.get_formatted_value_by_column_and_row(*col, *row);
+            if value == "" {
+                continue;
+            }
+            let mut string_point = StringPoint::default();
+            string_point.set_index(index.to_string());
+            string_point.get_numeric_value_mut().set_val(value);
+            string_cache.add_string_point(string_point);
+        }
+        self.string_cache = string_cache;
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
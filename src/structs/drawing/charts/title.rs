@@ -2,13 +2,16 @@
 use super::ChartText;
 use super::Layout;
 use super::Overlay;
+use super::RichText;
+use super::super::Paragraph;
+use super::super::Run;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Title {
     chart_text: Option<ChartText>,
     layout: Option<Layout>,
@@ -54,6 +57,37 @@ impl Title {
         self
     }
 
+    /// Get the plain text of the title.
+    pub fn get_text(&self) -> String {
+        let mut result = String::from("");
+        match &self.chart_text {
+            Some(v) => {
+                for paragraph in v.get_rich_text().get_paragraph() {
+                    for run in paragraph.get_run() {
+                        result.push_str(run.get_text());
+                    }
+                }
+            },
+            None => {}
+        }
+        result
+    }
+
+    /// Replace the title with plain text.
+    pub fn set_text<S: Into<String>>(&mut self, value:S) -> &mut Title {
+        let mut run = Run::default();
+        run.set_text(value);
+        let mut paragraph = Paragraph::default();
+        paragraph.add_run(run);
+        let mut rich_text = RichText::default();
+        rich_text.add_paragraph(paragraph);
+        let mut chart_text = ChartText::default();
+        chart_text.set_rich_text(rich_text);
+        self.set_chart_text(chart_text);
+        self.overlay.set_val("0");
+        self
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -0,0 +1,349 @@
+// c:trendline
+use super::TrendlineName;
+use super::ShapeProperties;
+use super::TrendlineType;
+use super::PolynomialOrder;
+use super::Period;
+use super::Forward;
+use super::Backward;
+use super::Intercept;
+use super::DisplayRSquaredValue;
+use super::DisplayEquation;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Trendline {
+    trendline_name: Option<TrendlineName>,
+    shape_properties: Option<ShapeProperties>,
+    trendline_type: TrendlineType,
+    polynomial_order: Option<PolynomialOrder>,
+    period: Option<Period>,
+    forward: Option<Forward>,
+    backward: Option<Backward>,
+    intercept: Option<Intercept>,
+    display_r_squared_value: Option<DisplayRSquaredValue>,
+    display_equation: Option<DisplayEquation>,
+}
+impl Trendline {
+    pub fn get_trendline_name(&self)-> &Option<TrendlineName> {
+        &self.trendline_name
+    }
+
+    pub fn get_trendline_name_mut(&mut self)-> &mut Option<TrendlineName> {
+        &mut self.trendline_name
+    }
+
+    pub fn set_trendline_name(&mut self, value:TrendlineName)-> &mut Trendline {
+        self.trendline_name = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self)-> &Option<ShapeProperties> {
+        &self.shape_properties
+    }
+
+    pub fn get_shape_properties_mut(&mut self)-> &mut Option<ShapeProperties> {
+        &mut self.shape_properties
+    }
+
+    pub fn set_shape_properties(&mut self, value:ShapeProperties)-> &mut Trendline {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub fn get_trendline_type(&self)-> &TrendlineType {
+        &self.trendline_type
+    }
+
+    pub fn get_trendline_type_mut(&mut self)-> &mut TrendlineType {
+        &mut self.trendline_type
+    }
+
+    pub fn set_trendline_type(&mut self, value:TrendlineType)-> &mut Trendline {
+        self.trendline_type = value;
+        self
+    }
+
+    pub fn get_polynomial_order(&self)-> &Option<PolynomialOrder> {
+        &self.polynomial_order
+    }
+
+    pub fn get_polynomial_order_mut(&mut self)-> &mut Option<PolynomialOrder> {
+        &mut self.polynomial_order
+    }
+
+    pub fn set_polynomial_order(&mut self, value:PolynomialOrder)-> &mut Trendline {
+        self.polynomial_order = Some(value);
+        self
+    }
+
+    pub fn get_period(&self)-> &Option<Period> {
+        &self.period
+    }
+
+    pub fn get_period_mut(&mut self)-> &mut Option<Period> {
+        &mut self.period
+    }
+
+    pub fn set_period(&mut self, value:Period)-> &mut Trendline {
+        self.period = Some(value);
+        self
+    }
+
+    pub fn get_forward(&self)-> &Option<Forward> {
+        &self.forward
+    }
+
+    pub fn get_forward_mut(&mut self)-> &mut Option<Forward> {
+        &mut self.forward
+    }
+
+    pub fn set_forward(&mut self, value:Forward)-> &mut Trendline {
+        self.forward = Some(value);
+        self
+    }
+
+    pub fn get_backward(&self)-> &Option<Backward> {
+        &self.backward
+    }
+
+    pub fn get_backward_mut(&mut self)-> &mut Option<Backward> {
+        &mut self.backward
+    }
+
+    pub fn set_backward(&mut self, value:Backward)-> &mut Trendline {
+        self.backward = Some(value);
+        self
+    }
+
+    pub fn get_intercept(&self)-> &Option<Intercept> {
+        &self.intercept
+    }
+
+    pub fn get_intercept_mut(&mut self)-> &mut Option<Intercept> {
+        &mut self.intercept
+    }
+
+    pub fn set_intercept(&mut self, value:Intercept)-> &mut Trendline {
+        self.intercept = Some(value);
+        self
+    }
+
+    pub fn get_display_r_squared_value(&self)-> &Option<DisplayRSquaredValue> {
+        &self.display_r_squared_value
+    }
+
+    pub fn get_display_r_squared_value_mut(&mut self)-> &mut Option<DisplayRSquaredValue> {
+        &mut self.display_r_squared_value
+    }
+
+    pub fn set_display_r_squared_value(&mut self, value:DisplayRSquaredValue)-> &mut Trendline {
+        self.display_r_squared_value = Some(value);
+        self
+    }
+
+    pub fn get_display_equation(&self)-> &Option<DisplayEquation> {
+        &self.display_equation
+    }
+
+    pub fn get_display_equation_mut(&mut self)-> &mut Option<DisplayEquation> {
+        &mut self.display_equation
+    }
+
+    pub fn set_display_equation(&mut self, value:DisplayEquation)-> &mut Trendline {
+        self.display_equation = Some(value);
+        self
+    }
+
+    /// Set the order of a polynomial trendline.
+    /// # Arguments
+    /// * `value` - order from 2 to 6.
+    pub fn set_polynomial_order_value(&mut self, value:u32)-> Result<&mut Trendline, &'static str> {
+        if value < 2 || value > 6 {
+            return Err("polynomial order must be from 2 to 6.");
+        }
+        let mut polynomial_order = PolynomialOrder::default();
+        polynomial_order.set_val(value.to_string());
+        self.set_polynomial_order(polynomial_order);
+        Ok(self)
+    }
+
+    /// Set the number of points averaged by a moving average trendline.
+    /// # Arguments
+    /// * `value` - period of 2 or more.
+    pub fn set_period_value(&mut self, value:u32)-> Result<&mut Trendline, &'static str> {
+        if value < 2 {
+            return Err("moving average period must be 2 or more.");
+        }
+        let mut period = Period::default();
+        period.set_val(value.to_string());
+        self.set_period(period);
+        Ok(self)
+    }
+
+    pub(crate) fn new_trendline(trendline_type:&str, is_display_r_squared:bool, is_display_equation:bool)-> Trendline {
+        let mut trendline = Trendline::default();
+        trendline.trendline_type.set_val(trendline_type);
+        if trendline_type == TrendlineType::TYPE_POLYNOMIAL {
+            let _ = trendline.set_polynomial_order_value(2);
+        }
+        if trendline_type == TrendlineType::TYPE_MOVING_AVERAGE {
+            let _ = trendline.set_period_value(2);
+        }
+        let mut display_r_squared_value = DisplayRSquaredValue::default();
+        display_r_squared_value.set_val(if is_display_r_squared {"1"} else {"0"});
+        trendline.set_display_r_squared_value(display_r_squared_value);
+        let mut display_equation = DisplayEquation::default();
+        display_equation.set_val(if is_display_equation {"1"} else {"0"});
+        trendline.set_display_equation(display_equation);
+        trendline
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:name" => {
+                            let mut obj = TrendlineName::default();
+                            obj.set_attributes(reader, e);
+                            self.set_trendline_name(obj);
+                        },
+                        b"c:spPr" => {
+                            let mut obj = ShapeProperties::default();
+                            obj.set_attributes(reader, e);
+                            self.set_shape_properties(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:trendlineType" => {
+                            self.trendline_type.set_attributes(reader, e);
+                        },
+                        b"c:order" => {
+                            let mut obj = PolynomialOrder::default();
+                            obj.set_attributes(reader, e);
+                            self.set_polynomial_order(obj);
+                        },
+                        b"c:period" => {
+                            let mut obj = Period::default();
+                            obj.set_attributes(reader, e);
+                            self.set_period(obj);
+                        },
+                        b"c:forward" => {
+                            let mut obj = Forward::default();
+                            obj.set_attributes(reader, e);
+                            self.set_forward(obj);
+                        },
+                        b"c:backward" => {
+                            let mut obj = Backward::default();
+                            obj.set_attributes(reader, e);
+                            self.set_backward(obj);
+                        },
+                        b"c:intercept" => {
+                            let mut obj = Intercept::default();
+                            obj.set_attributes(reader, e);
+                            self.set_intercept(obj);
+                        },
+                        b"c:dispRSqr" => {
+                            let mut obj = DisplayRSquaredValue::default();
+                            obj.set_attributes(reader, e);
+                            self.set_display_r_squared_value(obj);
+                        },
+                        b"c:dispEq" => {
+                            let mut obj = DisplayEquation::default();
+                            obj.set_attributes(reader, e);
+                            self.set_display_equation(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:trendline" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:trendline"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:trendline
+        write_start_tag(writer, "c:trendline", vec![], false);
+
+        // c:name
+        match &self.trendline_name {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:spPr
+        match &self.shape_properties {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:trendlineType
+        &self.trendline_type.write_to(writer);
+
+        // c:order
+        match &self.polynomial_order {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:period
+        match &self.period {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:forward
+        match &self.forward {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:backward
+        match &self.backward {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:intercept
+        match &self.intercept {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:dispRSqr
+        match &self.display_r_squared_value {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:dispEq
+        match &self.display_equation {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        write_end_tag(writer, "c:trendline");
+    }
+}
@@ -0,0 +1,44 @@
+// c:trendlineType
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct TrendlineType {
+    val: String,
+}
+impl TrendlineType {
+    pub const TYPE_EXPONENTIAL: &'static str = "exp";
+    pub const TYPE_LINEAR: &'static str = "linear";
+    pub const TYPE_LOGARITHMIC: &'static str = "log";
+    pub const TYPE_MOVING_AVERAGE: &'static str = "movingAvg";
+    pub const TYPE_POLYNOMIAL: &'static str = "poly";
+    pub const TYPE_POWER: &'static str = "power";
+
+    pub fn get_val(&self)-> &str {
+        &self.val
+    }
+
+    pub fn set_val<S: Into<String>>(&mut self, value:S)-> &mut TrendlineType {
+        self.val = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart
+    ) {
+        self.val = get_attribute(e, b"val").unwrap();
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:trendlineType
+        write_start_tag(writer, "c:trendlineType", vec![
+            ("val", &self.val),
+        ], true);
+    }
+}
@@ -0,0 +1,138 @@
+// c:upDownBars
+use super::GapWidth;
+use super::UpBars;
+use super::DownBars;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct UpDownBars {
+    gap_width: Option<GapWidth>,
+    up_bars: Option<UpBars>,
+    down_bars: Option<DownBars>,
+}
+impl UpDownBars {
+    pub fn get_gap_width(&self)-> &Option<GapWidth> {
+        &self.gap_width
+    }
+
+    pub fn get_gap_width_mut(&mut self)-> &mut Option<GapWidth> {
+        &mut self.gap_width
+    }
+
+    pub fn set_gap_width(&mut self, value:GapWidth)-> &mut UpDownBars {
+        self.gap_width = Some(value);
+        self
+    }
+
+    pub fn get_up_bars(&self)-> &Option<UpBars> {
+        &self.up_bars
+    }
+
+    pub fn get_up_bars_mut(&mut self)-> &mut Option<UpBars> {
+        &mut self.up_bars
+    }
+
+    pub fn set_up_bars(&mut self, value:UpBars)-> &mut UpDownBars {
+        self.up_bars = Some(value);
+        self
+    }
+
+    pub fn get_down_bars(&self)-> &Option<DownBars> {
+        &self.down_bars
+    }
+
+    pub fn get_down_bars_mut(&mut self)-> &mut Option<DownBars> {
+        &mut self.down_bars
+    }
+
+    pub fn set_down_bars(&mut self, value:DownBars)-> &mut UpDownBars {
+        self.down_bars = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"c:upBars" => {
+                            let mut obj = UpBars::default();
+                            obj.set_attributes(reader, e, false);
+                            self.set_up_bars(obj);
+                        },
+                        b"c:downBars" => {
+                            let mut obj = DownBars::default();
+                            obj.set_attributes(reader, e, false);
+                            self.set_down_bars(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"c:gapWidth" => {
+                            let mut obj = GapWidth::default();
+                            obj.set_attributes(reader, e);
+                            self.set_gap_width(obj);
+                        },
+                        b"c:upBars" => {
+                            let mut obj = UpBars::default();
+                            obj.set_attributes(reader, e, true);
+                            self.set_up_bars(obj);
+                        },
+                        b"c:downBars" => {
+                            let mut obj = DownBars::default();
+                            obj.set_attributes(reader, e, true);
+                            self.set_down_bars(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"c:upDownBars" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "c:upDownBars"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:upDownBars
+        write_start_tag(writer, "c:upDownBars", vec![], false);
+
+        // c:gapWidth
+        match &self.gap_width {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:upBars
+        match &self.up_bars {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:downBars
+        match &self.down_bars {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        write_end_tag(writer, "c:upDownBars");
+    }
+}
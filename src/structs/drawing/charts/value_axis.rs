@@ -12,13 +12,16 @@ use super::TickLabelPosition;
 use super::CrossingAxis;
 use super::Crosses;
 use super::CrossBetween;
+use super::MajorUnit;
+use super::MinorUnit;
+use super::DisplayUnits;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct ValueAxis {
     axis_id: AxisId,
     scaling: Scaling,
@@ -33,13 +36,16 @@ pub struct ValueAxis {
     crossing_axis: CrossingAxis,
     crosses: Crosses,
     cross_between: CrossBetween,
+    major_unit: Option<MajorUnit>,
+    minor_unit: Option<MinorUnit>,
+    display_units: Option<DisplayUnits>,
 }
 impl ValueAxis {
     pub fn get_axis_id(&self)-> &AxisId {
         &self.axis_id
     }
 
-    pub fn get_axis_id_mut(&mut self)-> &AxisId {
+    pub fn get_axis_id_mut(&mut self)-> &mut AxisId {
         &mut self.axis_id
     }
 
@@ -52,7 +58,7 @@ impl ValueAxis {
         &self.scaling
     }
 
-    pub fn get_scaling_mut(&mut self)-> &Scaling {
+    pub fn get_scaling_mut(&mut self)-> &mut Scaling {
         &mut self.scaling
     }
 
@@ -65,7 +71,7 @@ impl ValueAxis {
         &self.delete
     }
 
-    pub fn get_delete_mut(&mut self)-> &Delete {
+    pub fn get_delete_mut(&mut self)-> &mut Delete {
         &mut self.delete
     }
 
@@ -78,7 +84,7 @@ impl ValueAxis {
         &self.axis_position
     }
 
-    pub fn get_axis_position_mut(&mut self)-> &AxisPosition {
+    pub fn get_axis_position_mut(&mut self)-> &mut AxisPosition {
         &mut self.axis_position
     }
 
@@ -91,7 +97,7 @@ impl ValueAxis {
         &self.major_gridlines
     }
 
-    pub fn get_major_gridlines_mut(&mut self)-> &Option<MajorGridlines> {
+    pub fn get_major_gridlines_mut(&mut self)-> &mut Option<MajorGridlines> {
         &mut self.major_gridlines
     }
 
@@ -104,7 +110,7 @@ impl ValueAxis {
         &self.title
     }
 
-    pub fn get_title_mut(&mut self)-> &Option<Title> {
+    pub fn get_title_mut(&mut self)-> &mut Option<Title> {
         &mut self.title
     }
 
@@ -117,7 +123,7 @@ impl ValueAxis {
         &self.numbering_format
     }
 
-    pub fn get_numbering_format_mut(&mut self)-> &NumberingFormat {
+    pub fn get_numbering_format_mut(&mut self)-> &mut NumberingFormat {
         &mut self.numbering_format
     }
 
@@ -130,7 +136,7 @@ impl ValueAxis {
         &self.major_tick_mark
     }
 
-    pub fn get_major_tick_mark_mut(&mut self)-> &MajorTickMark {
+    pub fn get_major_tick_mark_mut(&mut self)-> &mut MajorTickMark {
         &mut self.major_tick_mark
     }
 
@@ -143,7 +149,7 @@ impl ValueAxis {
         &self.minor_tick_mark
     }
 
-    pub fn get_minor_tick_mark_mut(&mut self)-> &MinorTickMark {
+    pub fn get_minor_tick_mark_mut(&mut self)-> &mut MinorTickMark {
         &mut self.minor_tick_mark
     }
 
@@ -156,7 +162,7 @@ impl ValueAxis {
         &self.tick_label_position
     }
 
-    pub fn get_tick_label_position_mut(&mut self)-> &TickLabelPosition {
+    pub fn get_tick_label_position_mut(&mut self)-> &mut TickLabelPosition {
         &mut self.tick_label_position
     }
 
@@ -169,7 +175,7 @@ impl ValueAxis {
         &self.crossing_axis
     }
 
-    pub fn get_tick_crossing_axis_mut(&mut self)-> &CrossingAxis {
+    pub fn get_tick_crossing_axis_mut(&mut self)-> &mut CrossingAxis {
         &mut self.crossing_axis
     }
 
@@ -182,7 +188,7 @@ impl ValueAxis {
         &self.crosses
     }
 
-    pub fn get_crosses_mut(&mut self)-> &Crosses {
+    pub fn get_crosses_mut(&mut self)-> &mut Crosses {
         &mut self.crosses
     }
 
@@ -195,7 +201,7 @@ impl ValueAxis {
         &self.cross_between
     }
 
-    pub fn get_cross_between_mut(&mut self)-> &CrossBetween {
+    pub fn get_cross_between_mut(&mut self)-> &mut CrossBetween {
         &mut self.cross_between
     }
 
@@ -204,6 +210,45 @@ impl ValueAxis {
         self
     }
 
+    pub fn get_major_unit(&self)-> &Option<MajorUnit> {
+        &self.major_unit
+    }
+
+    pub fn get_major_unit_mut(&mut self)-> &mut Option<MajorUnit> {
+        &mut self.major_unit
+    }
+
+    pub fn set_major_unit(&mut self, value:MajorUnit)-> &mut ValueAxis {
+        self.major_unit = Some(value);
+        self
+    }
+
+    pub fn get_minor_unit(&self)-> &Option<MinorUnit> {
+        &self.minor_unit
+    }
+
+    pub fn get_minor_unit_mut(&mut self)-> &mut Option<MinorUnit> {
+        &mut self.minor_unit
+    }
+
+    pub fn set_minor_unit(&mut self, value:MinorUnit)-> &mut ValueAxis {
+        self.minor_unit = Some(value);
+        self
+    }
+
+    pub fn get_display_units(&self)-> &Option<DisplayUnits> {
+        &self.display_units
+    }
+
+    pub fn get_display_units_mut(&mut self)-> &mut Option<DisplayUnits> {
+        &mut self.display_units
+    }
+
+    pub fn set_display_units(&mut self, value:DisplayUnits)-> &mut ValueAxis {
+        self.display_units = Some(value);
+        self
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -222,6 +267,11 @@ impl ValueAxis {
                             obj.set_attributes(reader, e);
                             self.set_title(obj);
                         },
+                        b"c:dispUnits" => {
+                            let mut obj = DisplayUnits::default();
+                            obj.set_attributes(reader, e);
+                            self.set_display_units(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -262,6 +312,16 @@ impl ValueAxis {
                         b"c:crossBetween" => {
                             self.cross_between.set_attributes(reader, e);
                         },
+                        b"c:majorUnit" => {
+                            let mut obj = MajorUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_major_unit(obj);
+                        },
+                        b"c:minorUnit" => {
+                            let mut obj = MinorUnit::default();
+                            obj.set_attributes(reader, e);
+                            self.set_minor_unit(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -328,6 +388,24 @@ impl ValueAxis {
         // c:crossBetween
         &self.cross_between.write_to(writer);
 
+        // c:majorUnit
+        match &self.major_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:minorUnit
+        match &self.minor_unit {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
+        // c:dispUnits
+        match &self.display_units {
+            Some(v) => {v.write_to(writer);},
+            None => {}
+        }
+
         write_end_tag(writer, "c:valAx");
     }
 }
@@ -0,0 +1,58 @@
+// a:cxn
+use super::PathPoint;
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ConnectionSite {
+    angle: String,
+    position: PathPoint,
+}
+impl ConnectionSite {
+    pub fn get_angle(&self) -> &str {
+        &self.angle
+    }
+
+    pub fn set_angle<S: Into<String>>(&mut self, value:S) -> &mut ConnectionSite {
+        self.angle = value.into();
+        self
+    }
+
+    pub fn get_position(&self) -> &PathPoint {
+        &self.position
+    }
+
+    pub fn get_position_mut(&mut self) -> &mut PathPoint {
+        &mut self.position
+    }
+
+    pub fn set_position(&mut self, value:PathPoint) -> &mut ConnectionSite {
+        self.position = value;
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        e:&BytesStart
+    ) {
+        &mut self.set_angle(get_attribute(e, b"ang").unwrap_or_else(|| "0".to_string()));
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:cxn
+        write_start_tag(writer, "a:cxn", vec![
+            ("ang", &self.angle),
+        ], false);
+
+        // a:pos
+        write_start_tag(writer, "a:pos", vec![
+            ("x", self.position.get_x()),
+            ("y", self.position.get_y()),
+        ], true);
+
+        write_end_tag(writer, "a:cxn");
+    }
+}
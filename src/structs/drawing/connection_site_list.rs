@@ -0,0 +1,84 @@
+// a:cxnLst
+use super::ConnectionSite;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ConnectionSiteList {
+    connection_site_collection: Vec<ConnectionSite>,
+}
+impl ConnectionSiteList {
+    pub fn get_connection_site_collection(&self) -> &Vec<ConnectionSite> {
+        &self.connection_site_collection
+    }
+
+    pub fn get_connection_site_collection_mut(&mut self) -> &mut Vec<ConnectionSite> {
+        &mut self.connection_site_collection
+    }
+
+    pub fn add_connection_site_collection(&mut self, value:ConnectionSite) {
+        self.connection_site_collection.push(value);
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        let mut connection_site = ConnectionSite::default();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"a:cxn" => {
+                            connection_site = ConnectionSite::default();
+                            connection_site.set_attributes(e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:pos" => {
+                            connection_site.get_position_mut().set_attributes(e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:cxn" => {
+                            &mut self.add_connection_site_collection(connection_site.clone());
+                        },
+                        b"a:cxnLst" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:cxnLst"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:cxnLst
+        if self.connection_site_collection.is_empty() {
+            write_start_tag(writer, "a:cxnLst", vec![], true);
+            return;
+        }
+        write_start_tag(writer, "a:cxnLst", vec![], false);
+
+        // a:cxn
+        for connection_site in &self.connection_site_collection {
+            connection_site.write_to(writer);
+        }
+
+        write_end_tag(writer, "a:cxnLst");
+    }
+}
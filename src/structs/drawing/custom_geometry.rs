@@ -0,0 +1,196 @@
+// a:custGeom
+use super::AdjustValueList;
+use super::ShapeGuideList;
+use super::AdjustHandleList;
+use super::ConnectionSiteList;
+use super::GeometryRectangle;
+use super::PathList;
+use super::Path;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomGeometry {
+    adjust_value_list: AdjustValueList,
+    shape_guide_list: ShapeGuideList,
+    adjust_handle_list: AdjustHandleList,
+    connection_site_list: ConnectionSiteList,
+    rectangle: Option<GeometryRectangle>,
+    path_list: PathList,
+}
+impl CustomGeometry {
+    pub fn get_adjust_value_list(&self) -> &AdjustValueList {
+        &self.adjust_value_list
+    }
+
+    pub fn get_adjust_value_list_mut(&mut self) -> &mut AdjustValueList {
+        &mut self.adjust_value_list
+    }
+
+    pub fn set_adjust_value_list(&mut self, value:AdjustValueList) {
+        self.adjust_value_list = value;
+    }
+
+    pub fn get_shape_guide_list(&self) -> &ShapeGuideList {
+        &self.shape_guide_list
+    }
+
+    pub fn get_shape_guide_list_mut(&mut self) -> &mut ShapeGuideList {
+        &mut self.shape_guide_list
+    }
+
+    pub fn set_shape_guide_list(&mut self, value:ShapeGuideList) {
+        self.shape_guide_list = value;
+    }
+
+    pub fn get_adjust_handle_list(&self) -> &AdjustHandleList {
+        &self.adjust_handle_list
+    }
+
+    pub fn get_adjust_handle_list_mut(&mut self) -> &mut AdjustHandleList {
+        &mut self.adjust_handle_list
+    }
+
+    pub fn set_adjust_handle_list(&mut self, value:AdjustHandleList) {
+        self.adjust_handle_list = value;
+    }
+
+    pub fn get_connection_site_list(&self) -> &ConnectionSiteList {
+        &self.connection_site_list
+    }
+
+    pub fn get_connection_site_list_mut(&mut self) -> &mut ConnectionSiteList {
+        &mut self.connection_site_list
+    }
+
+    pub fn set_connection_site_list(&mut self, value:ConnectionSiteList) {
+        self.connection_site_list = value;
+    }
+
+    pub fn get_rectangle(&self) -> &Option<GeometryRectangle> {
+        &self.rectangle
+    }
+
+    pub fn get_rectangle_mut(&mut self) -> &mut Option<GeometryRectangle> {
+        &mut self.rectangle
+    }
+
+    pub fn set_rectangle(&mut self, value:GeometryRectangle) {
+        self.rectangle = Some(value);
+    }
+
+    pub fn get_path_list(&self) -> &PathList {
+        &self.path_list
+    }
+
+    pub fn get_path_list_mut(&mut self) -> &mut PathList {
+        &mut self.path_list
+    }
+
+    pub fn set_path_list(&mut self, value:PathList) {
+        self.path_list = value;
+    }
+
+    /// Add a path drawn in its own coordinate space.
+    /// # Arguments
+    /// * `width` - width of the coordinate space. It is stretched to the width of the shape.
+    /// * `height` - height of the coordinate space. It is stretched to the height of the shape.
+    /// # Return value
+    /// * `&mut Path` - the new path, to add drawing commands to.
+    pub fn add_path(&mut self, width:&i64, height:&i64) -> &mut Path {
+        let mut path = Path::default();
+        path.set_width(*width).set_height(*height);
+        self.path_list.add_path_collection(path);
+        self.path_list.get_path_collection_mut().last_mut().unwrap()
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"a:avLst" => {
+                            &mut self.adjust_value_list.set_attributes(reader, e);
+                        },
+                        b"a:gdLst" => {
+                            &mut self.shape_guide_list.set_attributes(reader, e);
+                        },
+                        b"a:ahLst" => {
+                            &mut self.adjust_handle_list.set_attributes(reader, e);
+                        },
+                        b"a:cxnLst" => {
+                            &mut self.connection_site_list.set_attributes(reader, e);
+                        },
+                        b"a:pathLst" => {
+                            &mut self.path_list.set_attributes(reader, e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:rect" => {
+                            let mut obj = GeometryRectangle::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_rectangle(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:custGeom" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:custGeom"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:custGeom
+        write_start_tag(writer, "a:custGeom", vec![], false);
+
+        // a:avLst
+        &self.adjust_value_list.write_to(writer);
+
+        // a:gdLst
+        &self.shape_guide_list.write_to(writer);
+
+        // a:ahLst
+        &self.adjust_handle_list.write_to(writer);
+
+        // a:cxnLst
+        &self.connection_site_list.write_to(writer);
+
+        // a:rect
+        match &self.rectangle {
+            Some(v) => v.write_to(writer),
+            None => {
+                write_start_tag(writer, "a:rect", vec![
+                    ("l", "l"),
+                    ("t", "t"),
+                    ("r", "r"),
+                    ("b", "b"),
+                ], true);
+            }
+        }
+
+        // a:pathLst
+        &self.path_list.write_to(writer);
+
+        write_end_tag(writer, "a:custGeom");
+    }
+}
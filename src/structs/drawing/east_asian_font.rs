@@ -6,7 +6,7 @@ use quick_xml::events::{BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct EastAsianFont {
     typeface: String,
     pitch_family: String,
@@ -61,10 +61,14 @@ impl EastAsianFont {
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // a:ea
-        write_start_tag(writer, "a:ea", vec![
-            ("typeface", &self.typeface),
-            ("pitchFamily", &self.pitch_family),
-            ("charset", &self.charset),
-        ], true);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("typeface", &self.typeface));
+        if &self.pitch_family != "" {
+            attributes.push(("pitchFamily", &self.pitch_family));
+        }
+        if &self.charset != "" {
+            attributes.push(("charset", &self.charset));
+        }
+        write_start_tag(writer, "a:ea", attributes, true);
     }
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+// a:rect
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct GeometryRectangle {
+    left: String,
+    top: String,
+    right: String,
+    bottom: String,
+}
+impl GeometryRectangle {
+    pub fn get_left(&self) -> &str {
+        &self.left
+    }
+
+    pub fn set_left<S: Into<String>>(&mut self, value:S) -> &mut GeometryRectangle {
+        self.left = value.into();
+        self
+    }
+
+    pub fn get_top(&self) -> &str {
+        &self.top
+    }
+
+    pub fn set_top<S: Into<String>>(&mut self, value:S) -> &mut GeometryRectangle {
+        self.top = value.into();
+        self
+    }
+
+    pub fn get_right(&self) -> &str {
+        &self.right
+    }
+
+    pub fn set_right<S: Into<String>>(&mut self, value:S) -> &mut GeometryRectangle {
+        self.right = value.into();
+        self
+    }
+
+    pub fn get_bottom(&self) -> &str {
+        &self.bottom
+    }
+
+    pub fn set_bottom<S: Into<String>>(&mut self, value:S) -> &mut GeometryRectangle {
+        self.bottom = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart
+    ) {
+        match get_attribute(e, b"l") {
+            Some(v) => {&mut self.set_left(v);},
+            None => {}
+        }
+        match get_attribute(e, b"t") {
+            Some(v) => {&mut self.set_top(v);},
+            None => {}
+        }
+        match get_attribute(e, b"r") {
+            Some(v) => {&mut self.set_right(v);},
+            None => {}
+        }
+        match get_attribute(e, b"b") {
+            Some(v) => {&mut self.set_bottom(v);},
+            None => {}
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:rect
+        write_start_tag(writer, "a:rect", vec![
+            ("l", &self.left),
+            ("t", &self.top),
+            ("r", &self.right),
+            ("b", &self.bottom),
+        ], true);
+    }
+}
@@ -1,4 +1,5 @@
 // a:ln
+use super::HeadEnd;
 use super::TailEnd;
 use super::SolidFill;
 use super::NoFill;
@@ -9,11 +10,12 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Outline {
     width: u32,
     compound_line_type: Option<String>,
     solid_fill: Option<SolidFill>,
+    head_end: Option<HeadEnd>,
     tail_end: Option<TailEnd>,
     no_fill: Option<NoFill>,
 }
@@ -42,8 +44,22 @@ impl Outline {
         &mut self.solid_fill
     }
 
+    /// Draw the line in one color. Clears no_fill.
     pub fn set_solid_fill(&mut self, value:SolidFill) {
         self.solid_fill = Some(value);
+        self.no_fill = None;
+    }
+
+    pub fn get_head_end(&self) -> &Option<HeadEnd> {
+        &self.head_end
+    }
+
+    pub fn get_head_end_mut(&mut self) -> &mut Option<HeadEnd> {
+        &mut self.head_end
+    }
+
+    pub fn set_head_end(&mut self, value:HeadEnd) {
+        self.head_end = Some(value);
     }
 
     pub fn get_tail_end(&self) -> &Option<TailEnd> {
@@ -66,8 +82,10 @@ impl Outline {
         &mut self.no_fill
     }
 
+    /// Hide the line. Clears solid_fill.
     pub fn set_no_fill(&mut self, value:NoFill) -> &mut Outline {
         self.no_fill = Some(value);
+        self.solid_fill = None;
         self
     }
 
@@ -102,6 +120,11 @@ impl Outline {
                 },
                 Ok(Event::Empty(ref e)) => {
                     match e.name() {
+                        b"a:headEnd" => {
+                            let mut obj = HeadEnd::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_head_end(obj);
+                        },
                         b"a:tailEnd" => {
                             let mut obj = TailEnd::default();
                             obj.set_attributes(reader, e);
@@ -146,20 +169,26 @@ impl Outline {
         }
         write_start_tag(writer, "a:ln", attributes, false);
 
+        // a:noFill
+        match &self.no_fill {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
         // a:solidFill
         match &self.solid_fill {
             Some(v) => v.write_to(writer),
             None => {},
         }
 
-        // a:tailEnd
-        match &self.tail_end {
+        // a:headEnd
+        match &self.head_end {
             Some(v) => v.write_to(writer),
             None => {},
         }
 
-        // a:noFill
-        match &self.no_fill {
+        // a:tailEnd
+        match &self.tail_end {
             Some(v) => v.write_to(writer),
             None => {},
         }
@@ -8,7 +8,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Paragraph {
     paragraph_properties: ParagraphProperties,
     run: Vec<Run>,
@@ -32,6 +32,10 @@ impl Paragraph {
         &self.run
     }
 
+    pub fn get_run_mut(&mut self) -> &mut Vec<Run> {
+        &mut self.run
+    }
+
     pub fn add_run(&mut self, value:Run) {
         self.run.push(value);
     }
@@ -0,0 +1,252 @@
+// a:path
+use super::PathCommand;
+use super::PathPoint;
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Path {
+    width: Option<i64>,
+    height: Option<i64>,
+    fill: Option<String>,
+    stroke: Option<String>,
+    command_collection: Vec<PathCommand>,
+}
+impl Path {
+    // Fill modes
+    pub const FILL_NONE: &'static str = "none";
+    pub const FILL_NORM: &'static str = "norm";
+    pub const FILL_LIGHTEN: &'static str = "lighten";
+    pub const FILL_LIGHTEN_LESS: &'static str = "lightenLess";
+    pub const FILL_DARKEN: &'static str = "darken";
+    pub const FILL_DARKEN_LESS: &'static str = "darkenLess";
+
+    pub fn get_width(&self) -> &Option<i64> {
+        &self.width
+    }
+
+    pub fn set_width(&mut self, value:i64) -> &mut Path {
+        self.width = Some(value);
+        self
+    }
+
+    pub fn get_height(&self) -> &Option<i64> {
+        &self.height
+    }
+
+    pub fn set_height(&mut self, value:i64) -> &mut Path {
+        self.height = Some(value);
+        self
+    }
+
+    pub fn get_fill(&self) -> &Option<String> {
+        &self.fill
+    }
+
+    pub fn set_fill<S: Into<String>>(&mut self, value:S) -> &mut Path {
+        self.fill = Some(value.into());
+        self
+    }
+
+    pub fn get_stroke(&self) -> &Option<String> {
+        &self.stroke
+    }
+
+    pub fn set_stroke<S: Into<String>>(&mut self, value:S) -> &mut Path {
+        self.stroke = Some(value.into());
+        self
+    }
+
+    pub fn get_command_collection(&self) -> &Vec<PathCommand> {
+        &self.command_collection
+    }
+
+    pub fn get_command_collection_mut(&mut self) -> &mut Vec<PathCommand> {
+        &mut self.command_collection
+    }
+
+    pub fn add_command_collection(&mut self, value:PathCommand) -> &mut Path {
+        self.command_collection.push(value);
+        self
+    }
+
+    /// Start a new figure at a point.
+    /// # Arguments
+    /// * `x` - horizontal position in the coordinate space of the path.
+    /// * `y` - vertical position in the coordinate space of the path.
+    pub fn add_move_to(&mut self, x:&i64, y:&i64) -> &mut Path {
+        self.add_command_collection(PathCommand::MoveTo(PathPoint::new_point(x, y)))
+    }
+
+    /// Draw a straight line to a point.
+    pub fn add_line_to(&mut self, x:&i64, y:&i64) -> &mut Path {
+        self.add_command_collection(PathCommand::LineTo(PathPoint::new_point(x, y)))
+    }
+
+    /// Draw an arc from the current point.
+    /// # Arguments
+    /// * `width_radius` - horizontal radius.
+    /// * `height_radius` - vertical radius.
+    /// * `start_angle` - start angle in 60000ths of a degree.
+    /// * `swing_angle` - swing angle in 60000ths of a degree. Positive values go clockwise.
+    pub fn add_arc_to(&mut self, width_radius:&i64, height_radius:&i64, start_angle:&i64, swing_angle:&i64) -> &mut Path {
+        self.add_command_collection(PathCommand::ArcTo {
+            width_radius: width_radius.to_string(),
+            height_radius: height_radius.to_string(),
+            start_angle: start_angle.to_string(),
+            swing_angle: swing_angle.to_string(),
+        })
+    }
+
+    /// Draw a quadratic bezier curve through a control point to an end point.
+    pub fn add_quadratic_bezier_to(&mut self, x1:&i64, y1:&i64, x2:&i64, y2:&i64) -> &mut Path {
+        self.add_command_collection(PathCommand::QuadraticBezierTo(
+            PathPoint::new_point(x1, y1),
+            PathPoint::new_point(x2, y2),
+        ))
+    }
+
+    /// Draw a cubic bezier curve through two control points to an end point.
+    pub fn add_cubic_bezier_to(&mut self, x1:&i64, y1:&i64, x2:&i64, y2:&i64, x3:&i64, y3:&i64) -> &mut Path {
+        self.add_command_collection(PathCommand::CubicBezierTo(
+            PathPoint::new_point(x1, y1),
+            PathPoint::new_point(x2, y2),
+            PathPoint::new_point(x3, y3),
+        ))
+    }
+
+    /// Close the figure with a line to its start point.
+    pub fn add_close(&mut self) -> &mut Path {
+        self.add_command_collection(PathCommand::Close)
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart,
+        empty_flag:bool,
+    ) {
+        match get_attribute(e, b"w").map(|v| v.parse::<i64>()) {
+            Some(Ok(v)) => {&mut self.set_width(v);},
+            _ => {}
+        }
+        match get_attribute(e, b"h").map(|v| v.parse::<i64>()) {
+            Some(Ok(v)) => {&mut self.set_height(v);},
+            _ => {}
+        }
+        match get_attribute(e, b"fill") {
+            Some(v) => {&mut self.set_fill(v);},
+            None => {}
+        }
+        match get_attribute(e, b"stroke") {
+            Some(v) => {&mut self.set_stroke(v);},
+            None => {}
+        }
+
+        if empty_flag {
+            return;
+        }
+
+        let mut points: Vec<PathPoint> = Vec::new();
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"a:moveTo" | b"a:lnTo" | b"a:quadBezTo" | b"a:cubicBezTo" => {
+                            points.clear();
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:pt" => {
+                            let mut point = PathPoint::default();
+                            point.set_attributes(e);
+                            points.push(point);
+                        },
+                        b"a:arcTo" => {
+                            &mut self.add_command_collection(PathCommand::ArcTo {
+                                width_radius: get_attribute(e, b"wR").unwrap_or_else(|| "0".to_string()),
+                                height_radius: get_attribute(e, b"hR").unwrap_or_else(|| "0".to_string()),
+                                start_angle: get_attribute(e, b"stAng").unwrap_or_else(|| "0".to_string()),
+                                swing_angle: get_attribute(e, b"swAng").unwrap_or_else(|| "0".to_string()),
+                            });
+                        },
+                        b"a:close" => {
+                            &mut self.add_command_collection(PathCommand::Close);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    let mut points = points.drain(..);
+                    match e.name() {
+                        b"a:moveTo" => {
+                            &mut self.add_command_collection(PathCommand::MoveTo(points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0))));
+                        },
+                        b"a:lnTo" => {
+                            &mut self.add_command_collection(PathCommand::LineTo(points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0))));
+                        },
+                        b"a:quadBezTo" => {
+                            &mut self.add_command_collection(PathCommand::QuadraticBezierTo(
+                                points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0)),
+                                points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0)),
+                            ));
+                        },
+                        b"a:cubicBezTo" => {
+                            &mut self.add_command_collection(PathCommand::CubicBezierTo(
+                                points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0)),
+                                points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0)),
+                                points.next().unwrap_or_else(|| PathPoint::new_point(&0, &0)),
+                            ));
+                        },
+                        b"a:path" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:path"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:path
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let width_str = self.width.map(|v| v.to_string()).unwrap_or_default();
+        if self.width.is_some() {
+            attributes.push(("w", &width_str));
+        }
+        let height_str = self.height.map(|v| v.to_string()).unwrap_or_default();
+        if self.height.is_some() {
+            attributes.push(("h", &height_str));
+        }
+        match &self.fill {
+            Some(v) => attributes.push(("fill", v)),
+            None => {}
+        }
+        match &self.stroke {
+            Some(v) => attributes.push(("stroke", v)),
+            None => {}
+        }
+        if self.command_collection.is_empty() {
+            write_start_tag(writer, "a:path", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "a:path", attributes, false);
+
+        for command in &self.command_collection {
+            command.write_to(writer);
+        }
+
+        write_end_tag(writer, "a:path");
+    }
+}
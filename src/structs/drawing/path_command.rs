@@ -0,0 +1,66 @@
+use super::PathPoint;
+use writer::driver::*;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// One drawing command of a custom geometry path.
+#[derive(Clone, Debug)]
+pub enum PathCommand {
+    /// a:moveTo
+    MoveTo(PathPoint),
+    /// a:lnTo
+    LineTo(PathPoint),
+    /// a:arcTo
+    ArcTo {
+        width_radius: String,
+        height_radius: String,
+        start_angle: String,
+        swing_angle: String,
+    },
+    /// a:quadBezTo
+    QuadraticBezierTo(PathPoint, PathPoint),
+    /// a:cubicBezTo
+    CubicBezierTo(PathPoint, PathPoint, PathPoint),
+    /// a:close
+    Close,
+}
+impl PathCommand {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        match self {
+            PathCommand::MoveTo(point) => {
+                write_start_tag(writer, "a:moveTo", vec![], false);
+                point.write_to(writer);
+                write_end_tag(writer, "a:moveTo");
+            },
+            PathCommand::LineTo(point) => {
+                write_start_tag(writer, "a:lnTo", vec![], false);
+                point.write_to(writer);
+                write_end_tag(writer, "a:lnTo");
+            },
+            PathCommand::ArcTo { width_radius, height_radius, start_angle, swing_angle } => {
+                write_start_tag(writer, "a:arcTo", vec![
+                    ("wR", width_radius),
+                    ("hR", height_radius),
+                    ("stAng", start_angle),
+                    ("swAng", swing_angle),
+                ], true);
+            },
+            PathCommand::QuadraticBezierTo(point1, point2) => {
+                write_start_tag(writer, "a:quadBezTo", vec![], false);
+                point1.write_to(writer);
+                point2.write_to(writer);
+                write_end_tag(writer, "a:quadBezTo");
+            },
+            PathCommand::CubicBezierTo(point1, point2, point3) => {
+                write_start_tag(writer, "a:cubicBezTo", vec![], false);
+                point1.write_to(writer);
+                point2.write_to(writer);
+                point3.write_to(writer);
+                write_end_tag(writer, "a:cubicBezTo");
+            },
+            PathCommand::Close => {
+                write_start_tag(writer, "a:close", vec![], true);
+            },
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// a:pathLst
+use super::Path;
+use quick_xml::Reader;
+use writer::driver::*;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct PathList {
+    path_collection: Vec<Path>,
+}
+impl PathList {
+    pub fn get_path_collection(&self) -> &Vec<Path> {
+        &self.path_collection
+    }
+
+    pub fn get_path_collection_mut(&mut self) -> &mut Vec<Path> {
+        &mut self.path_collection
+    }
+
+    pub fn add_path_collection(&mut self, value:Path) {
+        self.path_collection.push(value);
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"a:path" => {
+                            let mut obj = Path::default();
+                            obj.set_attributes(reader, e, false);
+                            &mut self.add_path_collection(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:path" => {
+                            let mut obj = Path::default();
+                            obj.set_attributes(reader, e, true);
+                            &mut self.add_path_collection(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:pathLst" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:pathLst"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:pathLst
+        write_start_tag(writer, "a:pathLst", vec![], false);
+
+        // a:path
+        for path in &self.path_collection {
+            path.write_to(writer);
+        }
+
+        write_end_tag(writer, "a:pathLst");
+    }
+}
@@ -0,0 +1,53 @@
+// a:pt
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct PathPoint {
+    x: String,
+    y: String,
+}
+impl PathPoint {
+    pub fn get_x(&self) -> &str {
+        &self.x
+    }
+
+    pub fn set_x<S: Into<String>>(&mut self, value:S) -> &mut PathPoint {
+        self.x = value.into();
+        self
+    }
+
+    pub fn get_y(&self) -> &str {
+        &self.y
+    }
+
+    pub fn set_y<S: Into<String>>(&mut self, value:S) -> &mut PathPoint {
+        self.y = value.into();
+        self
+    }
+
+    pub(crate) fn new_point(x:&i64, y:&i64) -> PathPoint {
+        let mut point = PathPoint::default();
+        point.set_x(x.to_string()).set_y(y.to_string());
+        point
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        e:&BytesStart
+    ) {
+        &mut self.set_x(get_attribute(e, b"x").unwrap_or_else(|| "0".to_string()));
+        &mut self.set_y(get_attribute(e, b"y").unwrap_or_else(|| "0".to_string()));
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:pt
+        write_start_tag(writer, "a:pt", vec![
+            ("x", &self.x),
+            ("y", &self.y),
+        ], true);
+    }
+}
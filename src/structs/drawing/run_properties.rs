@@ -8,7 +8,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct RunProperties {
     text: String,
     kumimoji: Option<String>,
@@ -26,56 +26,63 @@ impl RunProperties {
         &self.text
     }
 
-    pub fn set_text<S: Into<String>>(&mut self, value:S) {
+    pub fn set_text<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.text = value.into();
+        self
     }
 
     pub fn get_kumimoji(&self) -> &Option<String> {
         &self.kumimoji
     }
 
-    pub fn set_kumimoji<S: Into<String>>(&mut self, value:S) {
+    pub fn set_kumimoji<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.kumimoji = Some(value.into());
+        self
     }
 
     pub fn get_lang(&self) -> &Option<String> {
         &self.lang
     }
 
-    pub fn set_lang<S: Into<String>>(&mut self, value:S) {
+    pub fn set_lang<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.lang = Some(value.into());
+        self
     }
 
     pub fn get_alt_lang(&self) -> &Option<String> {
         &self.alt_lang
     }
 
-    pub fn set_alt_lang<S: Into<String>>(&mut self, value:S) {
+    pub fn set_alt_lang<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.alt_lang = Some(value.into());
+        self
     }
 
     pub fn get_bold(&self) -> &Option<String> {
         &self.bold
     }
 
-    pub fn set_bold<S: Into<String>>(&mut self, value:S) {
+    pub fn set_bold<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.bold = Some(value.into());
+        self
     }
 
     pub fn get_sz(&self) -> &Option<String> {
         &self.sz
     }
 
-    pub fn set_sz<S: Into<String>>(&mut self, value:S) {
+    pub fn set_sz<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.sz = Some(value.into());
+        self
     }
 
     pub fn get_italic(&self) -> &Option<String> {
         &self.italic
     }
 
-    pub fn set_italic<S: Into<String>>(&mut self, value:S) {
+    pub fn set_italic<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
         self.italic = Some(value.into());
+        self
     }
 
     pub fn get_solid_fill(&self) -> &Option<SolidFill> {
@@ -86,8 +93,9 @@ impl RunProperties {
         &mut self.solid_fill
     }
     
-    pub fn set_solid_fill(&mut self, value:SolidFill) {
+    pub fn set_solid_fill(&mut self, value:SolidFill) -> &mut RunProperties {
         self.solid_fill = Some(value);
+        self
     }
 
     pub fn get_latin_font(&self) -> &Option<LatinFont> {
@@ -98,8 +106,9 @@ impl RunProperties {
         &mut self.latin_font
     }
     
-    pub fn set_latin_font(&mut self, value:LatinFont) {
+    pub fn set_latin_font(&mut self, value:LatinFont) -> &mut RunProperties {
         self.latin_font = Some(value);
+        self
     }
 
     pub fn get_east_asian_font(&self) -> &Option<EastAsianFont> {
@@ -110,8 +119,36 @@ impl RunProperties {
         &mut self.east_asian_font
     }
     
-    pub fn set_east_asian_font(&mut self, value:EastAsianFont) {
+    pub fn set_east_asian_font(&mut self, value:EastAsianFont) -> &mut RunProperties {
         self.east_asian_font = Some(value);
+        self
+    }
+
+    /// Set the typeface used for latin and east asian text.
+    /// # Arguments
+    /// * `value` - name of the font. ex) "Calibri"
+    pub fn set_font_name<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
+        let value = value.into();
+        let mut latin_font = LatinFont::default();
+        latin_font.set_typeface(value.clone());
+        self.set_latin_font(latin_font);
+        let mut east_asian_font = EastAsianFont::default();
+        east_asian_font.set_typeface(value);
+        self.set_east_asian_font(east_asian_font)
+    }
+
+    /// Set the font size.
+    /// # Arguments
+    /// * `value` - size in points. ex) 11.0
+    pub fn set_font_size(&mut self, value:f64) -> &mut RunProperties {
+        self.set_sz(((value * 100f64).round() as u32).to_string())
+    }
+
+    /// Set the font color.
+    /// # Arguments
+    /// * `value` - RGB color. ex) "FF0000"
+    pub fn set_color<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
+        self.set_solid_fill(SolidFill::new_solid_fill(value))
     }
 
     pub(crate) fn set_attributes(
@@ -217,7 +254,7 @@ impl RunProperties {
             Some(v) => attributes.push(("i", v)),
             None => {}
         }
-        if self.solid_fill.is_some() || self.latin_font.is_some() || self.solid_fill.is_some() {
+        if self.solid_fill.is_some() || self.latin_font.is_some() || self.east_asian_font.is_some() {
             write_start_tag(writer, "a:rPr", attributes, false);
 
             // a:solidFill
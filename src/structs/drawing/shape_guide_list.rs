@@ -0,0 +1,85 @@
+// a:gdLst
+use super::shape_guide::ShapeGuide;
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ShapeGuideList {
+    shape_guide_collection: Vec<ShapeGuide>,
+}
+impl ShapeGuideList {
+    pub fn get_shape_guide_collection(&self) -> &Vec<ShapeGuide> {
+        &self.shape_guide_collection
+    }
+
+    pub fn get_shape_guide_collection_mut(&mut self) -> &mut Vec<ShapeGuide> {
+        &mut self.shape_guide_collection
+    }
+
+    pub fn set_shape_guide_collection(&mut self, value:Vec<ShapeGuide>) {
+        self.shape_guide_collection = value;
+    }
+
+    pub fn add_shape_guide_collection(&mut self, value:ShapeGuide) {
+        self.shape_guide_collection.push(value);
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+    
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:gd" => {
+                            // a guide without a name or a formula is skipped
+                            match (get_attribute(e, b"name"), get_attribute(e, b"fmla")) {
+                                (Some(name), Some(fmla)) => {
+                                    let mut shape_guide = ShapeGuide::default();
+                                    shape_guide.set_name(name);
+                                    shape_guide.set_fmla(fmla);
+                                    &mut self.add_shape_guide_collection(shape_guide);
+                                },
+                                _ => {}
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:gdLst" => {
+                            return;
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:gdLst"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:gdLst
+        if self.shape_guide_collection.len() > 0 {
+            write_start_tag(writer, "a:gdLst", vec![], false);
+            for gd in &self.shape_guide_collection {
+                gd.write_to(writer);
+            }
+            write_end_tag(writer, "a:gdLst");
+        } else {
+            write_start_tag(writer, "a:gdLst", vec![], true);
+        }
+    }
+}
@@ -7,7 +7,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct SolidFill {
     scheme_color: Option<SchemeColor>,
     rgb_color_model_hex: Option<RgbColorModelHex>,
@@ -37,6 +37,14 @@ impl SolidFill {
         self.rgb_color_model_hex = Some(value);
     }
 
+    pub(crate) fn new_solid_fill<S: Into<String>>(value:S) -> SolidFill {
+        let mut rgb_color_model_hex = RgbColorModelHex::default();
+        rgb_color_model_hex.set_val(value);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+        solid_fill
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
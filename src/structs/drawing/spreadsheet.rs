@@ -52,6 +52,9 @@ pub use self::two_cell_anchor::*;
           mod worksheet_drawing;
 pub use self::worksheet_drawing::*;
 
+          mod anchor;
+pub use self::anchor::*;
+
           mod graphic_frame;
 pub use self::graphic_frame::*;
 
@@ -64,3 +67,27 @@ pub use self::non_visual_graphic_frame_drawing_properties::*;
           mod transform;
 pub use self::transform::*;
 
+
+          mod one_cell_anchor;
+pub use self::one_cell_anchor::*;
+
+          mod absolute_anchor;
+pub use self::absolute_anchor::*;
+
+          mod extent;
+pub use self::extent::*;
+
+          mod position;
+pub use self::position::*;
+
+          mod group_shape;
+pub use self::group_shape::*;
+
+          mod group_shape_child;
+pub use self::group_shape_child::*;
+
+          mod non_visual_group_shape_properties;
+pub use self::non_visual_group_shape_properties::*;
+
+          mod group_shape_properties;
+pub use self::group_shape_properties::*;
@@ -0,0 +1,246 @@
+// xdr:absoluteAnchor
+use super::Position;
+use super::Extent;
+use super::GraphicFrame;
+use super::Shape;
+use super::ConnectionShape;
+use super::Picture;
+use super::GroupShape;
+use writer::driver::*;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use quick_xml::Reader;
+use std::io::Cursor;
+use tempdir::TempDir;
+
+#[derive(Default, Debug)]
+pub struct AbsoluteAnchor {
+    position: Position,
+    extent: Extent,
+    graphic_frame: Option<GraphicFrame>,
+    shape: Option<Shape>,
+    connection_shape: Option<ConnectionShape>,
+    picture: Option<Picture>,
+    group_shape: Option<GroupShape>,
+}
+impl AbsoluteAnchor {
+    pub fn get_position(&self)-> &Position {
+        &self.position
+    }
+
+    pub fn get_position_mut(&mut self)-> &mut Position {
+        &mut self.position
+    }
+
+    pub fn set_position(&mut self, value:Position)-> &mut AbsoluteAnchor {
+        self.position = value;
+        self
+    }
+
+    pub fn get_extent(&self)-> &Extent {
+        &self.extent
+    }
+
+    pub fn get_extent_mut(&mut self)-> &mut Extent {
+        &mut self.extent
+    }
+
+    pub fn set_extent(&mut self, value:Extent)-> &mut AbsoluteAnchor {
+        self.extent = value;
+        self
+    }
+
+    pub fn get_graphic_frame(&self)-> &Option<GraphicFrame> {
+        &self.graphic_frame
+    }
+
+    pub fn get_graphic_frame_mut(&mut self)-> &mut Option<GraphicFrame> {
+        &mut self.graphic_frame
+    }
+
+    pub fn set_graphic_frame(&mut self, value:GraphicFrame)-> &mut AbsoluteAnchor {
+        self.graphic_frame = Some(value);
+        self
+    }
+
+    pub fn get_shape(&self)-> &Option<Shape> {
+        &self.shape
+    }
+
+    pub fn get_shape_mut(&mut self)-> &mut Option<Shape> {
+        &mut self.shape
+    }
+
+    pub fn set_shape(&mut self, value:Shape)-> &mut AbsoluteAnchor {
+        self.shape = Some(value);
+        self
+    }
+
+    pub fn get_connection_shape(&self)-> &Option<ConnectionShape> {
+        &self.connection_shape
+    }
+
+    pub fn get_connection_shape_mut(&mut self)-> &mut Option<ConnectionShape> {
+        &mut self.connection_shape
+    }
+
+    pub fn set_connection_shape(&mut self, value:ConnectionShape)-> &mut AbsoluteAnchor {
+        self.connection_shape = Some(value);
+        self
+    }
+
+    pub fn get_picture(&self)-> &Option<Picture> {
+        &self.picture
+    }
+
+    pub fn get_picture_mut(&mut self)-> &mut Option<Picture> {
+        &mut self.picture
+    }
+
+    pub fn set_picture(&mut self, value:Picture)-> &mut AbsoluteAnchor {
+        self.picture = Some(value);
+        self
+    }
+
+    pub fn get_group_shape(&self)-> &Option<GroupShape> {
+        &self.group_shape
+    }
+
+    pub fn get_group_shape_mut(&mut self)-> &mut Option<GroupShape> {
+        &mut self.group_shape
+    }
+
+    pub fn set_group_shape(&mut self, value:GroupShape)-> &mut AbsoluteAnchor {
+        self.group_shape = Some(value);
+        self
+    }
+
+    // Objects of the anchor, borrowed together.
+    pub(crate) fn get_objects_mut(&mut self)-> (&mut Option<GraphicFrame>, &mut Option<Shape>, &mut Option<ConnectionShape>, &mut Option<Picture>, &mut Option<GroupShape>) {
+        (&mut self.graphic_frame, &mut self.shape, &mut self.connection_shape, &mut self.picture, &mut self.group_shape)
+    }
+
+    pub(crate) fn is_support(&self) -> bool {
+        match &self.graphic_frame {
+            Some(v) => {
+                return v.get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area().is_support();
+            },
+            None => {}
+        }
+        true
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart,
+        dir: &TempDir,
+        target: &str,
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"xdr:graphicFrame" => {
+                            let mut obj = GraphicFrame::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_graphic_frame(obj);
+                        },
+                        b"xdr:sp" => {
+                            let mut obj = Shape::default();
+                            obj.set_attributes(reader, e);
+                            self.set_shape(obj);
+                        },
+                        b"xdr:cxnSp" => {
+                            let mut obj = ConnectionShape::default();
+                            obj.set_attributes(reader, e);
+                            self.set_connection_shape(obj);
+                        }
+                        b"xdr:pic" => {
+                            let mut obj = Picture::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_picture(obj);
+                        }
+                        b"xdr:grpSp" => {
+                            let mut obj = GroupShape::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_group_shape(obj);
+                        }
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"xdr:pos" => {
+                            self.position.set_attributes(reader, e);
+                        },
+                        b"xdr:ext" => {
+                            self.extent.set_attributes(reader, e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"xdr:absoluteAnchor" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "xdr:absoluteAnchor"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32) {
+        // xdr:absoluteAnchor
+        write_start_tag(writer, "xdr:absoluteAnchor", vec![], false);
+
+        // xdr:pos
+        &self.position.write_to(writer);
+
+        // xdr:ext
+        &self.extent.write_to(writer);
+
+        // xdr:graphicFrame
+        match &self.graphic_frame {
+            Some(v) => {
+                v.write_to(writer, r_id);
+                *r_id += 1i32;
+            },
+            None => {},
+        }
+
+        // xdr:sp
+        match &self.shape {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
+        // xdr:cxnSp
+        match &self.connection_shape {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
+        // xdr:pic
+        match &self.picture {
+            Some(v) => v.write_to(writer, r_id),
+            None => {},
+        }
+
+        // xdr:grpSp
+        match &self.group_shape {
+            Some(v) => v.write_to(writer, r_id),
+            None => {},
+        }
+
+        // xdr:clientData
+        write_start_tag(writer, "xdr:clientData", vec![], true);
+
+        write_end_tag(writer, "xdr:absoluteAnchor");
+    }
+}
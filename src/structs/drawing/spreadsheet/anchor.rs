@@ -0,0 +1,100 @@
+// xdr:twoCellAnchor, xdr:oneCellAnchor, xdr:absoluteAnchor
+use super::TwoCellAnchor;
+use super::OneCellAnchor;
+use super::AbsoluteAnchor;
+use super::GraphicFrame;
+use super::Shape;
+use super::ConnectionShape;
+use super::Picture;
+use super::GroupShape;
+use super::FromMarker;
+use super::ToMarker;
+use super::Extent;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// One anchor of a worksheet drawing, kept in document order.
+#[derive(Debug)]
+pub enum Anchor {
+    /// xdr:twoCellAnchor
+    TwoCell(TwoCellAnchor),
+    /// xdr:oneCellAnchor
+    OneCell(OneCellAnchor),
+    /// xdr:absoluteAnchor
+    Absolute(AbsoluteAnchor),
+}
+impl Anchor {
+    pub fn get_graphic_frame(&self)-> &Option<GraphicFrame> {
+        match self {
+            Anchor::TwoCell(v) => v.get_graphic_frame(),
+            Anchor::OneCell(v) => v.get_graphic_frame(),
+            Anchor::Absolute(v) => v.get_graphic_frame(),
+        }
+    }
+
+    pub fn get_shape(&self)-> &Option<Shape> {
+        match self {
+            Anchor::TwoCell(v) => v.get_shape(),
+            Anchor::OneCell(v) => v.get_shape(),
+            Anchor::Absolute(v) => v.get_shape(),
+        }
+    }
+
+    pub fn get_connection_shape(&self)-> &Option<ConnectionShape> {
+        match self {
+            Anchor::TwoCell(v) => v.get_connection_shape(),
+            Anchor::OneCell(v) => v.get_connection_shape(),
+            Anchor::Absolute(v) => v.get_connection_shape(),
+        }
+    }
+
+    pub fn get_picture(&self)-> &Option<Picture> {
+        match self {
+            Anchor::TwoCell(v) => v.get_picture(),
+            Anchor::OneCell(v) => v.get_picture(),
+            Anchor::Absolute(v) => v.get_picture(),
+        }
+    }
+
+    pub fn get_group_shape(&self)-> &Option<GroupShape> {
+        match self {
+            Anchor::TwoCell(v) => v.get_group_shape(),
+            Anchor::OneCell(v) => v.get_group_shape(),
+            Anchor::Absolute(v) => v.get_group_shape(),
+        }
+    }
+
+    // Objects of the anchor, borrowed together.
+    pub(crate) fn get_objects_mut(&mut self)-> (&mut Option<GraphicFrame>, &mut Option<Shape>, &mut Option<ConnectionShape>, &mut Option<Picture>, &mut Option<GroupShape>) {
+        match self {
+            Anchor::TwoCell(v) => v.get_objects_mut(),
+            Anchor::OneCell(v) => v.get_objects_mut(),
+            Anchor::Absolute(v) => v.get_objects_mut(),
+        }
+    }
+
+    // Absolute anchors do not follow the cells.
+    pub(crate) fn adjustment_insert_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        match self {
+            Anchor::TwoCell(v) => v.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num),
+            Anchor::OneCell(v) => v.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num),
+            Anchor::Absolute(_) => {},
+        }
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        match self {
+            Anchor::TwoCell(v) => v.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num),
+            Anchor::OneCell(v) => v.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num),
+            Anchor::Absolute(_) => {},
+        }
+    }
+
+    pub(crate) fn write_to<F: Fn(&FromMarker, &Extent)-> ToMarker>(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32, get_to_marker:&F) {
+        match self {
+            Anchor::TwoCell(v) => v.write_to(writer, r_id),
+            Anchor::OneCell(v) => v.write_to(writer, r_id, get_to_marker),
+            Anchor::Absolute(v) => v.write_to(writer, r_id),
+        }
+    }
+}
@@ -10,7 +10,7 @@ use quick_xml::Reader;
 use std::io::Cursor;
 use tempdir::TempDir;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct BlipFill {
     rot_with_shape: usize,
     blip: Blip,
@@ -95,6 +95,9 @@ impl BlipFill {
                 },
                 Ok(Event::Empty(ref e)) => {
                     match e.name() {
+                        b"asvg:svgBlip" => {
+                            &mut self.blip.set_svg_attributes(e, dir, target);
+                        },
                         b"a:srcRect" => {
                             let mut source_rectangle = SourceRectangle::default();
                             source_rectangle.set_attributes(reader, e);
@@ -117,7 +120,7 @@ impl BlipFill {
         }
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &i32) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32) {
         // xdr:blipFill
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let rot_with_sharp_str = &self.rot_with_shape.to_string();
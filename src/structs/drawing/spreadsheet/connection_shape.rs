@@ -3,6 +3,7 @@ use super::super::super::Anchor;
 use super::NonVisualConnectionShapeProperties;
 use super::ShapeProperties;
 use super::ShapeStyle;
+use super::ConnectionType;
 use writer::driver::*;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
@@ -65,6 +66,38 @@ impl ConnectionShape {
         self.shape_style = value;
     }
 
+    /// Attach the start of the connector to a shape.
+    /// # Arguments
+    /// * `shape_id` - id of the shape. ex) "2"
+    /// * `index` - connection site of the shape. A rectangle has 0 at the top, 1 at the left, 2 at the bottom and 3 at the right.
+    pub fn set_start_connection_shape<S: Into<String>>(&mut self, shape_id:S, index:&usize) -> &mut ConnectionShape {
+        let mut connection_type = ConnectionType::default();
+        connection_type.set_id(shape_id);
+        connection_type.set_index(index.to_string());
+        self.non_visual_connection_shape_properties.get_non_visual_connector_shape_drawing_properties_mut().set_start_connection(connection_type);
+        self
+    }
+
+    /// Attach the end of the connector to a shape.
+    /// # Arguments
+    /// * `shape_id` - id of the shape. ex) "3"
+    /// * `index` - connection site of the shape. A rectangle has 0 at the top, 1 at the left, 2 at the bottom and 3 at the right.
+    pub fn set_end_connection_shape<S: Into<String>>(&mut self, shape_id:S, index:&usize) -> &mut ConnectionShape {
+        let mut connection_type = ConnectionType::default();
+        connection_type.set_id(shape_id);
+        connection_type.set_index(index.to_string());
+        self.non_visual_connection_shape_properties.get_non_visual_connector_shape_drawing_properties_mut().set_end_connection(connection_type);
+        self
+    }
+
+    // Connector with the geometry and the theme style of a new connector.
+    pub(crate) fn new_connection_shape(geometry:&str) -> ConnectionShape {
+        let mut connection_shape = ConnectionShape::default();
+        connection_shape.get_shape_properties_mut().get_geometry_mut().set_geometry(geometry);
+        connection_shape.set_shape_style(ShapeStyle::new_shape_style("1", "0", "tx1"));
+        connection_shape
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -1,5 +1,6 @@
 // xdr:from
 use writer::driver::*;
+use helper::coordinate::*;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use quick_xml::Reader;
@@ -49,20 +50,25 @@ impl FromMarker {
         self
     }
 
-    pub(crate) fn adjustment_insert_row(&mut self, num_rows:&usize) {
-        self.row += num_rows;
+    pub(crate) fn adjustment_insert_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.col = adjustment_insert_coordinate(&(self.col + 1), root_col_num, offset_col_num) - 1;
+        self.row = adjustment_insert_coordinate(&(self.row + 1), root_row_num, offset_row_num) - 1;
     }
 
-    pub(crate) fn adjustment_insert_colmun(&mut self, num_cols:&usize) {
-        self.col += num_cols;
-    }
-
-    pub(crate) fn adjustment_remove_row(&mut self, num_rows:&usize) {
-        self.row = if &self.row > num_rows { self.row - num_rows } else { 1 };
-    }
-
-    pub(crate) fn adjustment_remove_colmun(&mut self, num_cols:&usize) {
-        self.col = if &self.col > num_cols { self.col - num_cols } else { 1 };
+    pub(crate) fn adjustment_remove_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        // a marker in a removed column or row goes to the start of the next one
+        if offset_col_num > &0 && self.col + 1 >= *root_col_num && self.col + 1 < root_col_num + offset_col_num {
+            self.col = root_col_num - 1;
+            self.col_off = 0;
+        } else {
+            self.col = adjustment_remove_coordinate(&(self.col + 1), root_col_num, offset_col_num) - 1;
+        }
+        if offset_row_num > &0 && self.row + 1 >= *root_row_num && self.row + 1 < root_row_num + offset_row_num {
+            self.row = root_row_num - 1;
+            self.row_off = 0;
+        } else {
+            self.row = adjustment_remove_coordinate(&(self.row + 1), root_row_num, offset_row_num) - 1;
+        }
     }
 
     pub(crate) fn set_attributes(
@@ -32,7 +32,7 @@ impl GraphicFrame {
         &self.non_visual_graphic_frame_properties
     }
 
-    pub fn get_non_visual_graphic_frame_properties_mut(&mut self) -> &NonVisualGraphicFrameProperties {
+    pub fn get_non_visual_graphic_frame_properties_mut(&mut self) -> &mut NonVisualGraphicFrameProperties {
         &mut self.non_visual_graphic_frame_properties
     }
 
@@ -0,0 +1,345 @@
+// xdr:grpSp
+use super::NonVisualGroupShapeProperties;
+use super::GroupShapeProperties;
+use super::Shape;
+use super::ConnectionShape;
+use super::Picture;
+use super::GraphicFrame;
+use super::GroupShapeChild;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+use tempdir::TempDir;
+
+#[derive(Default, Debug)]
+pub struct GroupShape {
+    non_visual_group_shape_properties: NonVisualGroupShapeProperties,
+    group_shape_properties: GroupShapeProperties,
+    child_collection: Vec<GroupShapeChild>,
+}
+impl GroupShape {
+    pub fn get_non_visual_group_shape_properties(&self) -> &NonVisualGroupShapeProperties {
+        &self.non_visual_group_shape_properties
+    }
+
+    pub fn get_non_visual_group_shape_properties_mut(&mut self) -> &mut NonVisualGroupShapeProperties {
+        &mut self.non_visual_group_shape_properties
+    }
+
+    pub fn set_non_visual_group_shape_properties(&mut self, value:NonVisualGroupShapeProperties) {
+        self.non_visual_group_shape_properties = value;
+    }
+
+    pub fn get_group_shape_properties(&self) -> &GroupShapeProperties {
+        &self.group_shape_properties
+    }
+
+    pub fn get_group_shape_properties_mut(&mut self) -> &mut GroupShapeProperties {
+        &mut self.group_shape_properties
+    }
+
+    pub fn set_group_shape_properties(&mut self, value:GroupShapeProperties) {
+        self.group_shape_properties = value;
+    }
+
+    /// Objects of the group, in the order they are written.
+    pub fn get_child_collection(&self) -> &Vec<GroupShapeChild> {
+        &self.child_collection
+    }
+
+    pub fn get_child_collection_mut(&mut self) -> &mut Vec<GroupShapeChild> {
+        &mut self.child_collection
+    }
+
+    pub fn add_child_collection(&mut self, value:GroupShapeChild) {
+        self.child_collection.push(value);
+    }
+
+    pub fn get_shape_collection(&self) -> Vec<&Shape> {
+        self.child_collection.iter().filter_map(|v| match v {
+            GroupShapeChild::Shape(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_shape_collection_mut(&mut self) -> Vec<&mut Shape> {
+        self.child_collection.iter_mut().filter_map(|v| match v {
+            GroupShapeChild::Shape(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_shape_collection(&mut self, value:Shape) {
+        self.child_collection.push(GroupShapeChild::Shape(value));
+    }
+
+    pub fn get_connection_shape_collection(&self) -> Vec<&ConnectionShape> {
+        self.child_collection.iter().filter_map(|v| match v {
+            GroupShapeChild::ConnectionShape(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_connection_shape_collection_mut(&mut self) -> Vec<&mut ConnectionShape> {
+        self.child_collection.iter_mut().filter_map(|v| match v {
+            GroupShapeChild::ConnectionShape(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_connection_shape_collection(&mut self, value:ConnectionShape) {
+        self.child_collection.push(GroupShapeChild::ConnectionShape(value));
+    }
+
+    pub fn get_graphic_frame_collection(&self) -> Vec<&GraphicFrame> {
+        self.child_collection.iter().filter_map(|v| match v {
+            GroupShapeChild::GraphicFrame(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_graphic_frame_collection_mut(&mut self) -> Vec<&mut GraphicFrame> {
+        self.child_collection.iter_mut().filter_map(|v| match v {
+            GroupShapeChild::GraphicFrame(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_graphic_frame_collection(&mut self, value:GraphicFrame) {
+        self.child_collection.push(GroupShapeChild::GraphicFrame(value));
+    }
+
+    pub fn get_picture_collection(&self) -> Vec<&Picture> {
+        self.child_collection.iter().filter_map(|v| match v {
+            GroupShapeChild::Picture(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_picture_collection_mut(&mut self) -> Vec<&mut Picture> {
+        self.child_collection.iter_mut().filter_map(|v| match v {
+            GroupShapeChild::Picture(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_picture_collection(&mut self, value:Picture) {
+        self.child_collection.push(GroupShapeChild::Picture(value));
+    }
+
+    pub fn get_group_shape_collection(&self) -> Vec<&GroupShape> {
+        self.child_collection.iter().filter_map(|v| match v {
+            GroupShapeChild::GroupShape(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_group_shape_collection_mut(&mut self) -> Vec<&mut GroupShape> {
+        self.child_collection.iter_mut().filter_map(|v| match v {
+            GroupShapeChild::GroupShape(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_group_shape_collection(&mut self, value:GroupShape) {
+        self.child_collection.push(GroupShapeChild::GroupShape(value));
+    }
+
+    // Objects of this group and of the groups in it, in document order.
+    // Charts and pictures come in the order their relationships are written.
+    pub(crate) fn collect_shapes<'a>(&'a self, result:&mut Vec<&'a Shape>) {
+        for child in &self.child_collection {
+            match child {
+                GroupShapeChild::Shape(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_shapes(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_shapes_mut<'a>(&'a mut self, result:&mut Vec<&'a mut Shape>) {
+        for child in &mut self.child_collection {
+            match child {
+                GroupShapeChild::Shape(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_shapes_mut(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_connection_shapes<'a>(&'a self, result:&mut Vec<&'a ConnectionShape>) {
+        for child in &self.child_collection {
+            match child {
+                GroupShapeChild::ConnectionShape(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_connection_shapes(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_connection_shapes_mut<'a>(&'a mut self, result:&mut Vec<&'a mut ConnectionShape>) {
+        for child in &mut self.child_collection {
+            match child {
+                GroupShapeChild::ConnectionShape(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_connection_shapes_mut(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_graphic_frames<'a>(&'a self, result:&mut Vec<&'a GraphicFrame>) {
+        for child in &self.child_collection {
+            match child {
+                GroupShapeChild::GraphicFrame(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_graphic_frames(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_graphic_frames_mut<'a>(&'a mut self, result:&mut Vec<&'a mut GraphicFrame>) {
+        for child in &mut self.child_collection {
+            match child {
+                GroupShapeChild::GraphicFrame(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_graphic_frames_mut(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_pictures<'a>(&'a self, result:&mut Vec<&'a Picture>) {
+        for child in &self.child_collection {
+            match child {
+                GroupShapeChild::Picture(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_pictures(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_pictures_mut<'a>(&'a mut self, result:&mut Vec<&'a mut Picture>) {
+        for child in &mut self.child_collection {
+            match child {
+                GroupShapeChild::Picture(v) => result.push(v),
+                GroupShapeChild::GroupShape(v) => v.collect_pictures_mut(result),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_group_shapes<'a>(&'a self, result:&mut Vec<&'a GroupShape>) {
+        result.push(self);
+        for child in &self.child_collection {
+            match child {
+                GroupShapeChild::GroupShape(v) => v.collect_group_shapes(result),
+                _ => {}
+            }
+        }
+    }
+
+    // This group or a group in it with the id.
+    pub(crate) fn find_group_shape_mut(&mut self, id:&str) -> Option<&mut GroupShape> {
+        if self.non_visual_group_shape_properties.get_non_visual_drawing_properties().get_id() == id {
+            return Some(self);
+        }
+        for child in &mut self.child_collection {
+            match child {
+                GroupShapeChild::GroupShape(v) => match v.find_group_shape_mut(id) {
+                    Some(v) => return Some(v),
+                    None => {}
+                },
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart,
+        dir: &TempDir,
+        target: &str,
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"xdr:nvGrpSpPr" => {
+                            &mut self.non_visual_group_shape_properties.set_attributes(reader, e);
+                        },
+                        b"xdr:grpSpPr" => {
+                            &mut self.group_shape_properties.set_attributes(reader, e);
+                        },
+                        b"xdr:sp" => {
+                            let mut obj = Shape::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.add_shape_collection(obj);
+                        },
+                        b"xdr:cxnSp" => {
+                            let mut obj = ConnectionShape::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.add_connection_shape_collection(obj);
+                        },
+                        b"xdr:graphicFrame" => {
+                            let mut obj = GraphicFrame::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            &mut self.add_graphic_frame_collection(obj);
+                        },
+                        b"xdr:pic" => {
+                            let mut obj = Picture::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            &mut self.add_picture_collection(obj);
+                        },
+                        b"xdr:grpSp" => {
+                            let mut obj = GroupShape::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            &mut self.add_group_shape_collection(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"xdr:grpSp" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "xdr:grpSp"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32) {
+        // xdr:grpSp
+        write_start_tag(writer, "xdr:grpSp", vec![], false);
+
+        // xdr:nvGrpSpPr
+        &self.non_visual_group_shape_properties.write_to(writer);
+
+        // xdr:grpSpPr
+        &self.group_shape_properties.write_to(writer);
+
+        // xdr:sp, xdr:cxnSp, xdr:graphicFrame, xdr:pic, xdr:grpSp
+        for child in &self.child_collection {
+            match child {
+                GroupShapeChild::Shape(v) => v.write_to(writer),
+                GroupShapeChild::ConnectionShape(v) => v.write_to(writer),
+                GroupShapeChild::GraphicFrame(v) => {
+                    v.write_to(writer, r_id);
+                    *r_id += 1i32;
+                },
+                GroupShapeChild::Picture(v) => v.write_to(writer, r_id),
+                GroupShapeChild::GroupShape(v) => v.write_to(writer, r_id),
+            }
+        }
+
+        write_end_tag(writer, "xdr:grpSp");
+    }
+}
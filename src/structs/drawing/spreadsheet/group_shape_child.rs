@@ -0,0 +1,21 @@
+// xdr:sp, xdr:cxnSp, xdr:graphicFrame, xdr:pic, xdr:grpSp
+use super::Shape;
+use super::ConnectionShape;
+use super::GraphicFrame;
+use super::Picture;
+use super::GroupShape;
+
+/// One object of a group, kept in document order.
+#[derive(Debug)]
+pub enum GroupShapeChild {
+    /// xdr:sp
+    Shape(Shape),
+    /// xdr:cxnSp
+    ConnectionShape(ConnectionShape),
+    /// xdr:graphicFrame
+    GraphicFrame(GraphicFrame),
+    /// xdr:pic
+    Picture(Picture),
+    /// xdr:grpSp
+    GroupShape(GroupShape),
+}
@@ -0,0 +1,161 @@
+// xdr:grpSpPr
+use super::super::Transform2D;
+use super::super::SolidFill;
+use super::super::NoFill;
+use super::super::EffectList;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct GroupShapeProperties {
+    transform2d: Option<Transform2D>,
+    solid_fill: Option<SolidFill>,
+    no_fill: Option<NoFill>,
+    effect_list: Option<EffectList>,
+}
+impl GroupShapeProperties {
+    pub fn get_transform2d(&self) -> &Option<Transform2D> {
+        &self.transform2d
+    }
+
+    pub fn get_transform2d_mut(&mut self) -> &mut Option<Transform2D> {
+        &mut self.transform2d
+    }
+
+    pub fn set_transform2d(&mut self, value:Transform2D) {
+        self.transform2d = Some(value);
+    }
+
+    pub fn get_solid_fill(&self) -> &Option<SolidFill> {
+        &self.solid_fill
+    }
+
+    pub fn get_solid_fill_mut(&mut self) -> &mut Option<SolidFill> {
+        &mut self.solid_fill
+    }
+
+    /// Fill the group with one color. Clears no_fill.
+    pub fn set_solid_fill(&mut self, value:SolidFill) {
+        self.solid_fill = Some(value);
+        self.no_fill = None;
+    }
+
+    pub fn get_no_fill(&self) -> &Option<NoFill> {
+        &self.no_fill
+    }
+
+    pub fn get_no_fill_mut(&mut self) -> &mut Option<NoFill> {
+        &mut self.no_fill
+    }
+
+    /// Leave the group unfilled. Clears solid_fill.
+    pub fn set_no_fill(&mut self, value:NoFill) {
+        self.no_fill = Some(value);
+        self.solid_fill = None;
+    }
+
+    pub fn get_effect_list(&self) -> &Option<EffectList> {
+        &self.effect_list
+    }
+
+    pub fn get_effect_list_mut(&mut self) -> &mut Option<EffectList> {
+        &mut self.effect_list
+    }
+
+    pub fn set_effect_list(&mut self, value:EffectList) {
+        self.effect_list = Some(value);
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"a:xfrm" => {
+                            let mut obj = Transform2D::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_transform2d(obj);
+                        },
+                        b"a:solidFill" => {
+                            let mut obj = SolidFill::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_solid_fill(obj);
+                        },
+                        b"a:effectLst" => {
+                            let mut obj = EffectList::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_effect_list(obj);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:noFill" => {
+                            let mut obj = NoFill::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_no_fill(obj);
+                        },
+                        b"a:effectLst" => {
+                            &mut self.set_effect_list(EffectList::default());
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"xdr:grpSpPr" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "xdr:grpSpPr"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // xdr:grpSpPr
+        if self.transform2d.is_none() && self.solid_fill.is_none() && self.no_fill.is_none() && self.effect_list.is_none() {
+            write_start_tag(writer, "xdr:grpSpPr", vec![], true);
+            return;
+        }
+        write_start_tag(writer, "xdr:grpSpPr", vec![], false);
+
+        // a:xfrm
+        match &self.transform2d {
+            Some(v) => v.write_to(writer),
+            None => {}
+        }
+
+        // a:noFill
+        match &self.no_fill {
+            Some(v) => v.write_to(writer),
+            None => {}
+        }
+
+        // a:solidFill
+        match &self.solid_fill {
+            Some(v) => v.write_to(writer),
+            None => {}
+        }
+
+        // a:effectLst
+        match &self.effect_list {
+            Some(v) => v.write_to(writer),
+            None => {}
+        }
+
+        write_end_tag(writer, "xdr:grpSpPr");
+    }
+}
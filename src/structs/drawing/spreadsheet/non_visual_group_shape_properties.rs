@@ -0,0 +1,76 @@
+// xdr:nvGrpSpPr
+use super::NonVisualDrawingProperties;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct NonVisualGroupShapeProperties {
+    non_visual_drawing_properties: NonVisualDrawingProperties,
+}
+impl NonVisualGroupShapeProperties {
+    pub fn get_non_visual_drawing_properties(&self) -> &NonVisualDrawingProperties {
+        &self.non_visual_drawing_properties
+    }
+
+    pub fn get_non_visual_drawing_properties_mut(&mut self) -> &mut NonVisualDrawingProperties {
+        &mut self.non_visual_drawing_properties
+    }
+
+    pub fn set_non_visual_drawing_properties(&mut self, value:NonVisualDrawingProperties) {
+        self.non_visual_drawing_properties = value;
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"xdr:cNvPr" => {
+                            &mut self.non_visual_drawing_properties.set_attributes(reader, e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"xdr:cNvPr" => {
+                            &mut self.non_visual_drawing_properties.set_attributes(reader, e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"xdr:nvGrpSpPr" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "xdr:nvGrpSpPr"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // xdr:nvGrpSpPr
+        write_start_tag(writer, "xdr:nvGrpSpPr", vec![], false);
+
+        // xdr:cNvPr
+        &self.non_visual_drawing_properties.write_to(writer);
+
+        // xdr:cNvGrpSpPr
+        write_start_tag(writer, "xdr:cNvGrpSpPr", vec![], true);
+
+        write_end_tag(writer, "xdr:nvGrpSpPr");
+    }
+}
@@ -7,7 +7,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct NonVisualPictureProperties {
     non_visual_drawing_properties: NonVisualDrawingProperties,
     non_visual_picture_drawing_properties: NonVisualPictureDrawingProperties,
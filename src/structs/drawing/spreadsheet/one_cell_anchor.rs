@@ -0,0 +1,273 @@
+// xdr:oneCellAnchor
+use super::FromMarker;
+use super::Extent;
+use super::ToMarker;
+use super::GraphicFrame;
+use super::Shape;
+use super::ConnectionShape;
+use super::Picture;
+use super::GroupShape;
+use writer::driver::*;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use quick_xml::Reader;
+use std::io::Cursor;
+use tempdir::TempDir;
+
+#[derive(Default, Debug)]
+pub struct OneCellAnchor {
+    from_marker: FromMarker,
+    extent: Extent,
+    graphic_frame: Option<GraphicFrame>,
+    shape: Option<Shape>,
+    connection_shape: Option<ConnectionShape>,
+    picture: Option<Picture>,
+    group_shape: Option<GroupShape>,
+}
+impl OneCellAnchor {
+    pub fn get_from_marker(&self)-> &FromMarker {
+        &self.from_marker
+    }
+
+    pub fn get_from_marker_mut(&mut self)-> &mut FromMarker {
+        &mut self.from_marker
+    }
+
+    pub fn set_from_marker(&mut self, value:FromMarker)-> &mut OneCellAnchor {
+        self.from_marker = value;
+        self
+    }
+
+    pub fn get_extent(&self)-> &Extent {
+        &self.extent
+    }
+
+    pub fn get_extent_mut(&mut self)-> &mut Extent {
+        &mut self.extent
+    }
+
+    pub fn set_extent(&mut self, value:Extent)-> &mut OneCellAnchor {
+        self.extent = value;
+        self
+    }
+
+    pub fn get_graphic_frame(&self)-> &Option<GraphicFrame> {
+        &self.graphic_frame
+    }
+
+    pub fn get_graphic_frame_mut(&mut self)-> &mut Option<GraphicFrame> {
+        &mut self.graphic_frame
+    }
+
+    pub fn set_graphic_frame(&mut self, value:GraphicFrame)-> &mut OneCellAnchor {
+        self.graphic_frame = Some(value);
+        self
+    }
+
+    pub fn get_shape(&self)-> &Option<Shape> {
+        &self.shape
+    }
+
+    pub fn get_shape_mut(&mut self)-> &mut Option<Shape> {
+        &mut self.shape
+    }
+
+    pub fn set_shape(&mut self, value:Shape)-> &mut OneCellAnchor {
+        self.shape = Some(value);
+        self
+    }
+
+    pub fn get_connection_shape(&self)-> &Option<ConnectionShape> {
+        &self.connection_shape
+    }
+
+    pub fn get_connection_shape_mut(&mut self)-> &mut Option<ConnectionShape> {
+        &mut self.connection_shape
+    }
+
+    pub fn set_connection_shape(&mut self, value:ConnectionShape)-> &mut OneCellAnchor {
+        self.connection_shape = Some(value);
+        self
+    }
+
+    pub fn get_picture(&self)-> &Option<Picture> {
+        &self.picture
+    }
+
+    pub fn get_picture_mut(&mut self)-> &mut Option<Picture> {
+        &mut self.picture
+    }
+
+    pub fn set_picture(&mut self, value:Picture)-> &mut OneCellAnchor {
+        self.picture = Some(value);
+        self
+    }
+
+    pub fn get_group_shape(&self)-> &Option<GroupShape> {
+        &self.group_shape
+    }
+
+    pub fn get_group_shape_mut(&mut self)-> &mut Option<GroupShape> {
+        &mut self.group_shape
+    }
+
+    pub fn set_group_shape(&mut self, value:GroupShape)-> &mut OneCellAnchor {
+        self.group_shape = Some(value);
+        self
+    }
+
+    // Objects of the anchor, borrowed together.
+    pub(crate) fn get_objects_mut(&mut self)-> (&mut Option<GraphicFrame>, &mut Option<Shape>, &mut Option<ConnectionShape>, &mut Option<Picture>, &mut Option<GroupShape>) {
+        (&mut self.graphic_frame, &mut self.shape, &mut self.connection_shape, &mut self.picture, &mut self.group_shape)
+    }
+
+    pub(crate) fn adjustment_insert_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.from_marker.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        self.from_marker.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+    }
+
+    pub(crate) fn is_support(&self) -> bool {
+        match &self.graphic_frame {
+            Some(v) => {
+                return v.get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area().is_support();
+            },
+            None => {}
+        }
+        true
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        _e:&BytesStart,
+        dir: &TempDir,
+        target: &str,
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"xdr:from" => {
+                            self.from_marker.set_attributes(reader, e);
+                        },
+                        b"xdr:graphicFrame" => {
+                            let mut obj = GraphicFrame::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_graphic_frame(obj);
+                        },
+                        b"xdr:sp" => {
+                            let mut obj = Shape::default();
+                            obj.set_attributes(reader, e);
+                            self.set_shape(obj);
+                        },
+                        b"xdr:cxnSp" => {
+                            let mut obj = ConnectionShape::default();
+                            obj.set_attributes(reader, e);
+                            self.set_connection_shape(obj);
+                        }
+                        b"xdr:pic" => {
+                            let mut obj = Picture::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_picture(obj);
+                        }
+                        b"xdr:grpSp" => {
+                            let mut obj = GroupShape::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_group_shape(obj);
+                        }
+                        _ => (),
+                    }
+                },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"xdr:ext" => {
+                            self.extent.set_attributes(reader, e);
+                        },
+                        _ => (),
+                    }
+                },
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"xdr:oneCellAnchor" => return,
+                        _ => (),
+                    }
+                },
+                Ok(Event::Eof) => panic!("Error not find {} end element", "xdr:oneCellAnchor"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    // A picture with edit_as is saved in a two cell anchor ending at `get_to_marker`.
+    pub(crate) fn write_to<F: Fn(&FromMarker, &Extent)-> ToMarker>(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32, get_to_marker:&F) {
+        match &self.picture {
+            Some(picture) => match picture.get_edit_as() {
+                Some(edit_as) => {
+                    // xdr:twoCellAnchor
+                    write_start_tag(writer, "xdr:twoCellAnchor", vec![("editAs", edit_as)], false);
+                    &self.from_marker.write_to(writer);
+                    get_to_marker(&self.from_marker, &self.extent).write_to(writer);
+                    picture.write_to(writer, r_id);
+                    write_start_tag(writer, "xdr:clientData", vec![], true);
+                    write_end_tag(writer, "xdr:twoCellAnchor");
+                    return;
+                },
+                None => {},
+            },
+            None => {},
+        }
+
+        // xdr:oneCellAnchor
+        write_start_tag(writer, "xdr:oneCellAnchor", vec![], false);
+
+        // xdr:from
+        &self.from_marker.write_to(writer);
+
+        // xdr:ext
+        &self.extent.write_to(writer);
+
+        // xdr:graphicFrame
+        match &self.graphic_frame {
+            Some(v) => {
+                v.write_to(writer, r_id);
+                *r_id += 1i32;
+            },
+            None => {},
+        }
+
+        // xdr:sp
+        match &self.shape {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
+        // xdr:cxnSp
+        match &self.connection_shape {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
+        // xdr:pic
+        match &self.picture {
+            Some(v) => v.write_to(writer, r_id),
+            None => {},
+        }
+
+        // xdr:grpSp
+        match &self.group_shape {
+            Some(v) => v.write_to(writer, r_id),
+            None => {},
+        }
+
+        // xdr:clientData
+        write_start_tag(writer, "xdr:clientData", vec![], true);
+
+        write_end_tag(writer, "xdr:oneCellAnchor");
+    }
+}
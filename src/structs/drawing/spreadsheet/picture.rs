@@ -3,6 +3,9 @@ use super::super::super::Anchor;
 use super::NonVisualPictureProperties;
 use super::BlipFill;
 use super::ShapeProperties;
+use super::TwoCellAnchor;
+use super::super::FillRectangle;
+use super::super::PresetGeometry;
 use writer::driver::*;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
@@ -10,12 +13,13 @@ use quick_xml::Reader;
 use std::io::Cursor;
 use tempdir::TempDir;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Picture {
     anchor: Anchor,
     non_visual_picture_properties: NonVisualPictureProperties,
     blip_fill: BlipFill,
     shape_properties: ShapeProperties,
+    edit_as: Option<String>,
 }
 impl Picture {
     pub fn get_anchor(&self) -> &Anchor {
@@ -66,6 +70,53 @@ impl Picture {
         self.shape_properties = value;
     }
 
+    /// Get how a picture anchored to one cell follows the cells. None keeps the one cell anchor.
+    pub fn get_edit_as(&self) -> &Option<String> {
+        &self.edit_as
+    }
+
+    /// Set how a picture anchored to one cell follows the cells.
+    /// The picture is then saved in a two cell anchor ending where its size reaches.
+    /// # Arguments
+    /// * `value` - ex) TwoCellAnchor::EDIT_AS_TWO_CELL
+    /// # Return value
+    /// * `Result<&mut Picture, &'static str>` - Err if the value is not one of TwoCellAnchor::EDIT_AS_*.
+    pub fn set_edit_as(&mut self, value:&str) -> Result<&mut Picture, &'static str> {
+        match value {
+            TwoCellAnchor::EDIT_AS_TWO_CELL | TwoCellAnchor::EDIT_AS_ONE_CELL | TwoCellAnchor::EDIT_AS_ABSOLUTE => {},
+            _ => return Err("Invalid edit_as."),
+        }
+        self.edit_as = Some(value.to_string());
+        Ok(self)
+    }
+
+    pub fn remove_edit_as(&mut self) {
+        self.edit_as = None;
+    }
+
+    /// Make a picture of the image at its full size.
+    /// # Arguments
+    /// * `image_name` - file name of the image in the package. ex) "image1.png"
+    /// * `image_data` - content of the image file.
+    /// * `width` - width in EMU.
+    /// * `height` - height in EMU.
+    pub(crate) fn new_picture<S: Into<String>>(image_name:S, image_data:Vec<u8>, width:usize, height:usize)-> Picture {
+        let mut picture = Picture::default();
+        picture.get_non_visual_picture_properties_mut().get_non_visual_picture_drawing_properties_mut()
+            .get_picture_locks_mut().set_no_change_aspect(true);
+
+        let blip = picture.get_blip_fill_mut().get_blip_mut();
+        blip.set_image_name(image_name);
+        blip.set_image_data(image_data);
+        picture.get_blip_fill_mut().get_stretch_mut().set_fill_rectangle(FillRectangle::default());
+
+        let transform2d = picture.get_shape_properties_mut().get_transform2d_mut();
+        transform2d.set_width(width);
+        transform2d.set_height(height);
+        picture.get_shape_properties_mut().get_geometry_mut().set_geometry(PresetGeometry::GEOMETRY_RECT);
+        picture
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -105,7 +156,8 @@ impl Picture {
         }
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &i32) {
+    // Takes one relationship id, or two with an SVG image.
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32) {
         // xdr:pic
         write_start_tag(writer, "xdr:pic", vec![], false);
 
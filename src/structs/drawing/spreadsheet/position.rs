@@ -0,0 +1,49 @@
+// xdr:pos
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct Position {
+    x: usize,
+    y: usize,
+}
+impl Position {
+    pub fn get_x(&self) -> &usize {
+        &self.x
+    }
+
+    pub fn set_x(&mut self, value:usize) -> &mut Position {
+        self.x = value;
+        self
+    }
+
+    pub fn get_y(&self) -> &usize {
+        &self.y
+    }
+
+    pub fn set_y(&mut self, value:usize) -> &mut Position {
+        self.y = value;
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader:&mut Reader<std::io::BufReader<std::fs::File>>,
+        e:&BytesStart
+    ) {
+        self.x = get_attribute(e, b"x").unwrap().parse::<usize>().unwrap();
+        self.y = get_attribute(e, b"y").unwrap().parse::<usize>().unwrap();
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // xdr:pos
+        write_start_tag(writer, "xdr:pos", vec![
+            ("x", &self.x.to_string()),
+            ("y", &self.y.to_string()),
+        ], true);
+    }
+}
@@ -3,6 +3,9 @@ use super::NonVisualShapeProperties;
 use super::ShapeProperties;
 use super::ShapeStyle;
 use super::TextBody;
+use super::super::Paragraph;
+use super::super::Run;
+use super::super::RunProperties;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
@@ -78,6 +81,69 @@ impl Shape {
         self.text_body = value;
     }
 
+    /// Get the text of the shape. Paragraphs are separated by a line feed.
+    pub fn get_text(&self) -> String {
+        self.text_body.get_paragraph().iter().map(|paragraph| {
+            paragraph.get_run().iter().map(|run| run.get_text()).collect::<Vec<&str>>().concat()
+        }).collect::<Vec<String>>().join("\n")
+    }
+
+    /// Replace the text of the shape.
+    /// # Arguments
+    /// * `value` - text. Each line becomes a paragraph.
+    pub fn set_text<S: Into<String>>(&mut self, value:S) -> &mut Shape {
+        self.text_body.get_paragraph_mut().clear();
+        for line in value.into().split('\n') {
+            self.add_text_paragraph();
+            if line != "" {
+                self.add_text_run(line);
+            }
+        }
+        self
+    }
+
+    /// Start a new paragraph. Runs added after it go to the new paragraph.
+    pub fn add_text_paragraph(&mut self) -> &mut Shape {
+        self.text_body.add_paragraph(Paragraph::default());
+        self
+    }
+
+    /// Add a run of text to the last paragraph.
+    /// # Arguments
+    /// * `value` - text of the run.
+    /// # Return value
+    /// * `&mut RunProperties` - font, size and color of the run.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let shape = book.get_sheet_mut(0).add_shape(umya_spreadsheet::drawing::PresetGeometry::GEOMETRY_RECT, "B2:E6").unwrap();
+    /// shape.add_text_run("Total: ").set_bold("1");
+    /// shape.add_text_run("120").set_font_size(14.0).set_color("FF0000");
+    /// ```
+    pub fn add_text_run<S: Into<String>>(&mut self, value:S) -> &mut RunProperties {
+        if self.text_body.get_paragraph().is_empty() {
+            self.add_text_paragraph();
+        }
+        let mut run = Run::default();
+        run.set_text(value);
+        let paragraph = self.text_body.get_paragraph_mut().last_mut().unwrap();
+        paragraph.add_run(run);
+        paragraph.get_run_mut().last_mut().unwrap().get_run_properties_mut()
+    }
+
+    // Shape with the geometry and the theme style of a new shape.
+    pub(crate) fn new_shape(geometry:&str) -> Shape {
+        let mut shape = Shape::default();
+        shape.get_shape_properties_mut().get_geometry_mut().set_geometry(geometry);
+        shape.set_shape_style(ShapeStyle::new_shape_style("2", "1", "lt1"));
+        let body_properties = shape.get_text_body_mut().get_body_properties_mut();
+        body_properties.set_vert_overflow("clip");
+        body_properties.set_horz_overflow("clip");
+        body_properties.set_rtl_col("0");
+        body_properties.set_anchor("t");
+        shape
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
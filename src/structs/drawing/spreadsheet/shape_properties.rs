@@ -1,19 +1,23 @@
 // xdr:spPr
 use super::super::Transform2D;
 use super::super::PresetGeometry;
+use super::super::CustomGeometry;
 use super::super::SolidFill;
 use super::super::Outline;
 use super::super::EffectList;
+use super::super::HeadEnd;
+use super::super::TailEnd;
 use writer::driver::*;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use quick_xml::Reader;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct ShapeProperties {
     transform2d: Transform2D,
     preset_geometry: PresetGeometry,
+    custom_geometry: Option<CustomGeometry>,
     solid_fill: Option<SolidFill>,
     outline: Option<Outline>,
     effect_list: Option<EffectList>,
@@ -31,6 +35,23 @@ impl ShapeProperties {
         self.preset_geometry = value;
     }
 
+    pub fn get_custom_geometry(&self) -> &Option<CustomGeometry> {
+        &self.custom_geometry
+    }
+
+    pub fn get_custom_geometry_mut(&mut self) -> &mut Option<CustomGeometry> {
+        &mut self.custom_geometry
+    }
+
+    /// Set the custom geometry. It is written instead of the preset geometry.
+    pub fn set_custom_geometry(&mut self, value:CustomGeometry) {
+        self.custom_geometry = Some(value);
+    }
+
+    pub fn remove_custom_geometry(&mut self) {
+        self.custom_geometry = None;
+    }
+
     pub fn get_transform2d(&self) -> &Transform2D {
         &self.transform2d
     }
@@ -79,6 +100,57 @@ impl ShapeProperties {
         self.effect_list = Some(value);
     }
 
+    /// Fill the shape with a solid color.
+    /// # Arguments
+    /// * `value` - RGB color. ex) "4472C4"
+    pub fn set_fill_color<S: Into<String>>(&mut self, value:S) -> &mut ShapeProperties {
+        self.set_solid_fill(SolidFill::new_solid_fill(value));
+        self
+    }
+
+    /// Set the color of the outline.
+    /// # Arguments
+    /// * `value` - RGB color. ex) "2F528F"
+    pub fn set_outline_color<S: Into<String>>(&mut self, value:S) -> &mut ShapeProperties {
+        self.get_outline_or_default().set_solid_fill(SolidFill::new_solid_fill(value));
+        self
+    }
+
+    /// Set the width of the outline.
+    /// # Arguments
+    /// * `value` - width in EMU. 12700 EMU is 1 point.
+    pub fn set_outline_width(&mut self, value:u32) -> &mut ShapeProperties {
+        self.get_outline_or_default().set_width(value);
+        self
+    }
+
+    /// Set the arrowhead at the start of the line.
+    /// # Arguments
+    /// * `value` - type of the arrowhead. ex) HeadEnd::TYPE_TRIANGLE
+    pub fn set_head_end_type<S: Into<String>>(&mut self, value:S) -> &mut ShapeProperties {
+        let mut head_end = HeadEnd::default();
+        head_end.set_type(value);
+        self.get_outline_or_default().set_head_end(head_end);
+        self
+    }
+
+    /// Set the arrowhead at the end of the line.
+    /// # Arguments
+    /// * `value` - type of the arrowhead. ex) TailEnd::TYPE_TRIANGLE
+    pub fn set_tail_end_type<S: Into<String>>(&mut self, value:S) -> &mut ShapeProperties {
+        let mut tail_end = TailEnd::default();
+        tail_end.set_type(value);
+        self.get_outline_or_default().set_tail_end(tail_end);
+        self
+    }
+
+    fn get_outline_or_default(&mut self) -> &mut Outline {
+        if self.outline.is_none() {
+            self.outline = Some(Outline::default());
+        }
+        self.outline.as_mut().unwrap()
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -95,6 +167,11 @@ impl ShapeProperties {
                         b"a:prstGeom" => {
                             &mut self.preset_geometry.set_attributes(reader, e);
                         },
+                        b"a:custGeom" => {
+                            let mut custom_geometry = CustomGeometry::default();
+                            custom_geometry.set_attributes(reader, e);
+                            &mut self.set_custom_geometry(custom_geometry);
+                        },
                         b"a:ln" => {
                             let mut outline = Outline::default();
                             outline.set_attributes(reader, e);
@@ -134,8 +211,12 @@ impl ShapeProperties {
         // a:xfrm
         &self.transform2d.write_to(writer);
     
-        // a:prstGeom
-        &self.preset_geometry.write_to(writer);
+        match &self.custom_geometry {
+            // a:custGeom
+            Some(v) => v.write_to(writer),
+            // a:prstGeom
+            None => self.preset_geometry.write_to(writer),
+        }
     
         // a:solidFill
         match &self.solid_fill {
@@ -1,5 +1,6 @@
 // xdr:style
 use super::super::StyleMatrixReferenceType;
+use super::super::SchemeColor;
 use writer::driver::*;
 use quick_xml::Reader;
 use quick_xml::events::{Event, BytesStart};
@@ -46,6 +47,24 @@ impl ShapeStyle {
         self.font_reference = Some(value);
     }
 
+    // Theme references Excel gives a new shape or connector.
+    pub(crate) fn new_shape_style(line_index:&str, fill_index:&str, font_color:&str) -> ShapeStyle {
+        let new_reference = |index:&str, color:&str| {
+            let mut scheme_color = SchemeColor::default();
+            scheme_color.set_val(color);
+            let mut reference = StyleMatrixReferenceType::default();
+            reference.set_index(index);
+            reference.set_scheme_color(scheme_color);
+            reference
+        };
+        let mut shape_style = ShapeStyle::default();
+        shape_style.set_line_reference(new_reference(line_index, "accent1"));
+        shape_style.set_fill_reference(new_reference(fill_index, "accent1"));
+        shape_style.set_effect_reference(new_reference("0", "accent1"));
+        shape_style.set_font_reference(new_reference("minor", font_color));
+        shape_style
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
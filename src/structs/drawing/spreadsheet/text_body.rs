@@ -75,6 +75,9 @@ impl TextBody {
                             body_properties.set_attributes(reader, e);
                             &mut self.set_body_properties(body_properties);
                         },
+                        b"a:p" => {
+                            &mut self.add_paragraph(Paragraph::default());
+                        },
                         _ => (),
                     }
                 },
@@ -102,9 +105,13 @@ impl TextBody {
         // a:lstStyle
         write_start_tag(writer, "a:lstStyle", vec![], true);
 
+        // a:p
         for content in &self.paragraph {
             content.write_to(writer);
         }
+        if self.paragraph.is_empty() {
+            write_start_tag(writer, "a:p", vec![], true);
+        }
 
         write_end_tag(writer, "xdr:txBody");
     }
@@ -5,6 +5,7 @@ use super::GraphicFrame;
 use super::Shape;
 use super::ConnectionShape;
 use super::Picture;
+use super::GroupShape;
 use writer::driver::*;
 use reader::driver::*;
 use quick_xml::events::{Event, BytesStart};
@@ -22,12 +23,29 @@ pub struct TwoCellAnchor {
     shape: Option<Shape>,
     connection_shape: Option<ConnectionShape>,
     picture: Option<Picture>,
+    group_shape: Option<GroupShape>,
 }
 impl TwoCellAnchor {
+    // editAs
+    /// Move and resize with the cells.
+    pub const EDIT_AS_TWO_CELL: &'static str = "twoCell";
+    /// Move with the cell of the top left corner, keeping the size.
+    pub const EDIT_AS_ONE_CELL: &'static str = "oneCell";
+    /// Neither move nor resize with the cells.
+    pub const EDIT_AS_ABSOLUTE: &'static str = "absolute";
+
     pub fn get_edit_as(&self)-> &Option<String> {
         &self.edit_as
     }
 
+    /// Get how the anchor follows the cells. EDIT_AS_TWO_CELL if not set.
+    pub fn get_edit_as_str(&self)-> &str {
+        match &self.edit_as {
+            Some(v) => v,
+            None => TwoCellAnchor::EDIT_AS_TWO_CELL,
+        }
+    }
+
     pub fn set_edit_as<S: Into<String>>(&mut self, value:S)-> &mut TwoCellAnchor {
         self.edit_as = Some(value.into());
         self
@@ -111,24 +129,56 @@ impl TwoCellAnchor {
         self
     }
 
-    pub(crate) fn adjustment_insert_row(&mut self, num_rows:&usize) {
-        &mut self.from_marker.adjustment_insert_row(num_rows);
-        &mut self.to_marker.adjustment_insert_row(num_rows);
+    pub fn get_group_shape(&self)-> &Option<GroupShape> {
+        &self.group_shape
     }
 
-    pub(crate) fn adjustment_insert_colmun(&mut self, num_cols:&usize) {
-        &mut self.from_marker.adjustment_insert_colmun(num_cols);
-        &mut self.to_marker.adjustment_insert_colmun(num_cols);
+    pub fn get_group_shape_mut(&mut self)-> &mut Option<GroupShape> {
+        &mut self.group_shape
     }
 
-    pub(crate) fn adjustment_remove_row(&mut self, num_rows:&usize) {
-        &mut self.from_marker.adjustment_remove_row(num_rows);
-        &mut self.to_marker.adjustment_remove_row(num_rows);
+    pub fn set_group_shape(&mut self, value:GroupShape)-> &mut TwoCellAnchor {
+        self.group_shape = Some(value);
+        self
     }
 
-    pub(crate) fn adjustment_remove_colmun(&mut self, num_cols:&usize) {
-        &mut self.from_marker.adjustment_remove_colmun(num_cols);
-        &mut self.to_marker.adjustment_remove_colmun(num_cols);
+    // Objects of the anchor, borrowed together.
+    pub(crate) fn get_objects_mut(&mut self)-> (&mut Option<GraphicFrame>, &mut Option<Shape>, &mut Option<ConnectionShape>, &mut Option<Picture>, &mut Option<GroupShape>) {
+        (&mut self.graphic_frame, &mut self.shape, &mut self.connection_shape, &mut self.picture, &mut self.group_shape)
+    }
+
+    pub(crate) fn adjustment_insert_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        match self.get_edit_as_str() {
+            TwoCellAnchor::EDIT_AS_ABSOLUTE => {},
+            TwoCellAnchor::EDIT_AS_ONE_CELL => {
+                let (col, row) = (self.from_marker.get_col().clone(), self.from_marker.get_row().clone());
+                self.from_marker.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+                let to_col = self.to_marker.get_col() + self.from_marker.get_col() - col;
+                let to_row = self.to_marker.get_row() + self.from_marker.get_row() - row;
+                self.to_marker.set_col(to_col).set_row(to_row);
+            },
+            _ => {
+                self.from_marker.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+                self.to_marker.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            },
+        }
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        match self.get_edit_as_str() {
+            TwoCellAnchor::EDIT_AS_ABSOLUTE => {},
+            TwoCellAnchor::EDIT_AS_ONE_CELL => {
+                let (col, row) = (self.from_marker.get_col().clone(), self.from_marker.get_row().clone());
+                self.from_marker.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+                let to_col = self.to_marker.get_col() - (col - self.from_marker.get_col());
+                let to_row = self.to_marker.get_row() - (row - self.from_marker.get_row());
+                self.to_marker.set_col(to_col).set_row(to_row);
+            },
+            _ => {
+                self.from_marker.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+                self.to_marker.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            },
+        }
     }
 
     pub(crate) fn is_support(&self) -> bool {
@@ -184,6 +234,11 @@ impl TwoCellAnchor {
                             obj.set_attributes(reader, e, dir, target);
                             self.set_picture(obj);
                         }
+                        b"xdr:grpSp" => {
+                            let mut obj = GroupShape::default();
+                            obj.set_attributes(reader, e, dir, target);
+                            self.set_group_shape(obj);
+                        }
                         _ => (),
                     }
                 },
@@ -241,10 +296,13 @@ impl TwoCellAnchor {
 
         // xdr:pic
         match &self.picture {
-            Some(v) => {
-                v.write_to(writer, r_id);
-                *r_id += 1i32;
-            },
+            Some(v) => v.write_to(writer, r_id),
+            None => {},
+        }
+
+        // xdr:grpSp
+        match &self.group_shape {
+            Some(v) => v.write_to(writer, r_id),
             None => {},
         }
 
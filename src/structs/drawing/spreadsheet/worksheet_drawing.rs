@@ -1,9 +1,20 @@
 // xdr:wsDr
 use super::two_cell_anchor::TwoCellAnchor;
+use super::one_cell_anchor::OneCellAnchor;
+use super::absolute_anchor::AbsoluteAnchor;
+use super::anchor::Anchor;
 use super::picture::Picture;
 use super::shape::Shape;
 use super::connection_shape::ConnectionShape;
+use super::group_shape::GroupShape;
 use super::GraphicFrame;
+use super::FromMarker;
+use super::ToMarker;
+use super::Extent;
+use super::super::Transform2D;
+use super::super::charts::ChartSpace;
+use super::super::super::ChartType;
+use helper::coordinate::*;
 use writer::driver::*;
 use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
@@ -13,36 +24,276 @@ use tempdir::TempDir;
 
 #[derive(Default, Debug)]
 pub struct WorksheetDrawing {
-    two_cell_anchor_collection: Vec<TwoCellAnchor>,
+    anchor_collection: Vec<Anchor>,
 }
 impl WorksheetDrawing {
-    pub fn get_two_cell_anchor_collection(&self) -> &Vec<TwoCellAnchor> {
-        &self.two_cell_anchor_collection
+    /// Anchors of every kind, in the order they are written.
+    pub fn get_anchor_collection(&self) -> &Vec<Anchor> {
+        &self.anchor_collection
     }
 
-    pub fn get_two_cell_anchor_collection_mut(&mut self) -> &mut Vec<TwoCellAnchor> {
-        &mut self.two_cell_anchor_collection
+    pub fn get_anchor_collection_mut(&mut self) -> &mut Vec<Anchor> {
+        &mut self.anchor_collection
+    }
+
+    pub fn add_anchor_collection(&mut self, value:Anchor) {
+        self.anchor_collection.push(value);
+    }
+
+    pub fn get_two_cell_anchor_collection(&self) -> Vec<&TwoCellAnchor> {
+        self.anchor_collection.iter().filter_map(|v| match v {
+            Anchor::TwoCell(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_two_cell_anchor_collection_mut(&mut self) -> Vec<&mut TwoCellAnchor> {
+        self.anchor_collection.iter_mut().filter_map(|v| match v {
+            Anchor::TwoCell(v) => Some(v),
+            _ => None,
+        }).collect()
     }
 
     pub fn add_two_cell_anchor_collection(&mut self, value:TwoCellAnchor) {
-        self.two_cell_anchor_collection.push(value);
+        self.anchor_collection.push(Anchor::TwoCell(value));
+    }
+
+    pub fn get_one_cell_anchor_collection(&self) -> Vec<&OneCellAnchor> {
+        self.anchor_collection.iter().filter_map(|v| match v {
+            Anchor::OneCell(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_one_cell_anchor_collection_mut(&mut self) -> Vec<&mut OneCellAnchor> {
+        self.anchor_collection.iter_mut().filter_map(|v| match v {
+            Anchor::OneCell(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_one_cell_anchor_collection(&mut self, value:OneCellAnchor) {
+        self.anchor_collection.push(Anchor::OneCell(value));
+    }
+
+    pub fn get_absolute_anchor_collection(&self) -> Vec<&AbsoluteAnchor> {
+        self.anchor_collection.iter().filter_map(|v| match v {
+            Anchor::Absolute(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_absolute_anchor_collection_mut(&mut self) -> Vec<&mut AbsoluteAnchor> {
+        self.anchor_collection.iter_mut().filter_map(|v| match v {
+            Anchor::Absolute(v) => Some(v),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn add_absolute_anchor_collection(&mut self, value:AbsoluteAnchor) {
+        self.anchor_collection.push(Anchor::Absolute(value));
+    }
+
+    // Add a two cell anchor and borrow it back.
+    fn push_two_cell_anchor(&mut self, value:TwoCellAnchor)-> &mut TwoCellAnchor {
+        self.anchor_collection.push(Anchor::TwoCell(value));
+        match self.anchor_collection.last_mut() {
+            Some(Anchor::TwoCell(v)) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    // Id after the largest id of the objects in the drawing. Ids start from 2.
+    fn get_next_id(&self)-> usize {
+        let mut ids: Vec<&str> = Vec::new();
+        ids.extend(self.get_graphic_frame_collection().iter().map(|v| v.get_non_visual_graphic_frame_properties().get_non_visual_drawing_properties().get_id()));
+        ids.extend(self.get_shape_collection().iter().map(|v| v.get_non_visual_shape_properties().get_non_visual_drawing_properties().get_id()));
+        ids.extend(self.get_connection_shape_collection().iter().map(|v| v.get_non_visual_connection_shape_properties().get_non_visual_drawing_properties().get_id()));
+        ids.extend(self.get_picture_collection().iter().map(|v| v.get_non_visual_picture_properties().get_non_visual_drawing_properties().get_id()));
+        ids.extend(self.get_group_shape_collection().iter().map(|v| v.get_non_visual_group_shape_properties().get_non_visual_drawing_properties().get_id()));
+        ids.iter().filter_map(|v| v.parse::<usize>().ok()).max().unwrap_or(1) + 1
+    }
+
+    // Group with the id, including the groups in other groups.
+    fn get_group_shape_by_id_mut(&mut self, id:&str)-> Option<&mut GroupShape> {
+        for anchor in &mut self.anchor_collection {
+            let (_, _, _, _, group_shape) = anchor.get_objects_mut();
+            let group_shape = match group_shape {
+                Some(v) => v,
+                None => continue,
+            };
+            match group_shape.find_group_shape_mut(id) {
+                Some(v) => return Some(v),
+                None => {}
+            }
+        }
+        None
+    }
+
+    /// Add a chart over a range of cells.
+    /// # Arguments
+    /// * `chart_type` - type of the chart.
+    /// * `range` - cells the chart covers. ex) "E2:L20"
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - Err if the range is invalid.
+    pub fn add_chart(&mut self, chart_type:ChartType, range:&str)-> Result<&mut ChartSpace, &'static str> {
+        let mut two_cell_anchor = new_two_cell_anchor(range)?;
+
+        let chart_no = self.get_graphic_frame_collection().len() + 1;
+        let mut graphic_frame = GraphicFrame::default();
+        let non_visual_drawing_properties = graphic_frame.get_non_visual_graphic_frame_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(self.get_next_id().to_string());
+        non_visual_drawing_properties.set_name(format!("Chart {}", chart_no));
+        graphic_frame.get_graphic_mut().get_graphic_data_mut().set_chart_space(ChartSpace::new_chart(&chart_type));
+        two_cell_anchor.set_graphic_frame(graphic_frame);
+
+        Ok(self.push_two_cell_anchor(two_cell_anchor)
+            .get_graphic_frame_mut().as_mut().unwrap()
+            .get_graphic_mut().get_graphic_data_mut().get_chart_space_mut())
+    }
+
+    /// Add a picture with its anchor.
+    pub(crate) fn add_picture(&mut self, mut one_cell_anchor:OneCellAnchor, mut picture:Picture)-> &mut Picture {
+        let picture_no = self.get_picture_collection().len() + 1;
+        let non_visual_drawing_properties = picture.get_non_visual_picture_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(self.get_next_id().to_string());
+        non_visual_drawing_properties.set_name(format!("Picture {}", picture_no));
+        one_cell_anchor.set_picture(picture);
+
+        self.anchor_collection.push(Anchor::OneCell(one_cell_anchor));
+        match self.anchor_collection.last_mut() {
+            Some(Anchor::OneCell(v)) => v.get_picture_mut().as_mut().unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Add a shape over a range of cells.
+    /// # Arguments
+    /// * `geometry` - preset geometry of the shape. ex) PresetGeometry::GEOMETRY_RECT
+    /// * `range` - cells the shape covers. ex) "B2:E6"
+    /// # Return value
+    /// * `Result<&mut Shape, &'static str>` - Err if the range is invalid.
+    pub fn add_shape(&mut self, geometry:&str, range:&str)-> Result<&mut Shape, &'static str> {
+        let mut two_cell_anchor = new_two_cell_anchor(range)?;
+
+        let shape_no = self.get_shape_collection().len() + 1;
+        let mut shape = Shape::new_shape(geometry);
+        let non_visual_drawing_properties = shape.get_non_visual_shape_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(self.get_next_id().to_string());
+        non_visual_drawing_properties.set_name(format!("Shape {}", shape_no));
+        two_cell_anchor.set_shape(shape);
+
+        Ok(self.push_two_cell_anchor(two_cell_anchor)
+            .get_shape_mut().as_mut().unwrap())
+    }
+
+    /// Add a connector over a range of cells.
+    /// # Arguments
+    /// * `geometry` - preset geometry of the connector. ex) PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1
+    /// * `range` - cells the connector covers. ex) "E4:G4"
+    /// # Return value
+    /// * `Result<&mut ConnectionShape, &'static str>` - Err if the range is invalid.
+    pub fn add_connection_shape(&mut self, geometry:&str, range:&str)-> Result<&mut ConnectionShape, &'static str> {
+        let mut two_cell_anchor = new_two_cell_anchor(range)?;
+
+        let connector_no = self.get_connection_shape_collection().len() + 1;
+        let mut connection_shape = ConnectionShape::new_connection_shape(geometry);
+        let non_visual_drawing_properties = connection_shape.get_non_visual_connection_shape_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(self.get_next_id().to_string());
+        non_visual_drawing_properties.set_name(format!("Connector {}", connector_no));
+        two_cell_anchor.set_connection_shape(connection_shape);
+
+        Ok(self.push_two_cell_anchor(two_cell_anchor)
+            .get_connection_shape_mut().as_mut().unwrap())
+    }
+
+    /// Add a group over a range of cells.
+    /// Objects in the group are placed in the coordinate space of `transform2d`.
+    /// # Arguments
+    /// * `range` - cells the group covers. ex) "B2:F10"
+    /// * `transform2d` - position and size of the group, and of the space of its objects.
+    /// # Return value
+    /// * `Result<&mut GroupShape, &'static str>` - Err if the range is invalid.
+    pub fn add_group_shape(&mut self, range:&str, transform2d:Transform2D)-> Result<&mut GroupShape, &'static str> {
+        let mut two_cell_anchor = new_two_cell_anchor(range)?;
+
+        let group_no = self.get_group_shape_collection().len() + 1;
+        let mut group_shape = GroupShape::default();
+        let non_visual_drawing_properties = group_shape.get_non_visual_group_shape_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(self.get_next_id().to_string());
+        non_visual_drawing_properties.set_name(format!("Group {}", group_no));
+        group_shape.get_group_shape_properties_mut().set_transform2d(transform2d);
+        two_cell_anchor.set_group_shape(group_shape);
+
+        Ok(self.push_two_cell_anchor(two_cell_anchor)
+            .get_group_shape_mut().as_mut().unwrap())
+    }
+
+    /// Add a shape to a group.
+    /// # Arguments
+    /// * `group_id` - id of the group.
+    /// * `geometry` - preset geometry of the shape. ex) PresetGeometry::GEOMETRY_RECT
+    /// * `transform2d` - position and size of the shape in the coordinate space of the group.
+    /// # Return value
+    /// * `Result<&mut Shape, &'static str>` - Err if the group is not found.
+    pub fn add_shape_to_group(&mut self, group_id:&str, geometry:&str, transform2d:Transform2D)-> Result<&mut Shape, &'static str> {
+        let id = self.get_next_id();
+        let shape_no = self.get_shape_collection().len() + 1;
+        let group_shape = match self.get_group_shape_by_id_mut(group_id) {
+            Some(v) => v,
+            None => return Err("Group not found."),
+        };
+        let mut shape = Shape::new_shape(geometry);
+        let non_visual_drawing_properties = shape.get_non_visual_shape_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(id.to_string());
+        non_visual_drawing_properties.set_name(format!("Shape {}", shape_no));
+        shape.get_shape_properties_mut().set_transform2d(transform2d);
+        group_shape.add_shape_collection(shape);
+        Ok(group_shape.get_shape_collection_mut().into_iter().last().unwrap())
+    }
+
+    /// Add a connector to a group.
+    /// # Arguments
+    /// * `group_id` - id of the group.
+    /// * `geometry` - preset geometry of the connector. ex) PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1
+    /// * `transform2d` - position and size of the connector in the coordinate space of the group.
+    /// # Return value
+    /// * `Result<&mut ConnectionShape, &'static str>` - Err if the group is not found.
+    pub fn add_connection_shape_to_group(&mut self, group_id:&str, geometry:&str, transform2d:Transform2D)-> Result<&mut ConnectionShape, &'static str> {
+        let id = self.get_next_id();
+        let connector_no = self.get_connection_shape_collection().len() + 1;
+        let group_shape = match self.get_group_shape_by_id_mut(group_id) {
+            Some(v) => v,
+            None => return Err("Group not found."),
+        };
+        let mut connection_shape = ConnectionShape::new_connection_shape(geometry);
+        let non_visual_drawing_properties = connection_shape.get_non_visual_connection_shape_properties_mut().get_non_visual_drawing_properties_mut();
+        non_visual_drawing_properties.set_id(id.to_string());
+        non_visual_drawing_properties.set_name(format!("Connector {}", connector_no));
+        connection_shape.get_shape_properties_mut().set_transform2d(transform2d);
+        group_shape.add_connection_shape_collection(connection_shape);
+        Ok(group_shape.get_connection_shape_collection_mut().into_iter().last().unwrap())
     }
 
     pub fn has_drawing_object(&self)-> bool
     {
-        &self.two_cell_anchor_collection.len() > &0usize
+        self.anchor_collection.len() > 0
     }
 
     pub fn get_graphic_frame_collection(&self)-> Vec<&GraphicFrame>
     {
         let mut result:Vec<&GraphicFrame> = Vec::new(); 
-        for two_cell_anchor in &self.two_cell_anchor_collection {
-            match two_cell_anchor.get_graphic_frame() {
+        for anchor in &self.anchor_collection {
+            match anchor.get_graphic_frame() {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match anchor.get_group_shape() {
+                Some(v) => v.collect_graphic_frames(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -50,13 +301,18 @@ impl WorksheetDrawing {
     pub fn get_graphic_frame_collection_mut(&mut self)-> Vec<&mut GraphicFrame>
     {
         let mut result:Vec<&mut GraphicFrame> = Vec::new(); 
-        for two_cell_anchor in &mut self.two_cell_anchor_collection {
-            match two_cell_anchor.get_graphic_frame_mut() {
+        for anchor in &mut self.anchor_collection {
+            let (graphic_frame, _, _, _, group_shape) = anchor.get_objects_mut();
+            match graphic_frame {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match group_shape {
+                Some(v) => v.collect_graphic_frames_mut(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -64,13 +320,17 @@ impl WorksheetDrawing {
     pub fn get_shape_collection(&self)-> Vec<&Shape>
     {
         let mut result:Vec<&Shape> = Vec::new(); 
-        for two_cell_anchor in &self.two_cell_anchor_collection {
-            match two_cell_anchor.get_shape() {
+        for anchor in &self.anchor_collection {
+            match anchor.get_shape() {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match anchor.get_group_shape() {
+                Some(v) => v.collect_shapes(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -78,13 +338,18 @@ impl WorksheetDrawing {
     pub fn get_shape_collection_mut(&mut self)-> Vec<&mut Shape>
     {
         let mut result:Vec<&mut Shape> = Vec::new(); 
-        for two_cell_anchor in &mut self.two_cell_anchor_collection {
-            match two_cell_anchor.get_shape_mut() {
+        for anchor in &mut self.anchor_collection {
+            let (_, shape, _, _, group_shape) = anchor.get_objects_mut();
+            match shape {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match group_shape {
+                Some(v) => v.collect_shapes_mut(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -92,13 +357,17 @@ impl WorksheetDrawing {
     pub fn get_connection_shape_collection(&self)-> Vec<&ConnectionShape>
     {
         let mut result:Vec<&ConnectionShape> = Vec::new(); 
-        for two_cell_anchor in &self.two_cell_anchor_collection {
-            match two_cell_anchor.get_connection_shape() {
+        for anchor in &self.anchor_collection {
+            match anchor.get_connection_shape() {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match anchor.get_group_shape() {
+                Some(v) => v.collect_connection_shapes(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -106,13 +375,18 @@ impl WorksheetDrawing {
     pub fn get_connection_shape_collection_mut(&mut self)-> Vec<&mut ConnectionShape>
     {
         let mut result:Vec<&mut ConnectionShape> = Vec::new(); 
-        for two_cell_anchor in &mut self.two_cell_anchor_collection {
-            match two_cell_anchor.get_connection_shape_mut() {
+        for anchor in &mut self.anchor_collection {
+            let (_, _, connection_shape, _, group_shape) = anchor.get_objects_mut();
+            match connection_shape {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match group_shape {
+                Some(v) => v.collect_connection_shapes_mut(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -120,13 +394,17 @@ impl WorksheetDrawing {
     pub fn get_picture_collection(&self)-> Vec<&Picture>
     {
         let mut result:Vec<&Picture> = Vec::new(); 
-        for two_cell_anchor in &self.two_cell_anchor_collection {
-            match two_cell_anchor.get_picture() {
+        for anchor in &self.anchor_collection {
+            match anchor.get_picture() {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match anchor.get_group_shape() {
+                Some(v) => v.collect_pictures(&mut result),
+                None => {}
+            }
         }
         result
     }
@@ -134,17 +412,47 @@ impl WorksheetDrawing {
     pub fn get_picture_collection_mut(&mut self)-> Vec<&mut Picture>
     {
         let mut result:Vec<&mut Picture> = Vec::new(); 
-        for two_cell_anchor in &mut self.two_cell_anchor_collection {
-            match two_cell_anchor.get_picture_mut() {
+        for anchor in &mut self.anchor_collection {
+            let (_, _, _, picture, group_shape) = anchor.get_objects_mut();
+            match picture {
                 Some(v) => {
                     result.push(v);
                 },
                 None => {}
             }
+            match group_shape {
+                Some(v) => v.collect_pictures_mut(&mut result),
+                None => {}
+            }
+        }
+        result
+    }
+
+    /// Groups of the drawing, including the groups in other groups.
+    pub fn get_group_shape_collection(&self)-> Vec<&GroupShape>
+    {
+        let mut result:Vec<&GroupShape> = Vec::new(); 
+        for anchor in &self.anchor_collection {
+            match anchor.get_group_shape() {
+                Some(v) => v.collect_group_shapes(&mut result),
+                None => {}
+            }
         }
         result
     }
 
+    pub(crate) fn adjustment_insert_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        for anchor in &mut self.anchor_collection {
+            anchor.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+        }
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(&mut self, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        for anchor in &mut self.anchor_collection {
+            anchor.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+        }
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -168,6 +476,26 @@ impl WorksheetDrawing {
                                 &mut self.add_two_cell_anchor_collection(two_cell_anchor);
                             }
                         },
+                        b"xdr:oneCellAnchor" => {
+                            if is_alternate_content {
+                                continue;
+                            }
+                            let mut one_cell_anchor = OneCellAnchor::default();
+                            one_cell_anchor.set_attributes(reader, e, dir, target);
+                            if one_cell_anchor.is_support() {
+                                &mut self.add_one_cell_anchor_collection(one_cell_anchor);
+                            }
+                        },
+                        b"xdr:absoluteAnchor" => {
+                            if is_alternate_content {
+                                continue;
+                            }
+                            let mut absolute_anchor = AbsoluteAnchor::default();
+                            absolute_anchor.set_attributes(reader, e, dir, target);
+                            if absolute_anchor.is_support() {
+                                &mut self.add_absolute_anchor_collection(absolute_anchor);
+                            }
+                        },
                         b"mc:AlternateContent" => {
                             is_alternate_content = true;
                         },
@@ -191,19 +519,39 @@ impl WorksheetDrawing {
         }
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    // `get_to_marker` gives where a picture anchored to one cell ends, for the pictures saved in two cell anchors.
+    pub(crate) fn write_to<F: Fn(&FromMarker, &Extent)-> ToMarker>(&self, writer: &mut Writer<Cursor<Vec<u8>>>, get_to_marker:&F) {
         // xdr:wsDr
         write_start_tag(writer, "xdr:wsDr", vec![
             ("xmlns:xdr", "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing"),
             ("xmlns:a", "http://schemas.openxmlformats.org/drawingml/2006/main"),
         ], false);
 
-        // xdr:twoCellAnchor
+        // xdr:twoCellAnchor, xdr:oneCellAnchor, xdr:absoluteAnchor
         let mut r_id = 1;
-        for two_cell_anchor in &self.two_cell_anchor_collection {
-            two_cell_anchor.write_to(writer, &mut r_id);
+        for anchor in &self.anchor_collection {
+            anchor.write_to(writer, &mut r_id, get_to_marker);
         }
-        
+
         write_end_tag(writer, "xdr:wsDr");
     }
 }
+
+// Anchor covering a range of cells. ex) "E2:L20"
+fn new_two_cell_anchor(range:&str)-> Result<TwoCellAnchor, &'static str> {
+    if !is_range(range) {
+        return Err("Invalid range.");
+    }
+    let coordinates: Vec<&str> = range.split(':').collect();
+    let start = index_from_coordinate(coordinates[0]);
+    let end = index_from_coordinate(coordinates[coordinates.len() - 1]);
+
+    let mut two_cell_anchor = TwoCellAnchor::default();
+    two_cell_anchor.get_from_marker_mut()
+        .set_col(start[0] - 1)
+        .set_row(start[1] - 1);
+    two_cell_anchor.get_to_marker_mut()
+        .set_col(end[0])
+        .set_row(end[1]);
+    Ok(two_cell_anchor)
+}
@@ -6,17 +6,51 @@ use quick_xml::events::{BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct TailEnd {
     r#type: String,
+    width: Option<String>,
+    length: Option<String>,
 }
 impl TailEnd {
+    // Types
+    pub const TYPE_NONE: &'static str = "none";
+    pub const TYPE_TRIANGLE: &'static str = "triangle";
+    pub const TYPE_STEALTH: &'static str = "stealth";
+    pub const TYPE_DIAMOND: &'static str = "diamond";
+    pub const TYPE_OVAL: &'static str = "oval";
+    pub const TYPE_ARROW: &'static str = "arrow";
+
+    // Sizes
+    pub const SIZE_SMALL: &'static str = "sm";
+    pub const SIZE_MEDIUM: &'static str = "med";
+    pub const SIZE_LARGE: &'static str = "lg";
+
     pub fn get_type(&self) -> &str {
         &self.r#type
     }
 
-    pub fn set_type<S: Into<String>>(&mut self, value:S) {
+    pub fn set_type<S: Into<String>>(&mut self, value:S) -> &mut TailEnd {
         self.r#type = value.into();
+        self
+    }
+
+    pub fn get_width(&self) -> &Option<String> {
+        &self.width
+    }
+
+    pub fn set_width<S: Into<String>>(&mut self, value:S) -> &mut TailEnd {
+        self.width = Some(value.into());
+        self
+    }
+
+    pub fn get_length(&self) -> &Option<String> {
+        &self.length
+    }
+
+    pub fn set_length<S: Into<String>>(&mut self, value:S) -> &mut TailEnd {
+        self.length = Some(value.into());
+        self
     }
 
     pub(crate) fn set_attributes(
@@ -28,12 +62,28 @@ impl TailEnd {
             Some(v) => {&mut self.set_type(v);},
             None => {}
         }
+        match get_attribute(e, b"w") {
+            Some(v) => {&mut self.set_width(v);},
+            None => {}
+        }
+        match get_attribute(e, b"len") {
+            Some(v) => {&mut self.set_length(v);},
+            None => {}
+        }
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // a:tailEnd
-        write_start_tag(writer, "a:tailEnd", vec![
-            ("type", &self.r#type),
-        ], true);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("type", &self.r#type));
+        match &self.width {
+            Some(v) => attributes.push(("w", v)),
+            None => {}
+        }
+        match &self.length {
+            Some(v) => attributes.push(("len", v)),
+            None => {}
+        }
+        write_start_tag(writer, "a:tailEnd", attributes, true);
     }
 }
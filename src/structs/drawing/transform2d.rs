@@ -6,7 +6,7 @@ use quick_xml::events::{Event, BytesStart};
 use quick_xml::Writer;
 use std::io::Cursor;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Transform2D {
     x: usize,
     y: usize,
@@ -15,6 +15,10 @@ pub struct Transform2D {
     rot: Option<String>,
     flip_v: Option<String>,
     flip_h: Option<String>,
+    child_x: Option<usize>,
+    child_y: Option<usize>,
+    child_width: Option<usize>,
+    child_height: Option<usize>,
 }
 impl Transform2D {
     pub fn get_x(&self) -> &usize {
@@ -73,6 +77,38 @@ impl Transform2D {
         self.flip_h = Some(value.into());
     }
     
+    pub fn get_child_x(&self) -> &Option<usize> {
+        &self.child_x
+    }
+
+    pub fn set_child_x(&mut self, value:usize) {
+        self.child_x = Some(value);
+    }
+
+    pub fn get_child_y(&self) -> &Option<usize> {
+        &self.child_y
+    }
+
+    pub fn set_child_y(&mut self, value:usize) {
+        self.child_y = Some(value);
+    }
+
+    pub fn get_child_width(&self) -> &Option<usize> {
+        &self.child_width
+    }
+
+    pub fn set_child_width(&mut self, value:usize) {
+        self.child_width = Some(value);
+    }
+
+    pub fn get_child_height(&self) -> &Option<usize> {
+        &self.child_height
+    }
+
+    pub fn set_child_height(&mut self, value:usize) {
+        self.child_height = Some(value);
+    }
+
     pub(crate) fn set_attributes(
         &mut self,
         reader:&mut Reader<std::io::BufReader<std::fs::File>>,
@@ -107,6 +143,14 @@ impl Transform2D {
                             &mut self.set_width(get_attribute(e, b"cx").unwrap().parse::<usize>().unwrap());
                             &mut self.set_height(get_attribute(e, b"cy").unwrap().parse::<usize>().unwrap());
                         },
+                        b"a:chOff" => {
+                            &mut self.set_child_x(get_attribute(e, b"x").unwrap().parse::<usize>().unwrap());
+                            &mut self.set_child_y(get_attribute(e, b"y").unwrap().parse::<usize>().unwrap());
+                        },
+                        b"a:chExt" => {
+                            &mut self.set_child_width(get_attribute(e, b"cx").unwrap().parse::<usize>().unwrap());
+                            &mut self.set_child_height(get_attribute(e, b"cy").unwrap().parse::<usize>().unwrap());
+                        },
                         _ => (),
                     }
                 },
@@ -155,6 +199,28 @@ impl Transform2D {
             ("cy", &self.height.to_string()),
         ], true);
 
+        // a:chOff
+        match (&self.child_x, &self.child_y) {
+            (Some(x), Some(y)) => {
+                write_start_tag(writer, "a:chOff", vec![
+                    ("x", &x.to_string()),
+                    ("y", &y.to_string()),
+                ], true);
+            },
+            _ => {}
+        }
+
+        // a:chExt
+        match (&self.child_width, &self.child_height) {
+            (Some(width), Some(height)) => {
+                write_start_tag(writer, "a:chExt", vec![
+                    ("cx", &width.to_string()),
+                    ("cy", &height.to_string()),
+                ], true);
+            },
+            _ => {}
+        }
+
         write_end_tag(writer, "a:xfrm");
     }
 }
\ No newline at end of file
@@ -1,10 +1,107 @@
-#[derive(Default, Debug)]
+use super::Address;
+use helper::formula::*;
+use onig::Regex;
+
+lazy_static! {
+    static ref LOCATION_ADDRESS_REGEX: Regex = Regex::new(r#"^(('([^']|'')+'|[^'!]+)!)?\$?[A-Z]+\$?[0-9]+(:\$?[A-Z]+\$?[0-9]+)?$"#).unwrap();
+}
+
+/// Where a hyperlink leads.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HyperlinkTarget {
+    /// A web address. ex) "https://example.com"
+    Url(String),
+    /// A place in the workbook. ex) "'Sheet 2'!A1"
+    Location(String),
+    /// A mail address, with the subject if any.
+    Mail(String, Option<String>),
+    /// A file. ex) "file:///C:/docs/report.xlsx"
+    File(String),
+}
+
+#[derive(Default, Debug, Clone)]
 pub struct Hyperlink {
     url: String,
     tooltip: String,
     location: bool,
+    display: String,
 }
 impl Hyperlink {
+    /// Make a link to a web address.
+    /// # Arguments
+    /// * `url` - ex) "https://example.com"
+    pub fn new_url<S: Into<String>>(url:S)-> Hyperlink {
+        let mut hyperlink = Hyperlink::default();
+        hyperlink.set_url(url);
+        hyperlink
+    }
+
+    /// Make a link to a place in the workbook.
+    /// # Arguments
+    /// * `sheet_name` - name of the sheet, quoted as needed. ex) "Sheet 2"
+    /// * `range` - ex) "A1" or "A1:C3"
+    pub fn new_location(sheet_name:&str, range:&str)-> Hyperlink {
+        let mut hyperlink = Hyperlink::default();
+        hyperlink.set_url(format!("{}!{}", quote_sheet_name(sheet_name), range.to_uppercase()));
+        hyperlink.set_location(true);
+        hyperlink
+    }
+
+    /// Make a link that writes a mail.
+    /// # Arguments
+    /// * `address` - ex) "info@example.com"
+    /// * `subject` - subject of the mail. ex) Some("Question")
+    pub fn new_mail(address:&str, subject:Option<&str>)-> Hyperlink {
+        let url = match subject {
+            Some(v) => format!("mailto:{}?subject={}", address, encode_url_component(v)),
+            None => format!("mailto:{}", address),
+        };
+        Hyperlink::new_url(url)
+    }
+
+    /// Make a link to a file.
+    /// # Arguments
+    /// * `path` - absolute or relative path. ex) "C:/docs/report.xlsx"
+    pub fn new_file(path:&str)-> Hyperlink {
+        let path = path.replace("\\", "/");
+        let url = if path.starts_with("file:") {
+            path
+        } else if path.starts_with("//") {
+            format!("file:{}", path)
+        } else if path.starts_with("/") || path.chars().nth(1) == Some(':') {
+            format!("file:///{}", path.trim_start_matches('/'))
+        } else {
+            // relative paths are kept as they are
+            path
+        };
+        Hyperlink::new_url(url)
+    }
+
+    /// Get the target of the link.
+    pub fn get_target(&self)-> HyperlinkTarget {
+        if self.location {
+            return HyperlinkTarget::Location(self.url.clone());
+        }
+        if self.url.starts_with("mailto:") {
+            let value = self.url.trim_start_matches("mailto:");
+            let mut split = value.splitn(2, '?');
+            let address = split.next().unwrap_or("").to_string();
+            let subject = split.next().and_then(|query| {
+                query.split('&').find(|x| x.starts_with("subject=")).map(|x| decode_url_component(x.trim_start_matches("subject=")))
+            });
+            return HyperlinkTarget::Mail(address, subject);
+        }
+        // a scheme of one letter is a drive letter
+        let has_scheme = match self.url.find(':') {
+            Some(v) => v > 1 && self.url[..v].chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '.' || c == '-'),
+            None => false,
+        };
+        if self.url.starts_with("file:") || !has_scheme {
+            return HyperlinkTarget::File(self.url.clone());
+        }
+        HyperlinkTarget::Url(self.url.clone())
+    }
+
     pub fn get_url(&self) -> &String {
         &self.url
     }
@@ -23,6 +120,16 @@ impl Hyperlink {
         self
     }
 
+    /// Text shown for the link. The cell keeps its own value.
+    pub fn get_display(&self) -> &String {
+        &self.display
+    }
+
+    pub fn set_display<S: Into<String>>(&mut self, value:S)-> &mut Hyperlink {
+        self.display = value.into();
+        self
+    }
+
     pub fn get_location(&self) -> &bool {
         &self.location
     }
@@ -31,4 +138,75 @@ impl Hyperlink {
         self.location = value;
         self
     }
+
+    pub(crate) fn adjustment_insert_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        if let Some(mut address) = self.get_location_address(self_sheet_name, sheet_name) {
+            address.get_range_mut().adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            self.url = address.get_address();
+        }
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(&mut self, self_sheet_name:&str, sheet_name:&str, root_col_num:&usize, offset_col_num:&usize, root_row_num:&usize, offset_row_num:&usize) {
+        if let Some(mut address) = self.get_location_address(self_sheet_name, sheet_name) {
+            address.get_range_mut().adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            self.url = address.get_address();
+        }
+    }
+
+    pub(crate) fn adjustment_rename_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        if let Some(mut address) = self.get_location_address("", old_sheet_name) {
+            address.adjustment_rename_sheet(old_sheet_name, new_sheet_name);
+            self.url = address.get_address();
+        }
+    }
+
+    // The location as a cell reference, if it points into `sheet_name`.
+    // A location without a sheet name points into `self_sheet_name`.
+    fn get_location_address(&self, self_sheet_name:&str, sheet_name:&str)-> Option<Address> {
+        if !self.location {
+            return None;
+        }
+        if !LOCATION_ADDRESS_REGEX.is_match(&self.url) {
+            return None;
+        }
+        let mut address = Address::default();
+        address.set_address(self.url.as_str());
+        let target_sheet_name = match address.get_sheet_name() {
+            "" => self_sheet_name.to_string(),
+            v => unquote_sheet_name(v),
+        };
+        if target_sheet_name != sheet_name {
+            return None;
+        }
+        Some(address)
+    }
+}
+
+fn encode_url_component(value:&str)-> String {
+    let mut result = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => result.push(byte as char),
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+fn decode_url_component(value:&str)-> String {
+    let bytes = value.as_bytes();
+    let mut result: Vec<u8> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(v) = u8::from_str_radix(&String::from_utf8_lossy(&bytes[i + 1..i + 3]), 16) {
+                result.push(v);
+                i += 3;
+                continue;
+            }
+        }
+        result.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&result).to_string()
 }
@@ -124,6 +124,8 @@ impl NumberFormat {
     pub const FORMAT_DATE_TIME5: &'static str = "mm:ss";
     pub const FORMAT_DATE_TIME6: &'static str = "h:mm:ss";
     pub const FORMAT_DATE_TIME8: &'static str = "h:mm:ss;@";
+    pub const FORMAT_DATE_BUILT_IN: &'static str = "m/d/yyyy";
+    pub const FORMAT_DATE_TIME_BUILT_IN: &'static str = "m/d/yyyy h:mm";
     pub const FORMAT_DATE_YYYYMMDDSLASH: &'static str = "yyyy/mm/dd;@";
     
     pub const FORMAT_CURRENCY_USD_SIMPLE: &'static str = r###""$"#,##0.00_-"###;
@@ -0,0 +1,61 @@
+use helper::guid::*;
+
+/// An author of threaded comments (xl/persons/person.xml).
+#[derive(Default, Debug, Clone)]
+pub struct Person {
+    display_name: String,
+    id: String,
+    user_id: String,
+    provider_id: String,
+}
+impl Person {
+    pub const PROVIDER_NONE: &'static str = "None";
+    pub const PROVIDER_ACTIVE_DIRECTORY: &'static str = "AD";
+    pub const PROVIDER_WINDOWS_LIVE: &'static str = "Windows Live";
+    pub const PROVIDER_PEOPLE_PICKER: &'static str = "PeoplePicker";
+
+    pub fn get_display_name(&self)-> &str {
+        &self.display_name
+    }
+
+    pub fn set_display_name<S: Into<String>>(&mut self, value:S)-> &mut Person {
+        self.display_name = value.into();
+        self
+    }
+
+    pub fn get_id(&self)-> &str {
+        &self.id
+    }
+
+    pub fn set_id<S: Into<String>>(&mut self, value:S)-> &mut Person {
+        self.id = value.into();
+        self
+    }
+
+    pub fn get_user_id(&self)-> &str {
+        &self.user_id
+    }
+
+    pub fn set_user_id<S: Into<String>>(&mut self, value:S)-> &mut Person {
+        self.user_id = value.into();
+        self
+    }
+
+    pub fn get_provider_id(&self)-> &str {
+        &self.provider_id
+    }
+
+    pub fn set_provider_id<S: Into<String>>(&mut self, value:S)-> &mut Person {
+        self.provider_id = value.into();
+        self
+    }
+
+    pub(crate) fn new_person(display_name:&str) -> Person {
+        let mut person = Person::default();
+        person.set_display_name(display_name);
+        person.set_id(new_guid(display_name));
+        person.set_user_id(display_name);
+        person.set_provider_id(Person::PROVIDER_NONE);
+        person
+    }
+}
@@ -1,3 +1,6 @@
+use super::CustomProperty;
+use super::CustomPropertyValue;
+
 #[derive(Debug)]
 pub struct Properties {
     creator: String,
@@ -13,7 +16,15 @@ pub struct Properties {
     company: String,
     revision: String,
     version: String,
-    custom_properties: Vec<String>,
+    application: String,
+    app_version: String,
+    doc_security: i32,
+    hyperlink_base: String,
+    scale_crop: bool,
+    links_up_to_date: bool,
+    shared_doc: bool,
+    hyperlinks_changed: bool,
+    custom_properties: Vec<CustomProperty>,
 }
 impl Default for Properties {
     fn default() -> Self {
@@ -31,6 +42,14 @@ impl Default for Properties {
             company: String::from(""),
             revision: String::from(""),
             version: String::from(""),
+            application: String::from("Microsoft Excel"),
+            app_version: String::from("14.0300"),
+            doc_security: 0,
+            hyperlink_base: String::from(""),
+            scale_crop: false,
+            links_up_to_date: false,
+            shared_doc: false,
+            hyperlinks_changed: false,
             custom_properties: Vec::new()
         }
     }
@@ -153,12 +172,129 @@ impl Properties {
         self
     }
 
-    pub fn get_custom_properties(&self)-> &Vec<String> {
+    pub fn get_application(&self)-> &str {
+        &self.application
+    }
+
+    pub fn set_application<S: Into<String>>(&mut self, value:S)-> &mut Properties {
+        self.application = value.into();
+        self
+    }
+
+    /// Get the application version. ex) "16.0300"
+    pub fn get_app_version(&self)-> &str {
+        &self.app_version
+    }
+
+    pub fn set_app_version<S: Into<String>>(&mut self, value:S)-> &mut Properties {
+        self.app_version = value.into();
+        self
+    }
+
+    /// Get the document security level.
+    /// # Return value
+    /// * `&i32` - 0: none, 1: password protected, 2: read-only recommended, 4: read-only enforced, 8: locked for annotation.
+    pub fn get_doc_security(&self)-> &i32 {
+        &self.doc_security
+    }
+
+    pub fn set_doc_security(&mut self, value:i32)-> &mut Properties {
+        self.doc_security = value;
+        self
+    }
+
+    /// Get the base string used to evaluate relative hyperlinks.
+    pub fn get_hyperlink_base(&self)-> &str {
+        &self.hyperlink_base
+    }
+
+    pub fn set_hyperlink_base<S: Into<String>>(&mut self, value:S)-> &mut Properties {
+        self.hyperlink_base = value.into();
+        self
+    }
+
+    pub fn get_scale_crop(&self)-> &bool {
+        &self.scale_crop
+    }
+
+    pub fn set_scale_crop(&mut self, value:bool)-> &mut Properties {
+        self.scale_crop = value;
+        self
+    }
+
+    pub fn get_links_up_to_date(&self)-> &bool {
+        &self.links_up_to_date
+    }
+
+    pub fn set_links_up_to_date(&mut self, value:bool)-> &mut Properties {
+        self.links_up_to_date = value;
+        self
+    }
+
+    pub fn get_shared_doc(&self)-> &bool {
+        &self.shared_doc
+    }
+
+    pub fn set_shared_doc(&mut self, value:bool)-> &mut Properties {
+        self.shared_doc = value;
+        self
+    }
+
+    pub fn get_hyperlinks_changed(&self)-> &bool {
+        &self.hyperlinks_changed
+    }
+
+    pub fn set_hyperlinks_changed(&mut self, value:bool)-> &mut Properties {
+        self.hyperlinks_changed = value;
+        self
+    }
+
+    pub fn get_custom_properties(&self)-> &Vec<CustomProperty> {
         &self.custom_properties
     }
 
-    pub fn set_custom_properties(&mut self, value:Vec<String>)-> &mut Properties {
+    pub fn set_custom_properties(&mut self, value:Vec<CustomProperty>)-> &mut Properties {
         self.custom_properties = value;
         self
     }
+
+    /// Get the value of a custom property.
+    /// # Arguments
+    /// * `name` - name of the property. ex) "Project ID"
+    pub fn get_custom_property(&self, name:&str)-> Option<&CustomPropertyValue> {
+        self.custom_properties.iter().find(|x| x.get_name() == name).map(|x| x.get_value())
+    }
+
+    /// Set a custom property. A property of the same name is overwritten.
+    /// # Arguments
+    /// * `name` - name of the property. ex) "Project ID"
+    /// * `value` - text, number, bool or `CustomPropertyValue::Date`.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::CustomPropertyValue;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_properties_mut()
+    ///     .set_custom_property("Classification", "Internal")
+    ///     .set_custom_property("Project ID", 1234)
+    ///     .set_custom_property("Retention", CustomPropertyValue::Date("2030-03-31T00:00:00Z".to_string()));
+    /// ```
+    pub fn set_custom_property<S: Into<String>, V: Into<CustomPropertyValue>>(&mut self, name:S, value:V)-> &mut Properties {
+        let name = name.into();
+        match self.custom_properties.iter_mut().find(|x| x.get_name() == name) {
+            Some(v) => {v.set_value(value);},
+            None => self.custom_properties.push(CustomProperty::new(name, value)),
+        }
+        self
+    }
+
+    /// Remove a custom property.
+    /// # Arguments
+    /// * `name` - name of the property. ex) "Project ID"
+    /// # Return value
+    /// * `bool` - whether the property was removed.
+    pub fn remove_custom_property(&mut self, name:&str)-> bool {
+        let count = self.custom_properties.len();
+        self.custom_properties.retain(|x| x.get_name() != name);
+        count != self.custom_properties.len()
+    }
 }
\ No newline at end of file
@@ -12,6 +12,13 @@ impl Default for RichText  {
     }
 }
 impl RichText  {
+    pub fn get_text(&self)-> String {
+        let mut text = String::from("");
+        for ele in &self.rich_text_elements {
+            text += ele.get_text();
+        }
+        text
+    }
     pub fn get_rich_text_elements(&self)-> &Vec<TextElement> {
         &self.rich_text_elements
     }
@@ -1,36 +1,259 @@
+use helper::crypt::*;
+
+/// Workbook protection (workbookProtection).
 #[derive(Default, Debug)]
 pub struct Security {
     lock_revision: bool,
     lock_structure: bool,
     lock_windows: bool,
     revisions_password: String,
+    revisions_algorithm_name: String,
+    revisions_hash_value: String,
+    revisions_salt_value: String,
+    revisions_spin_count: u32,
     workbook_password: String,
+    workbook_algorithm_name: String,
+    workbook_hash_value: String,
+    workbook_salt_value: String,
+    workbook_spin_count: u32,
 }
 impl Security {
-     pub(crate) fn is_security_enabled(&self) -> bool {
-         if self.lock_revision {
-            return true;
-         } else if self.lock_structure {
-            return true;
-         } else if self.lock_windows {
-            return true;
-         } else {
-             return false;
-         }
+    pub const ALGORITHM_SHA_512: &'static str = "SHA-512";
+    pub const DEFAULT_SPIN_COUNT: u32 = 100000;
+
+    pub(crate) fn is_security_enabled(&self) -> bool {
+        self.lock_revision
+            || self.lock_structure
+            || self.lock_windows
+            || self.revisions_password != ""
+            || self.revisions_hash_value != ""
+            || self.workbook_password != ""
+            || self.workbook_hash_value != ""
     }
+
     pub fn get_lock_revision(&self) -> &bool {
         &self.lock_revision
     }
+
+    pub fn set_lock_revision(&mut self, value:bool) -> &mut Security {
+        self.lock_revision = value;
+        self
+    }
+
     pub fn get_lock_structure(&self) -> &bool {
         &self.lock_structure
     }
+
+    pub fn set_lock_structure(&mut self, value:bool) -> &mut Security {
+        self.lock_structure = value;
+        self
+    }
+
     pub fn get_lock_windows(&self) -> &bool {
         &self.lock_windows
     }
+
+    pub fn set_lock_windows(&mut self, value:bool) -> &mut Security {
+        self.lock_windows = value;
+        self
+    }
+
+    /// Get the legacy hash of the revisions password. ex) "CC1A"
     pub fn get_revisions_password(&self) -> &str {
         &self.revisions_password
     }
+
+    pub fn get_revisions_algorithm_name(&self) -> &str {
+        &self.revisions_algorithm_name
+    }
+
+    /// Get the revisions password hash in base64.
+    pub fn get_revisions_hash_value(&self) -> &str {
+        &self.revisions_hash_value
+    }
+
+    /// Get the revisions password salt in base64.
+    pub fn get_revisions_salt_value(&self) -> &str {
+        &self.revisions_salt_value
+    }
+
+    pub fn get_revisions_spin_count(&self) -> &u32 {
+        &self.revisions_spin_count
+    }
+
+    /// Get the legacy hash of the workbook password. ex) "CC1A"
     pub fn get_workbook_password(&self) -> &str {
         &self.workbook_password
     }
+
+    pub fn get_workbook_algorithm_name(&self) -> &str {
+        &self.workbook_algorithm_name
+    }
+
+    /// Get the workbook password hash in base64.
+    pub fn get_workbook_hash_value(&self) -> &str {
+        &self.workbook_hash_value
+    }
+
+    /// Get the workbook password salt in base64.
+    pub fn get_workbook_salt_value(&self) -> &str {
+        &self.workbook_salt_value
+    }
+
+    pub fn get_workbook_spin_count(&self) -> &u32 {
+        &self.workbook_spin_count
+    }
+
+    /// Protect the workbook structure with a password hashed by SHA-512 with a new salt.
+    /// # Arguments
+    /// * `password` - plain text password.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_security_mut()
+    ///     .set_workbook_password("P@ssw0rd")
+    ///     .set_lock_structure(true);
+    /// assert!(book.get_security().verify_workbook_password("P@ssw0rd"));
+    /// ```
+    pub fn set_workbook_password(&mut self, password:&str) -> &mut Security {
+        let salt = new_salt();
+        self.workbook_password = String::from("");
+        self.workbook_algorithm_name = Security::ALGORITHM_SHA_512.to_string();
+        self.workbook_hash_value = hash_password(password, &salt, &Security::DEFAULT_SPIN_COUNT);
+        self.workbook_salt_value = encode_base64(&salt);
+        self.workbook_spin_count = Security::DEFAULT_SPIN_COUNT;
+        self
+    }
+
+    /// Protect the workbook structure with a password hashed by the legacy 16 bit algorithm.
+    /// Weak, for applications that do not know SHA-512.
+    pub fn set_workbook_password_legacy(&mut self, password:&str) -> &mut Security {
+        self.clear_workbook_password();
+        self.workbook_password = hash_password_legacy(password);
+        self
+    }
+
+    pub fn clear_workbook_password(&mut self) -> &mut Security {
+        self.workbook_password = String::from("");
+        self.workbook_algorithm_name = String::from("");
+        self.workbook_hash_value = String::from("");
+        self.workbook_salt_value = String::from("");
+        self.workbook_spin_count = 0;
+        self
+    }
+
+    /// Check a password against the workbook password hash.
+    /// # Return value
+    /// * `bool` - true if the password matches, or if no password is set and the password is empty.
+    pub fn verify_workbook_password(&self, password:&str) -> bool {
+        verify_password(
+            password,
+            &self.workbook_password,
+            &self.workbook_algorithm_name,
+            &self.workbook_hash_value,
+            &self.workbook_salt_value,
+            &self.workbook_spin_count,
+        )
+    }
+
+    /// Protect the shared workbook revisions with a password hashed by SHA-512 with a new salt.
+    /// # Arguments
+    /// * `password` - plain text password.
+    pub fn set_revisions_password(&mut self, password:&str) -> &mut Security {
+        let salt = new_salt();
+        self.revisions_password = String::from("");
+        self.revisions_algorithm_name = Security::ALGORITHM_SHA_512.to_string();
+        self.revisions_hash_value = hash_password(password, &salt, &Security::DEFAULT_SPIN_COUNT);
+        self.revisions_salt_value = encode_base64(&salt);
+        self.revisions_spin_count = Security::DEFAULT_SPIN_COUNT;
+        self
+    }
+
+    /// Protect the shared workbook revisions with a password hashed by the legacy 16 bit algorithm.
+    pub fn set_revisions_password_legacy(&mut self, password:&str) -> &mut Security {
+        self.clear_revisions_password();
+        self.revisions_password = hash_password_legacy(password);
+        self
+    }
+
+    pub fn clear_revisions_password(&mut self) -> &mut Security {
+        self.revisions_password = String::from("");
+        self.revisions_algorithm_name = String::from("");
+        self.revisions_hash_value = String::from("");
+        self.revisions_salt_value = String::from("");
+        self.revisions_spin_count = 0;
+        self
+    }
+
+    /// Check a password against the revisions password hash.
+    /// # Return value
+    /// * `bool` - true if the password matches, or if no password is set and the password is empty.
+    pub fn verify_revisions_password(&self, password:&str) -> bool {
+        verify_password(
+            password,
+            &self.revisions_password,
+            &self.revisions_algorithm_name,
+            &self.revisions_hash_value,
+            &self.revisions_salt_value,
+            &self.revisions_spin_count,
+        )
+    }
+
+    pub(crate) fn set_revisions_password_hash<S: Into<String>>(&mut self, value:S) {
+        self.revisions_password = value.into();
+    }
+
+    pub(crate) fn set_revisions_algorithm_name<S: Into<String>>(&mut self, value:S) {
+        self.revisions_algorithm_name = value.into();
+    }
+
+    pub(crate) fn set_revisions_hash_value<S: Into<String>>(&mut self, value:S) {
+        self.revisions_hash_value = value.into();
+    }
+
+    pub(crate) fn set_revisions_salt_value<S: Into<String>>(&mut self, value:S) {
+        self.revisions_salt_value = value.into();
+    }
+
+    pub(crate) fn set_revisions_spin_count(&mut self, value:u32) {
+        self.revisions_spin_count = value;
+    }
+
+    pub(crate) fn set_workbook_password_hash<S: Into<String>>(&mut self, value:S) {
+        self.workbook_password = value.into();
+    }
+
+    pub(crate) fn set_workbook_algorithm_name<S: Into<String>>(&mut self, value:S) {
+        self.workbook_algorithm_name = value.into();
+    }
+
+    pub(crate) fn set_workbook_hash_value<S: Into<String>>(&mut self, value:S) {
+        self.workbook_hash_value = value.into();
+    }
+
+    pub(crate) fn set_workbook_salt_value<S: Into<String>>(&mut self, value:S) {
+        self.workbook_salt_value = value.into();
+    }
+
+    pub(crate) fn set_workbook_spin_count(&mut self, value:u32) {
+        self.workbook_spin_count = value;
+    }
+}
+
+// Only SHA-512 is checked among the modern algorithms; others never match.
+fn verify_password(password:&str, legacy_hash:&str, algorithm_name:&str, hash_value:&str, salt_value:&str, spin_count:&u32) -> bool {
+    if hash_value != "" {
+        if algorithm_name != Security::ALGORITHM_SHA_512 {
+            return false;
+        }
+        let salt = match decode_base64(salt_value) {
+            Some(v) => v,
+            None => return false,
+        };
+        return hash_password(password, &salt, spin_count) == hash_value;
+    }
+    if legacy_hash != "" {
+        return hash_password_legacy(password) == legacy_hash.to_uppercase();
+    }
+    password == ""
 }
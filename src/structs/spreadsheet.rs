@@ -2,6 +2,7 @@ use super::Worksheet;
 use super::Properties;
 use super::Security;
 use super::Calculation;
+use super::WorkbookView;
 use super::Style;
 use super::NumberFormat;
 use super::Font;
@@ -9,14 +10,17 @@ use super::Fill;
 use super::DefinedName;
 use super::Borders;
 use super::Theme;
+use super::Person;
 use helper::coordinate::*;
+use helper::date::*;
+use std::collections::HashSet;
 
 #[derive(Default, Debug)]
 pub struct Spreadsheet {
     properties: Properties,
     security: Security,
     work_sheet_collection: Vec<Worksheet>,
-    calculation_engine: Calculation,
+    calculation: Calculation,
     active_sheet_index: usize,
     named_ranges: Vec<String>,
     has_macros: bool,
@@ -25,16 +29,14 @@ pub struct Spreadsheet {
     ribbon_xml_data: Option<String>,
     ribbon_bin_objects: Option<Vec<String>>,
     unparsed_loaded_data: Vec<String>,
-    show_horizontal_scroll: bool,
-    show_vertical_scroll: bool,
-    show_sheet_tabs: bool,
-    minimized: bool,
-    auto_filter_date_grouping: bool,
-    first_sheet_index: i32,
-    visibility: String,
-    tab_ratio: i32,
+    workbook_view_collection: Vec<WorkbookView>,
     theme: Theme,
     defined_names: Vec<DefinedName>,
+    date1904: bool,
+    code_name: Option<String>,
+    filter_privacy: bool,
+    default_theme_version: Option<String>,
+    person_collection: Vec<Person>,
 }
 impl Spreadsheet {
     // ************************
@@ -131,7 +133,7 @@ impl Spreadsheet {
 
     pub(crate) fn adjustment_insert_coordinate(&mut self, sheet_name:&str, column_index:&usize, num_columns:&usize, row_index:&usize, num_rows:&usize) {
         for defined_name in &mut self.defined_names {
-            defined_name.get_address_obj_mut().adjustment_insert_coordinate(sheet_name, column_index, num_columns, row_index, num_rows);
+            defined_name.adjustment_insert_coordinate(sheet_name, column_index, num_columns, row_index, num_rows);
         }
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_insert_coordinate(sheet_name, column_index, num_columns, row_index, num_rows);
@@ -140,16 +142,25 @@ impl Spreadsheet {
 
     pub(crate) fn adjustment_remove_coordinate(&mut self, sheet_name:&str, column_index:&usize, num_columns:&usize, row_index:&usize, num_rows:&usize) {
         self.defined_names.retain(|x| {
-            !(x.get_address_obj().is_remove(sheet_name, column_index, num_columns, row_index, num_rows))
+            !(x.is_remove(sheet_name, column_index, num_columns, row_index, num_rows))
         });
         for defined_name in &mut self.defined_names {
-            defined_name.get_address_obj_mut().adjustment_remove_coordinate(sheet_name, column_index, num_columns, row_index, num_rows);
+            defined_name.adjustment_remove_coordinate(sheet_name, column_index, num_columns, row_index, num_rows);
         }
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_remove_coordinate(sheet_name, column_index, num_columns, row_index, num_rows);
         }
     }
 
+    pub(crate) fn adjustment_rename_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        for defined_name in &mut self.defined_names {
+            defined_name.adjustment_rename_sheet(old_sheet_name, new_sheet_name);
+        }
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.adjustment_rename_sheet(old_sheet_name, new_sheet_name);
+        }
+    }
+
     pub fn get_defined_names(&self) -> &Vec<DefinedName> {
         &self.defined_names
     }
@@ -166,14 +177,92 @@ impl Spreadsheet {
         self.defined_names.push(value);
     }
 
-    pub fn add_defined_name<S: Into<String>>(&mut self, name:S, address:S)->Result<(), &str> {
-        let mut defined_name = DefinedName::default();
-        defined_name.set_name(name.into());
-        defined_name.set_address(address.into());
+    /// Add a workbook scoped defined name.
+    /// # Arguments
+    /// * `name` - name. ex) "TaxRate"
+    /// * `formula` - reference, constant or formula. ex) "Sheet1!$A$1:$B$2", "0.19"
+    /// # Return value
+    /// * `Result` - Err when the name is already defined in the workbook scope.
+    pub fn add_defined_name<S: Into<String>>(&mut self, name:S, formula:S)->Result<(), &str> {
+        let defined_name = DefinedName::new(name, formula);
+        if self.get_defined_name(defined_name.get_name(), None).is_some() {
+            return Err("This name is already defined.");
+        }
+        self.defined_names.push(defined_name);
+        Ok(())
+    }
+
+    /// Add a defined name scoped to a sheet.
+    /// # Arguments
+    /// * `name` - name. ex) "Total"
+    /// * `formula` - reference, constant or formula. ex) "Sheet1!$C$10"
+    /// * `sheet_name` - sheet the name is visible from.
+    /// # Return value
+    /// * `Result` - Err when the sheet is not found or the name is already defined in its scope.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let _ = book.add_defined_name("TaxRate", "0.19");
+    /// let _ = book.add_local_defined_name("TaxRate", "0.07", "Sheet1");
+    /// assert_eq!("0.07", book.find_defined_name("TaxRate", "Sheet1").unwrap().get_formula());
+    /// ```
+    pub fn add_local_defined_name<S: Into<String>>(&mut self, name:S, formula:S, sheet_name:S)->Result<(), &str> {
+        let sheet_name = sheet_name.into();
+        let local_sheet_id = match self.work_sheet_collection.iter().position(|x| x.get_title() == sheet_name) {
+            Some(v) => v,
+            None => return Err("Not found."),
+        };
+        let mut defined_name = DefinedName::new(name, formula);
+        defined_name.set_local_sheet_id(local_sheet_id);
+        if self.get_defined_name(defined_name.get_name(), Some(local_sheet_id)).is_some() {
+            return Err("This name is already defined.");
+        }
         self.defined_names.push(defined_name);
         Ok(())
     }
 
+    /// Get a defined name by its name (case insensitive) and scope.
+    /// # Arguments
+    /// * `name` - name.
+    /// * `local_sheet_id` - index of the scoping sheet, `None` for workbook scope.
+    pub fn get_defined_name(&self, name:&str, local_sheet_id:Option<usize>)->Option<&DefinedName> {
+        self.defined_names.iter().find(|x| {
+            x.get_name().to_uppercase() == name.to_uppercase() && x.get_local_sheet_id() == &local_sheet_id
+        })
+    }
+
+    pub fn get_defined_name_mut(&mut self, name:&str, local_sheet_id:Option<usize>)->Option<&mut DefinedName> {
+        self.defined_names.iter_mut().find(|x| {
+            x.get_name().to_uppercase() == name.to_uppercase() && x.get_local_sheet_id() == &local_sheet_id
+        })
+    }
+
+    /// Resolve a name as seen from a sheet: a name scoped to the sheet wins over the workbook one.
+    /// # Arguments
+    /// * `name` - name.
+    /// * `sheet_name` - sheet the name is used on.
+    pub fn find_defined_name(&self, name:&str, sheet_name:&str)->Option<&DefinedName> {
+        let local_defined_name = match self.work_sheet_collection.iter().position(|x| x.get_title() == sheet_name) {
+            Some(v) => self.get_defined_name(name, Some(v)),
+            None => None,
+        };
+        match local_defined_name {
+            Some(v) => Some(v),
+            None => self.get_defined_name(name, None),
+        }
+    }
+
+    /// Remove a defined name.
+    /// # Return value
+    /// * `bool` - true when the name was found.
+    pub fn remove_defined_name(&mut self, name:&str, local_sheet_id:Option<usize>)->bool {
+        let count = self.defined_names.len();
+        self.defined_names.retain(|x| {
+            !(x.get_name().to_uppercase() == name.to_uppercase() && x.get_local_sheet_id() == &local_sheet_id)
+        });
+        count != self.defined_names.len()
+    }
+
     pub(crate) fn get_all_conditional_style_list(&self) -> Vec<(String, Style)> {
         let mut result:Vec<(String, Style)> = Vec::new();
         for work_sheet in &self.work_sheet_collection {
@@ -200,6 +289,87 @@ impl Spreadsheet {
         result
     }
 
+    /// Whether the workbook uses the 1904 date system.
+    pub fn get_date1904(&self) -> &bool {
+        &self.date1904
+    }
+
+    pub fn set_date1904(&mut self, value:bool) {
+        self.date1904 = value;
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.set_date1904(value);
+        }
+    }
+
+    /// VBA code name of the workbook. ex) "ThisWorkbook"
+    pub fn get_code_name(&self) -> &Option<String> {
+        &self.code_name
+    }
+
+    pub fn set_code_name<S: Into<String>>(&mut self, value:S) {
+        self.code_name = Some(value.into());
+    }
+
+    /// Whether Excel does not warn about personal information when saving.
+    pub fn get_filter_privacy(&self) -> &bool {
+        &self.filter_privacy
+    }
+
+    pub fn set_filter_privacy(&mut self, value:bool) {
+        self.filter_privacy = value;
+    }
+
+    pub fn get_default_theme_version(&self) -> &Option<String> {
+        &self.default_theme_version
+    }
+
+    pub fn set_default_theme_version<S: Into<String>>(&mut self, value:S) {
+        self.default_theme_version = Some(value.into());
+    }
+
+    /// Get the calculation properties.
+    pub fn get_calculation(&self) -> &Calculation {
+        &self.calculation
+    }
+
+    pub fn get_calculation_mut(&mut self) -> &mut Calculation {
+        &mut self.calculation
+    }
+
+    pub fn set_calculation(&mut self, value:Calculation) {
+        self.calculation = value;
+    }
+
+    /// Get the windows of the workbook.
+    /// A workbook without windows is written with one default window.
+    pub fn get_workbook_view_collection(&self) -> &Vec<WorkbookView> {
+        &self.workbook_view_collection
+    }
+
+    pub fn get_workbook_view_collection_mut(&mut self) -> &mut Vec<WorkbookView> {
+        &mut self.workbook_view_collection
+    }
+
+    pub fn add_workbook_view_collection(&mut self, value:WorkbookView) {
+        self.workbook_view_collection.push(value);
+    }
+
+    /// Get the first window of the workbook, made when there is none.
+    pub fn get_workbook_view_mut(&mut self) -> &mut WorkbookView {
+        if self.workbook_view_collection.is_empty() {
+            self.workbook_view_collection.push(WorkbookView::default());
+        }
+        self.workbook_view_collection.first_mut().unwrap()
+    }
+
+    pub(crate) fn get_calendar(&self) -> &str {
+        if self.date1904 {
+            CALENDAR_MAC_1904
+        } else {
+            CALENDAR_WINDOWS_1900
+        }
+    }
+
     pub fn get_theme(&self) -> &Theme {
         &self.theme
     }
@@ -210,13 +380,76 @@ impl Spreadsheet {
 
     pub(crate) fn has_comment(&self) -> bool {
         for worksheet in &self.work_sheet_collection {
-            if worksheet.get_comments().len() > 0 {
+            if worksheet.has_comments() {
                 return true;
             }
         }
         false
     }
 
+    /// Get the authors of threaded comments.
+    pub fn get_person_collection(&self) -> &Vec<Person> {
+        &self.person_collection
+    }
+
+    pub fn get_person_collection_mut(&mut self) -> &mut Vec<Person> {
+        &mut self.person_collection
+    }
+
+    pub fn add_person_collection(&mut self, value:Person) {
+        self.person_collection.push(value);
+    }
+
+    pub fn get_person_by_id(&self, id:&str) -> Option<&Person> {
+        self.person_collection.iter().find(|x| x.get_id() == id)
+    }
+
+    /// Add an author of threaded comments.
+    /// # Arguments
+    /// * `display_name` - name shown in the comments. ex) "Taro Yamada"
+    /// # Return value
+    /// * `&mut Person` - the new author. Use its id when adding threaded comments.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let person_id = book.add_person("Taro Yamada").get_id().to_string();
+    /// book.get_sheet_mut(0).add_threaded_comment("A1", &person_id, "Hello");
+    /// ```
+    pub fn add_person(&mut self, display_name:&str) -> &mut Person {
+        self.person_collection.push(Person::new_person(display_name));
+        self.person_collection.last_mut().unwrap()
+    }
+
+    /// Refresh the data caches of every chart from the referenced cells.
+    /// Saving only fills caches that are empty, such as those of charts added with `add_chart`.
+    /// Charts read from a file keep the caches they were loaded with, so call this after changing
+    /// the cells they reference so that viewers that do not recalculate show current data.
+    /// Formula cells contribute their cached result, as formulas are not recalculated.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_sheet_mut(0).get_cell_mut("A1").set_value("Item1");
+    /// book.get_sheet_mut(0).get_cell_mut("B1").set_value_number(10);
+    /// book.get_sheet_mut(0).add_chart(umya_spreadsheet::ChartType::Column, "D1:J10").unwrap()
+    ///     .add_series("", "$A$1:$A$1", "$B$1:$B$1");
+    /// book.get_sheet_mut(0).get_cell_mut("B1").set_value_number(20);
+    /// book.update_chart_caches();
+    /// ```
+    pub fn update_chart_caches(&mut self) {
+        let mut chart_spaces = Vec::new();
+        for (sheet_index, worksheet) in self.work_sheet_collection.iter().enumerate() {
+            for (frame_index, graphic_frame) in worksheet.get_worksheet_drawing().get_graphic_frame_collection().iter().enumerate() {
+                let mut chart_space = graphic_frame.get_graphic().get_graphic_data().get_chart_space().clone();
+                chart_space.update_caches(self, worksheet.get_title(), &true);
+                chart_spaces.push((sheet_index, frame_index, chart_space));
+            }
+        }
+        for (sheet_index, frame_index, chart_space) in chart_spaces {
+            let mut graphic_frames = self.work_sheet_collection[sheet_index].get_worksheet_drawing_mut().get_graphic_frame_collection_mut();
+            graphic_frames[frame_index].get_graphic_mut().get_graphic_data_mut().set_chart_space(chart_space);
+        }
+    }
+
     pub fn get_properties(&self) -> &Properties {
         &self.properties
     }
@@ -273,6 +506,19 @@ impl Spreadsheet {
         self.active_sheet_index = value;
     }
 
+    /// Select the sheet shown when the workbook is opened.
+    /// # Arguments
+    /// * `index` - sheet index. ex) 0
+    /// # Return value
+    /// * `Result` - Err when the sheet is not found.
+    pub fn set_active_sheet(&mut self, index:usize) -> Result<(), &'static str> {
+        if index >= self.work_sheet_collection.len() {
+            return Err("Not found.");
+        }
+        self.active_sheet_index = index;
+        Ok(())
+    }
+
     pub(crate) fn get_all_number_format(&self) -> Vec<(String, NumberFormat)> {
         let mut result:Vec<(String, NumberFormat)> = Vec::new();
         for (_, style) in &self.get_all_cell_style() {
@@ -373,19 +619,21 @@ impl Spreadsheet {
 
     pub(crate) fn get_all_cell_style(&self) -> Vec<(String, Style)> {
         let mut result:Vec<(String, Style)> = Vec::new();
+        let mut hash_set:HashSet<String> = HashSet::new();
         let def = Style::get_defalut_value();
+        hash_set.insert(def.get_hash_code());
         result.push((def.get_hash_code(), def));
         for worksheet in &self.work_sheet_collection {
             for style in worksheet.get_style_collection() {
-                let mut is_match = false;
-                for (hash, _) in &result {
-                    if hash == &style.get_hash_code() {
-                        is_match = true;
-                        break;
-                    }
+                let hash = style.get_hash_code();
+                if hash_set.insert(hash.clone()) {
+                    result.push((hash, style.clone()));
                 }
-                if is_match == false {
-                    result.push((style.get_hash_code(), style.clone()));
+            }
+            for style in worksheet.get_date_time_style_collection() {
+                let hash = style.get_hash_code();
+                if hash_set.insert(hash.clone()) {
+                    result.push((hash, style));
                 }
             }
         }
@@ -455,23 +703,32 @@ impl Spreadsheet {
         let mut worksheet = Worksheet::default();
         worksheet.set_sheet_id(sheet_id.into());
         worksheet.set_title(value.into());
+        worksheet.set_date1904(self.date1904);
         self.work_sheet_collection.push(worksheet);
         self.work_sheet_collection.last_mut().unwrap()
     }
 
+    /// Rename a worksheet.
+    /// References to the worksheet in formulas, charts, defined names and hyperlinks follow the new title.
+    /// # Arguments
+    /// * `index` - index of the worksheet.
+    /// * `value` - new title.
     pub fn set_sheet_title<S: Into<String>>(&mut self, index:usize, value:S) -> Result<(), &'static str>{
         let v = value.into();
         match Spreadsheet::check_sheet_title(self, &v) {
             Ok(_) => {},
             Err(e) => return Err(e)
         }
-        match self.work_sheet_collection.get_mut(index) {
+        let old_title = match self.work_sheet_collection.get_mut(index) {
             Some(sheet) => {
-                sheet.set_title(v);
-                Ok(())
+                let old_title = sheet.get_title().to_string();
+                sheet.set_title(v.clone());
+                old_title
             },
             None => return Err("sheet not found.")
-        }
+        };
+        self.adjustment_rename_sheet(&old_title, &v);
+        Ok(())
     }
 
     pub(crate) fn check_sheet_title<S: Into<String>>(&self, value:S) -> Result<(), &'static str> {
@@ -0,0 +1,133 @@
+use super::Coordinate;
+use super::ThreadedCommentMention;
+use helper::guid::*;
+
+/// A comment of a conversation thread (xl/threadedComments).
+/// The first comment of a thread has no parent id, replies refer to it.
+#[derive(Default, Debug, Clone)]
+pub struct ThreadedComment {
+    coordinate: Coordinate,
+    id: String,
+    person_id: String,
+    parent_id: Option<String>,
+    date_time: String,
+    done: bool,
+    text: String,
+    mentions: Vec<ThreadedCommentMention>,
+}
+impl ThreadedComment {
+    pub fn get_coordinate(&self)-> &Coordinate {
+        &self.coordinate
+    }
+
+    pub fn get_coordinate_mut(&mut self)-> &mut Coordinate {
+        &mut self.coordinate
+    }
+
+    pub fn get_id(&self)-> &str {
+        &self.id
+    }
+
+    pub fn set_id<S: Into<String>>(&mut self, value:S)-> &mut ThreadedComment {
+        self.id = value.into();
+        self
+    }
+
+    /// Id of the author. see `Person`.
+    pub fn get_person_id(&self)-> &str {
+        &self.person_id
+    }
+
+    pub fn set_person_id<S: Into<String>>(&mut self, value:S)-> &mut ThreadedComment {
+        self.person_id = value.into();
+        self
+    }
+
+    /// Id of the first comment of the thread. None for the first comment itself.
+    pub fn get_parent_id(&self)-> &Option<String> {
+        &self.parent_id
+    }
+
+    pub fn set_parent_id<S: Into<String>>(&mut self, value:S)-> &mut ThreadedComment {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    pub fn remove_parent_id(&mut self)-> &mut ThreadedComment {
+        self.parent_id = None;
+        self
+    }
+
+    pub fn is_reply(&self)-> bool {
+        self.parent_id.is_some()
+    }
+
+    /// Creation date time. ex) "2021-04-01T09:30:00.00"
+    pub fn get_date_time(&self)-> &str {
+        &self.date_time
+    }
+
+    pub fn set_date_time<S: Into<String>>(&mut self, value:S)-> &mut ThreadedComment {
+        self.date_time = value.into();
+        self
+    }
+
+    /// Whether the thread is resolved. Only meaningful on the first comment of a thread.
+    pub fn get_done(&self)-> &bool {
+        &self.done
+    }
+
+    pub fn set_done(&mut self, value:bool)-> &mut ThreadedComment {
+        self.done = value;
+        self
+    }
+
+    pub fn get_text(&self)-> &str {
+        &self.text
+    }
+
+    pub fn set_text<S: Into<String>>(&mut self, value:S)-> &mut ThreadedComment {
+        self.text = value.into();
+        self
+    }
+
+    pub fn get_mentions(&self)-> &Vec<ThreadedCommentMention> {
+        &self.mentions
+    }
+
+    pub fn get_mentions_mut(&mut self)-> &mut Vec<ThreadedCommentMention> {
+        &mut self.mentions
+    }
+
+    pub fn add_mentions(&mut self, value:ThreadedCommentMention)-> &mut ThreadedComment {
+        self.mentions.push(value);
+        self
+    }
+
+    /// Mention a person in the text.
+    /// # Arguments
+    /// * `person_id` - id of the mentioned person.
+    /// * `start_index` - position of the mention in the text, in characters.
+    /// * `length` - length of the mention in the text, in characters.
+    pub fn add_mention(&mut self, person_id:&str, start_index:usize, length:usize)-> &mut ThreadedComment {
+        let mut mention = ThreadedCommentMention::default();
+        mention.set_mention_person_id(person_id);
+        mention.set_mention_id(new_guid(person_id));
+        mention.set_start_index(start_index);
+        mention.set_length(length);
+        self.add_mentions(mention)
+    }
+
+    pub(crate) fn new_threaded_comment(coordinate:&str, person_id:&str, text:&str) -> ThreadedComment {
+        let mut threaded_comment = ThreadedComment::default();
+        threaded_comment.get_coordinate_mut().set_coordinate(coordinate);
+        threaded_comment.set_id(new_guid(coordinate));
+        threaded_comment.set_person_id(person_id);
+        // Excel writes hundredths of a second
+        let mut date_time = chrono::Local::now().naive_local().format("%Y-%m-%dT%H:%M:%S%.3f").to_string();
+        date_time.pop();
+        threaded_comment.set_date_time(date_time);
+        threaded_comment.set_text(text);
+        threaded_comment
+    }
+}
@@ -0,0 +1,46 @@
+/// A mention of a person in the text of a threaded comment.
+#[derive(Default, Debug, Clone)]
+pub struct ThreadedCommentMention {
+    mention_person_id: String,
+    mention_id: String,
+    start_index: usize,
+    length: usize,
+}
+impl ThreadedCommentMention {
+    pub fn get_mention_person_id(&self)-> &str {
+        &self.mention_person_id
+    }
+
+    pub fn set_mention_person_id<S: Into<String>>(&mut self, value:S)-> &mut ThreadedCommentMention {
+        self.mention_person_id = value.into();
+        self
+    }
+
+    pub fn get_mention_id(&self)-> &str {
+        &self.mention_id
+    }
+
+    pub fn set_mention_id<S: Into<String>>(&mut self, value:S)-> &mut ThreadedCommentMention {
+        self.mention_id = value.into();
+        self
+    }
+
+    /// Position of the mention in the comment text, in characters.
+    pub fn get_start_index(&self)-> &usize {
+        &self.start_index
+    }
+
+    pub fn set_start_index(&mut self, value:usize)-> &mut ThreadedCommentMention {
+        self.start_index = value;
+        self
+    }
+
+    pub fn get_length(&self)-> &usize {
+        &self.length
+    }
+
+    pub fn set_length(&mut self, value:usize)-> &mut ThreadedCommentMention {
+        self.length = value;
+        self
+    }
+}
@@ -0,0 +1,168 @@
+/// A window of the workbook (workbookView).
+#[derive(Debug, Clone)]
+pub struct WorkbookView {
+    x_window: i32,
+    y_window: i32,
+    window_width: u32,
+    window_height: u32,
+    tab_ratio: u32,
+    first_sheet: usize,
+    active_tab: usize,
+    show_horizontal_scroll: bool,
+    show_vertical_scroll: bool,
+    show_sheet_tabs: bool,
+    minimized: bool,
+    visibility: String,
+    auto_filter_date_grouping: bool,
+}
+impl Default for WorkbookView {
+    fn default() -> Self {
+        Self {
+            x_window: 240,
+            y_window: 105,
+            window_width: 14805,
+            window_height: 8010,
+            tab_ratio: 600,
+            first_sheet: 0,
+            active_tab: 0,
+            show_horizontal_scroll: true,
+            show_vertical_scroll: true,
+            show_sheet_tabs: true,
+            minimized: false,
+            visibility: WorkbookView::VISIBILITY_VISIBLE.to_string(),
+            auto_filter_date_grouping: true,
+        }
+    }
+}
+impl WorkbookView {
+    pub const VISIBILITY_VISIBLE: &'static str = "visible";
+    pub const VISIBILITY_HIDDEN: &'static str = "hidden";
+    pub const VISIBILITY_VERY_HIDDEN: &'static str = "veryHidden";
+
+    /// Left edge of the window, in twips.
+    pub fn get_x_window(&self)-> &i32 {
+        &self.x_window
+    }
+
+    pub fn set_x_window(&mut self, value:i32)-> &mut WorkbookView {
+        self.x_window = value;
+        self
+    }
+
+    /// Top edge of the window, in twips.
+    pub fn get_y_window(&self)-> &i32 {
+        &self.y_window
+    }
+
+    pub fn set_y_window(&mut self, value:i32)-> &mut WorkbookView {
+        self.y_window = value;
+        self
+    }
+
+    /// Width of the window, in twips.
+    pub fn get_window_width(&self)-> &u32 {
+        &self.window_width
+    }
+
+    pub fn set_window_width(&mut self, value:u32)-> &mut WorkbookView {
+        self.window_width = value;
+        self
+    }
+
+    /// Height of the window, in twips.
+    pub fn get_window_height(&self)-> &u32 {
+        &self.window_height
+    }
+
+    pub fn set_window_height(&mut self, value:u32)-> &mut WorkbookView {
+        self.window_height = value;
+        self
+    }
+
+    /// Share of the sheet tabs against the horizontal scroll bar, in 1/1000.
+    pub fn get_tab_ratio(&self)-> &u32 {
+        &self.tab_ratio
+    }
+
+    pub fn set_tab_ratio(&mut self, value:u32)-> &mut WorkbookView {
+        self.tab_ratio = value;
+        self
+    }
+
+    /// Index of the first sheet tab shown.
+    pub fn get_first_sheet(&self)-> &usize {
+        &self.first_sheet
+    }
+
+    pub fn set_first_sheet(&mut self, value:usize)-> &mut WorkbookView {
+        self.first_sheet = value;
+        self
+    }
+
+    /// Index of the selected sheet.
+    /// The first view is written with `Spreadsheet::get_active_sheet_index`.
+    pub fn get_active_tab(&self)-> &usize {
+        &self.active_tab
+    }
+
+    pub fn set_active_tab(&mut self, value:usize)-> &mut WorkbookView {
+        self.active_tab = value;
+        self
+    }
+
+    pub fn get_show_horizontal_scroll(&self)-> &bool {
+        &self.show_horizontal_scroll
+    }
+
+    pub fn set_show_horizontal_scroll(&mut self, value:bool)-> &mut WorkbookView {
+        self.show_horizontal_scroll = value;
+        self
+    }
+
+    pub fn get_show_vertical_scroll(&self)-> &bool {
+        &self.show_vertical_scroll
+    }
+
+    pub fn set_show_vertical_scroll(&mut self, value:bool)-> &mut WorkbookView {
+        self.show_vertical_scroll = value;
+        self
+    }
+
+    pub fn get_show_sheet_tabs(&self)-> &bool {
+        &self.show_sheet_tabs
+    }
+
+    pub fn set_show_sheet_tabs(&mut self, value:bool)-> &mut WorkbookView {
+        self.show_sheet_tabs = value;
+        self
+    }
+
+    pub fn get_minimized(&self)-> &bool {
+        &self.minimized
+    }
+
+    pub fn set_minimized(&mut self, value:bool)-> &mut WorkbookView {
+        self.minimized = value;
+        self
+    }
+
+    /// see `VISIBILITY_*`.
+    pub fn get_visibility(&self)-> &str {
+        &self.visibility
+    }
+
+    pub fn set_visibility<S: Into<String>>(&mut self, value:S)-> &mut WorkbookView {
+        self.visibility = value.into();
+        self
+    }
+
+    /// Whether dates are grouped in the auto filter menus.
+    pub fn get_auto_filter_date_grouping(&self)-> &bool {
+        &self.auto_filter_date_grouping
+    }
+
+    pub fn set_auto_filter_date_grouping(&mut self, value:bool)-> &mut WorkbookView {
+        self.auto_filter_date_grouping = value;
+        self
+    }
+}
@@ -4,6 +4,17 @@ use super::Range;
 use super::RowDimension;
 use super::ColumnDimension;
 use super::drawing::spreadsheet::WorksheetDrawing;
+use super::drawing::spreadsheet::OneCellAnchor;
+use super::drawing::spreadsheet::FromMarker;
+use super::drawing::spreadsheet::ToMarker;
+use super::drawing::spreadsheet::Extent;
+use super::drawing::spreadsheet::Picture;
+use super::drawing::spreadsheet::Shape;
+use super::drawing::spreadsheet::ConnectionShape;
+use super::drawing::spreadsheet::GroupShape;
+use super::drawing::Transform2D;
+use super::drawing::charts::ChartSpace;
+use super::ChartType;
 use super::PageSetup;
 use super::PageMargins;
 use super::HeaderFooter;
@@ -16,11 +27,18 @@ use super::AutoFilter;
 use super::Hyperlink;
 use super::Color;
 use super::Comment;
+use super::ThreadedComment;
+use super::RichText;
+use super::TextElement;
 use super::NumberFormat;
 use std::collections::BTreeMap; 
 use std::collections::HashMap;
+use std::path::Path;
 use helper::coordinate::*;
 use helper::number_format::*;
+use helper::date::*;
+use helper::image::*;
+use chrono::NaiveTime;
 
 #[derive(Debug)]
 pub struct Worksheet {
@@ -50,6 +68,8 @@ pub struct Worksheet {
     show_summary_below: bool,
     show_summary_right: bool,
     comments: Vec<Comment>,
+    threaded_comments: Vec<ThreadedComment>,
+    hyperlink_range_collection: Vec<(Range, Hyperlink)>,
     active_cell: String,
     selected_cells: String,
     right_to_left: bool,
@@ -58,6 +78,7 @@ pub struct Worksheet {
     dirty: bool,
     hash: String,
     code_name: Option<String>,
+    date1904: bool,
 }
 impl Default for Worksheet {
     fn default() -> Self {
@@ -88,6 +109,8 @@ impl Default for Worksheet {
             show_summary_below: false,
             show_summary_right: false,
             comments: Vec::new(),
+            threaded_comments: Vec::new(),
+            hyperlink_range_collection: Vec::new(),
             active_cell: String::from(""),
             selected_cells: String::from(""),
             right_to_left: false,
@@ -96,6 +119,7 @@ impl Default for Worksheet {
             dirty: false,
             hash: String::from(""),
             code_name: None,
+            date1904: false,
         }
     }
 }
@@ -137,7 +161,7 @@ impl Worksheet {
     /// ```
     pub fn get_value_by_column_and_row(&self, col:usize, row:usize)-> String {
         match self.get_cell_by_column_and_row(col, row) {
-            Some(v) => {v.get_value().into()},
+            Some(v) => {v.get_value()},
             None => "".into()
         }
     }
@@ -175,7 +199,12 @@ impl Worksheet {
     /// ```
     pub fn get_formatted_value_by_column_and_row(&self, col:usize, row:usize)-> String {
         let value:String = match self.get_cell_by_column_and_row(col, row) {
-            Some(v) => {v.get_value().into()},
+            Some(v) => {
+                match v.get_value_bool() {
+                    Some(b) => return if b {"TRUE".into()} else {"FALSE".into()},
+                    None => v.get_cell_value().get_value_with_calendar(self.get_calendar())
+                }
+            },
             None => "".into()
         };
         
@@ -184,15 +213,15 @@ impl Worksheet {
             Some(style) => {
                 match style.get_number_format() {
                     Some(nmuber_format) => {
-                        to_formatted_string(value.as_str(), nmuber_format.get_format_code())
+                        to_formatted_string_with_calendar(value.as_str(), nmuber_format.get_format_code(), self.get_calendar())
                     },
                     None => {
-                        to_formatted_string(value.as_str(), NumberFormat::FORMAT_GENERAL)
+                        to_formatted_string_with_calendar(value.as_str(), NumberFormat::FORMAT_GENERAL, self.get_calendar())
                     }
                 }
             },
             None => {
-                to_formatted_string(value.as_str(), NumberFormat::FORMAT_GENERAL)
+                to_formatted_string_with_calendar(value.as_str(), NumberFormat::FORMAT_GENERAL, self.get_calendar())
             }
         };
         result
@@ -408,6 +437,44 @@ impl Worksheet {
         self.styles.add(style);
     }
 
+    /// Style to write for a date and time cell whose style has no date number format.
+    pub(crate) fn get_date_time_style_by_column_and_row(&self, col:&usize, row:&usize) -> Option<Style> {
+        let cell = self.get_cell_by_column_and_row(*col, *row)?;
+        let date_time = cell.get_date_time()?;
+        let mut style = match self.get_style_by_column_and_row(*col, *row) {
+            Some(v) => v.clone(),
+            None => {
+                let mut style = Style::default();
+                style.get_coordinate_mut().set_col_num(*col);
+                style.get_coordinate_mut().set_row_num(*row);
+                style
+            }
+        };
+        if let Some(v) = style.get_number_format() {
+            if is_date_format(v.get_format_code()) {
+                return None;
+            }
+        }
+        let format_code = if date_time.time() == NaiveTime::from_hms_opt(0, 0, 0).unwrap() {
+            NumberFormat::FORMAT_DATE_BUILT_IN
+        } else {
+            NumberFormat::FORMAT_DATE_TIME_BUILT_IN
+        };
+        style.get_number_format_mut().set_format_code(format_code);
+        Some(style)
+    }
+
+    pub(crate) fn get_date_time_style_collection(&self) -> Vec<Style> {
+        let mut result: Vec<Style> = Vec::new();
+        for cell in self.cell_collection.get_collection() {
+            let coordinate = cell.get_coordinate();
+            if let Some(v) = self.get_date_time_style_by_column_and_row(coordinate.get_col_num(), coordinate.get_row_num()) {
+                result.push(v);
+            }
+        }
+        result
+    }
+
     // ************************    
     // Comment
     // ************************
@@ -432,8 +499,167 @@ impl Worksheet {
         self.comments.push(value);
     }
 
+    /// Add a note to a cell. A note already on the cell is replaced.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) "A1"
+    /// * `author` - author of the note.
+    /// * `text` - text of the note.
+    /// # Return value
+    /// * `&mut Comment` - the new note, with the default size and position of Excel.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let comment = book.get_sheet_mut(0).add_comment("B2", "Taro", "Checked.");
+    /// comment.set_anchor_range("C1:F6");
+    /// comment.set_width_pt(150.0);
+    /// comment.set_visible(true);
+    /// ```
+    pub fn add_comment<S: Into<String>, A: Into<String>, T: Into<String>>(&mut self, coordinate:S, author:A, text:T)-> &mut Comment {
+        let index = index_from_coordinate(coordinate.into().to_uppercase());
+        self.comments.retain(|x| {
+            !(x.get_coordinate().get_col_num() == &index[0] && x.get_coordinate().get_row_num() == &index[1])
+        });
+        let mut comment = Comment::new_note(&index[0], &index[1]);
+        comment.set_author(author);
+        comment.set_text_string(text);
+        self.comments.push(comment);
+        self.comments.last_mut().unwrap()
+    }
+
     pub fn has_comments(&self) -> bool {
-        self.comments.len() > 1
+        self.comments.len() > 0 || self.threaded_comments.len() > 0
+    }
+
+    /// Get the legacy comments to write.
+    /// Every thread of threaded comments gets a note with a plain text copy of the conversation,
+    /// which is what versions of Excel without threaded comments show.
+    pub(crate) fn get_comments_with_threaded_fallback(&self)-> Vec<Comment> {
+        let mut result = self.comments.clone();
+        for threaded_comment in &self.threaded_comments {
+            if threaded_comment.is_reply() {
+                continue;
+            }
+            let mut text = String::from("[Threaded comment]\n\nYour version of Excel allows you to read this threaded comment; however, any edits to it will get removed if the file is opened in a newer version of Excel. Learn more: https://go.microsoft.com/fwlink/?linkid=870924\n\nComment:\n    ");
+            text.push_str(threaded_comment.get_text());
+            for reply in self.get_threaded_comment_replies(threaded_comment.get_id()) {
+                text.push_str("\nReply:\n    ");
+                text.push_str(reply.get_text());
+            }
+            let mut text_element = TextElement::default();
+            text_element.set_text(text);
+            let mut rich_text = RichText::default();
+            rich_text.add_rich_text_elements(text_element);
+
+            let coordinate = threaded_comment.get_coordinate();
+            let position = result.iter().position(|x| {
+                x.get_coordinate().get_col_num() == coordinate.get_col_num() && x.get_coordinate().get_row_num() == coordinate.get_row_num()
+            });
+            let comment = match position {
+                Some(v) => &mut result[v],
+                None => {
+                    result.push(Comment::new_note(coordinate.get_col_num(), coordinate.get_row_num()));
+                    result.last_mut().unwrap()
+                }
+            };
+            comment.set_author(format!("tc={}", threaded_comment.get_id()));
+            comment.set_text(rich_text);
+        }
+        result
+    }
+
+    /// Remove the legacy notes which only copy threaded comments.
+    /// They are made again from the threaded comments on write.
+    pub(crate) fn remove_threaded_comment_fallbacks(&mut self) {
+        let authors: Vec<String> = self.threaded_comments.iter().map(|x| format!("tc={}", x.get_id())).collect();
+        self.comments.retain(|x| !authors.iter().any(|author| author == x.get_author()));
+    }
+
+    // ************************
+    // Threaded Comment
+    // ************************
+    pub fn get_threaded_comments(&self)-> &Vec<ThreadedComment> {
+        &self.threaded_comments
+    }
+
+    pub fn get_threaded_comments_mut(&mut self)-> &mut Vec<ThreadedComment> {
+        &mut self.threaded_comments
+    }
+
+    pub fn set_threaded_comments(&mut self, value:Vec<ThreadedComment>) {
+        self.threaded_comments = value;
+    }
+
+    pub fn add_threaded_comments(&mut self, value:ThreadedComment) {
+        self.threaded_comments.push(value);
+    }
+
+    /// Get the threaded comment by id.
+    /// # Arguments
+    /// * `id` - id of the threaded comment.
+    pub fn get_threaded_comment_by_id(&self, id:&str)-> Option<&ThreadedComment> {
+        self.threaded_comments.iter().find(|x| x.get_id() == id)
+    }
+
+    pub fn get_threaded_comment_by_id_mut(&mut self, id:&str)-> Option<&mut ThreadedComment> {
+        self.threaded_comments.iter_mut().find(|x| x.get_id() == id)
+    }
+
+    /// Get the replies to a threaded comment, oldest first.
+    /// # Arguments
+    /// * `id` - id of the first comment of the thread.
+    pub fn get_threaded_comment_replies(&self, id:&str)-> Vec<&ThreadedComment> {
+        self.threaded_comments.iter().filter(|x| {
+            match x.get_parent_id() {
+                Some(v) => v == id,
+                None => false,
+            }
+        }).collect()
+    }
+
+    /// Start a new thread of comments on a cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) "A1"
+    /// * `person_id` - id of the author. see `Spreadsheet::add_person`.
+    /// * `text` - text of the comment.
+    /// # Return value
+    /// * `&mut ThreadedComment` - the new comment.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let person_id = book.add_person("Taro Yamada").get_id().to_string();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// let thread_id = worksheet.add_threaded_comment("B2", &person_id, "Please check.").get_id().to_string();
+    /// let _ = worksheet.add_threaded_comment_reply(&thread_id, &person_id, "Done.").unwrap();
+    /// worksheet.get_threaded_comment_by_id_mut(&thread_id).unwrap().set_done(true);
+    /// ```
+    pub fn add_threaded_comment<S: Into<String>, T: Into<String>>(&mut self, coordinate:S, person_id:&str, text:T)-> &mut ThreadedComment {
+        let coordinate_upper = coordinate.into().to_uppercase();
+        let threaded_comment = ThreadedComment::new_threaded_comment(&coordinate_upper, person_id, &text.into());
+        self.threaded_comments.push(threaded_comment);
+        self.threaded_comments.last_mut().unwrap()
+    }
+
+    /// Reply to a thread of comments.
+    /// # Arguments
+    /// * `parent_id` - id of the first comment of the thread.
+    /// * `person_id` - id of the author. see `Spreadsheet::add_person`.
+    /// * `text` - text of the reply.
+    /// # Return value
+    /// * `Result` - Ok is the new reply. Err when the thread is not found.
+    pub fn add_threaded_comment_reply<S: Into<String>>(&mut self, parent_id:&str, person_id:&str, text:S)-> Result<&mut ThreadedComment, &'static str> {
+        let coordinate = match self.get_threaded_comment_by_id(parent_id) {
+            Some(v) => {
+                if v.is_reply() {
+                    return Err("Can not reply to a reply.");
+                }
+                v.get_coordinate().get_coordinate()
+            },
+            None => return Err("Not found."),
+        };
+        let mut threaded_comment = ThreadedComment::new_threaded_comment(&coordinate, person_id, &text.into());
+        threaded_comment.set_parent_id(parent_id);
+        self.threaded_comments.push(threaded_comment);
+        Ok(self.threaded_comments.last_mut().unwrap())
     }
 
     // ************************    
@@ -455,18 +681,96 @@ impl Worksheet {
     // ************************    
     // Hyperlink
     // ************************
-    pub(crate) fn get_hyperlink_collection(&self)-> HashMap<String, &Hyperlink> {
-        let mut result: HashMap<String, &Hyperlink> = HashMap::new();
+    /// Get all hyperlinks of the worksheet with the cell or range they are on, in order of rows.
+    /// # Return value
+    /// * `Vec<(String, &Hyperlink)>` - ex) ("A1", hyperlink), ("B2:D2", hyperlink)
+    pub fn get_hyperlink_collection(&self)-> Vec<(String, &Hyperlink)> {
+        let mut result: Vec<(usize, usize, String, &Hyperlink)> = Vec::new();
         for cell in self.cell_collection.get_collection() {
-            match cell.get_hyperlink() {
-                Some(hyperlink) => {
-                    let coordition = coordinate_from_index(cell.get_coordinate().get_col_num(), cell.get_coordinate().get_row_num());
-                    result.insert(coordition, hyperlink);
-                },
-                None => {}
+            if let Some(hyperlink) = cell.get_hyperlink() {
+                let coordinate = cell.get_coordinate();
+                let coordition = coordinate_from_index(coordinate.get_col_num(), coordinate.get_row_num());
+                result.push((*coordinate.get_row_num(), *coordinate.get_col_num(), coordition, hyperlink));
             }
         }
-        result
+        for (range, hyperlink) in &self.hyperlink_range_collection {
+            let coordinate = range.get_coordinate_start();
+            result.push((*coordinate.get_row_num(), *coordinate.get_col_num(), range.get_range(), hyperlink));
+        }
+        result.sort_by_key(|x| (x.0, x.1));
+        result.into_iter().map(|x| (x.2, x.3)).collect()
+    }
+
+    /// Get the hyperlink on a cell or range.
+    /// # Arguments
+    /// * `range` - Specify the cell or range. ex) "A1" or "A1:C1"
+    pub fn get_hyperlink<S: Into<String>>(&self, range:S)-> Option<&Hyperlink> {
+        let range_upper = range.into().to_uppercase();
+        if !range_upper.contains(':') {
+            return match self.get_cell(range_upper) {
+                Some(v) => v.get_hyperlink().as_ref(),
+                None => None,
+            };
+        }
+        self.hyperlink_range_collection.iter().find(|x| x.0.get_range() == range_upper).map(|x| &x.1)
+    }
+
+    pub fn get_hyperlink_mut<S: Into<String>>(&mut self, range:S)-> Option<&mut Hyperlink> {
+        let range_upper = range.into().to_uppercase();
+        if !range_upper.contains(':') {
+            if self.get_hyperlink(range_upper.as_str()).is_none() {
+                return None;
+            }
+            return Some(self.get_cell_mut(range_upper).get_hyperlink_mut());
+        }
+        self.hyperlink_range_collection.iter_mut().find(|x| x.0.get_range() == range_upper).map(|x| &mut x.1)
+    }
+
+    /// Put a hyperlink on a cell or range. A hyperlink already there is replaced.
+    /// # Arguments
+    /// * `range` - Specify the cell or range. ex) "A1" or "A1:C1"
+    /// * `value` - the hyperlink.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::Hyperlink;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// worksheet.add_hyperlink("A1", Hyperlink::new_url("https://example.com"));
+    /// worksheet.add_hyperlink("A2:C2", Hyperlink::new_location("Sheet1", "D10"));
+    /// worksheet.add_hyperlink("A3", Hyperlink::new_mail("info@example.com", Some("Question")))
+    ///     .set_tooltip("Write a mail");
+    /// ```
+    pub fn add_hyperlink<S: Into<String>>(&mut self, range:S, value:Hyperlink)-> &mut Hyperlink {
+        let range_upper = range.into().to_uppercase();
+        if !range_upper.contains(':') {
+            let cell = self.get_cell_mut(range_upper);
+            let _ = cell.set_hyperlink(value);
+            return cell.get_hyperlink_mut();
+        }
+        self.hyperlink_range_collection.retain(|x| x.0.get_range() != range_upper);
+        let mut range = Range::default();
+        range.set_range(range_upper);
+        self.hyperlink_range_collection.push((range, value));
+        &mut self.hyperlink_range_collection.last_mut().unwrap().1
+    }
+
+    /// Remove the hyperlink on a cell or range.
+    /// # Arguments
+    /// * `range` - Specify the cell or range. ex) "A1" or "A1:C1"
+    /// # Return value
+    /// * `bool` - whether a hyperlink was removed.
+    pub fn remove_hyperlink<S: Into<String>>(&mut self, range:S)-> bool {
+        let range_upper = range.into().to_uppercase();
+        if !range_upper.contains(':') {
+            if self.get_hyperlink(range_upper.as_str()).is_none() {
+                return false;
+            }
+            self.get_cell_mut(range_upper).remove_hyperlink();
+            return true;
+        }
+        let count = self.hyperlink_range_collection.len();
+        self.hyperlink_range_collection.retain(|x| x.0.get_range() != range_upper);
+        count != self.hyperlink_range_collection.len()
     }
 
     // ************************    
@@ -580,6 +884,264 @@ impl Worksheet {
         self.worksheet_drawing.has_drawing_object()
     }
 
+    /// Add a chart.
+    /// Axes, axis ids and styles are set up for the chart type, and caches are filled from the cells on write.
+    /// # Arguments
+    /// * `chart_type` - Type of the chart.
+    /// * `range` - Cells the chart covers. ex) "E2:L20"
+    /// # Return value
+    /// * `Result<&mut ChartSpace, &'static str>` - The new chart, to add series, titles and a legend to. Err if the range is invalid.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// worksheet.add_chart(umya_spreadsheet::ChartType::Column, "E2:L20").unwrap()
+    ///     .add_series("Sheet1!$B$1", "Sheet1!$A$2:$A$6", "Sheet1!$B$2:$B$6")
+    ///     .set_title_text("Sales")
+    ///     .set_legend_position(umya_spreadsheet::drawing::charts::LegendPosition::POSITION_BOTTOM)
+    ///     .set_category_axis_title_text("Month")
+    ///     .set_value_axis_title_text("Amount");
+    /// ```
+    pub fn add_chart(&mut self, chart_type:ChartType, range:&str) -> Result<&mut ChartSpace, &'static str> {
+        self.worksheet_drawing.add_chart(chart_type, range)
+    }
+
+    /// Add a shape over a range of cells.
+    /// # Arguments
+    /// * `geometry` - Preset geometry of the shape. ex) PresetGeometry::GEOMETRY_RECT
+    /// * `range` - Cells the shape covers. ex) "B2:E6"
+    /// # Return value
+    /// * `Result<&mut Shape, &'static str>` - The new shape, to set the text, fill and outline of. Err if the range is invalid.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::PresetGeometry;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// let shape = worksheet.add_shape(PresetGeometry::GEOMETRY_WEDGERECTCALLOUT, "B2:E6").unwrap();
+    /// shape.set_text("Check this value");
+    /// shape.get_shape_properties_mut()
+    ///     .set_fill_color("FFF2CC")
+    ///     .set_outline_color("BF9000");
+    /// ```
+    pub fn add_shape(&mut self, geometry:&str, range:&str) -> Result<&mut Shape, &'static str> {
+        self.worksheet_drawing.add_shape(geometry, range)
+    }
+
+    /// Add a connector over a range of cells.
+    /// Attach its ends to shapes with `set_start_connection_shape` and `set_end_connection_shape`.
+    /// # Arguments
+    /// * `geometry` - Preset geometry of the connector. ex) PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1
+    /// * `range` - Cells the connector covers. ex) "E4:G4"
+    /// # Return value
+    /// * `Result<&mut ConnectionShape, &'static str>` - The new connector. Err if the range is invalid.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::{PresetGeometry, TailEnd};
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// let start_id = worksheet.add_shape(PresetGeometry::GEOMETRY_RECT, "B2:C4").unwrap()
+    ///     .get_non_visual_shape_properties().get_non_visual_drawing_properties().get_id().to_string();
+    /// let end_id = worksheet.add_shape(PresetGeometry::GEOMETRY_RECT, "F2:G4").unwrap()
+    ///     .get_non_visual_shape_properties().get_non_visual_drawing_properties().get_id().to_string();
+    /// worksheet.add_connection_shape(PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1, "D3:E3").unwrap()
+    ///     .set_start_connection_shape(start_id, &3)
+    ///     .set_end_connection_shape(end_id, &1)
+    ///     .get_shape_properties_mut()
+    ///     .set_tail_end_type(TailEnd::TYPE_TRIANGLE);
+    /// ```
+    pub fn add_connection_shape(&mut self, geometry:&str, range:&str) -> Result<&mut ConnectionShape, &'static str> {
+        self.worksheet_drawing.add_connection_shape(geometry, range)
+    }
+
+    /// Add a group over a range of cells.
+    /// Objects added to the group are placed in EMU from the top left corner of the range.
+    /// # Arguments
+    /// * `range` - Cells the group covers. ex) "B2:F10"
+    /// # Return value
+    /// * `Result<&mut GroupShape, &'static str>` - The new group. Err if the range is invalid.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::{PresetGeometry, Transform2D};
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// let group_id = worksheet.add_group_shape("B2:F10").unwrap()
+    ///     .get_non_visual_group_shape_properties().get_non_visual_drawing_properties().get_id().to_string();
+    /// let mut transform2d = Transform2D::default();
+    /// transform2d.set_width(914400);
+    /// transform2d.set_height(457200);
+    /// let _ = worksheet.add_shape_to_group(&group_id, PresetGeometry::GEOMETRY_ELLIPSE, transform2d);
+    /// ```
+    pub fn add_group_shape(&mut self, range:&str) -> Result<&mut GroupShape, &'static str> {
+        if !is_range(range) {
+            return Err("Invalid range.");
+        }
+        let coordinates: Vec<&str> = range.split(':').collect();
+        let start = index_from_coordinate(coordinates[0]);
+        let end = index_from_coordinate(coordinates[coordinates.len() - 1]);
+        let x: usize = (1..start[0]).map(|col| self.get_column_width_emu(col)).sum();
+        let y: usize = (1..start[1]).map(|row| self.get_row_height_emu(row)).sum();
+        let width: usize = (start[0]..=end[0]).map(|col| self.get_column_width_emu(col)).sum();
+        let height: usize = (start[1]..=end[1]).map(|row| self.get_row_height_emu(row)).sum();
+
+        let mut transform2d = Transform2D::default();
+        transform2d.set_x(x);
+        transform2d.set_y(y);
+        transform2d.set_width(width);
+        transform2d.set_height(height);
+        transform2d.set_child_x(0);
+        transform2d.set_child_y(0);
+        transform2d.set_child_width(width);
+        transform2d.set_child_height(height);
+        self.worksheet_drawing.add_group_shape(range, transform2d)
+    }
+
+    /// Add a shape to a group.
+    /// # Arguments
+    /// * `group_id` - Id of the group.
+    /// * `geometry` - Preset geometry of the shape. ex) PresetGeometry::GEOMETRY_RECT
+    /// * `transform2d` - Position and size of the shape in the coordinate space of the group.
+    /// # Return value
+    /// * `Result<&mut Shape, &'static str>` - Err if the group is not found.
+    pub fn add_shape_to_group(&mut self, group_id:&str, geometry:&str, transform2d:Transform2D) -> Result<&mut Shape, &'static str> {
+        self.worksheet_drawing.add_shape_to_group(group_id, geometry, transform2d)
+    }
+
+    /// Add a connector to a group.
+    /// # Arguments
+    /// * `group_id` - Id of the group.
+    /// * `geometry` - Preset geometry of the connector. ex) PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1
+    /// * `transform2d` - Position and size of the connector in the coordinate space of the group.
+    /// # Return value
+    /// * `Result<&mut ConnectionShape, &'static str>` - Err if the group is not found.
+    pub fn add_connection_shape_to_group(&mut self, group_id:&str, geometry:&str, transform2d:Transform2D) -> Result<&mut ConnectionShape, &'static str> {
+        self.worksheet_drawing.add_connection_shape_to_group(group_id, geometry, transform2d)
+    }
+
+    /// Add an image from a file at its natural size.
+    /// PNG, JPEG, GIF, BMP and SVG images are supported.
+    /// # Arguments
+    /// * `path` - Path of the image file.
+    /// * `coordinate` - Cell of the top left corner of the image. ex) "B3"
+    /// # Return value
+    /// * `Result<&mut Picture, &'static str>` - Err if the file cannot be read or is not a supported image, or if the coordinate is invalid.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::spreadsheet::TwoCellAnchor;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(0);
+    /// let _ = worksheet.add_image("./images/sample1.png", "B3");
+    /// match worksheet.add_image("./images/sample1.png", "F3") {
+    ///     Ok(picture) => {let _ = picture.set_edit_as(TwoCellAnchor::EDIT_AS_TWO_CELL);},
+    ///     Err(_) => {},
+    /// }
+    /// ```
+    pub fn add_image<P: AsRef<Path>>(&mut self, path:P, coordinate:&str) -> Result<&mut Picture, &'static str> {
+        let image_data = match std::fs::read(path) {
+            Ok(v) => v,
+            Err(_) => return Err("Image file not found."),
+        };
+        self.add_image_from_bytes(image_data, coordinate)
+    }
+
+    /// Add an image from the content of an image file at its natural size.
+    /// The size comes from the pixel dimensions and resolution recorded in the image.
+    /// The picture moves with the cell it starts in and keeps its size. see `Picture::set_edit_as` to change it.
+    /// SVG images get a transparent PNG fallback for the versions of Excel that cannot show them.
+    /// Identical images share one file in the package.
+    /// # Arguments
+    /// * `image_data` - Content of a PNG, JPEG, GIF, BMP or SVG file.
+    /// * `coordinate` - Cell of the top left corner of the image. ex) "B3"
+    /// # Return value
+    /// * `Result<&mut Picture, &'static str>` - Err if the data is not a supported image, or if the coordinate is invalid.
+    pub fn add_image_from_bytes(&mut self, image_data:Vec<u8>, coordinate:&str) -> Result<&mut Picture, &'static str> {
+        if !is_coordinate(coordinate) {
+            return Err("Invalid coordinate.");
+        }
+        let image_format = match get_image_format(&image_data) {
+            Some(v) => v,
+            None => return Err("Unsupported image format."),
+        };
+        let (width_px, height_px) = match get_image_size(&image_data) {
+            Some(v) => v,
+            None => return Err("Invalid image data."),
+        };
+        let (dpi_x, dpi_y) = get_image_dpi(&image_data);
+        let width = pixel_to_emu(&width_px, &dpi_x);
+        let height = pixel_to_emu(&height_px, &dpi_y);
+
+        let nums = index_from_coordinate(coordinate);
+        let mut one_cell_anchor = OneCellAnchor::default();
+        one_cell_anchor.get_from_marker_mut()
+            .set_col(nums[0] - 1)
+            .set_row(nums[1] - 1);
+        one_cell_anchor.get_extent_mut()
+            .set_cx(width)
+            .set_cy(height);
+
+        let picture = match image_format {
+            IMAGE_FORMAT_SVG => {
+                let fallback_data = SVG_FALLBACK_PNG.to_vec();
+                let fallback_name = format!("image{:x}.{}", md5::compute(&fallback_data), IMAGE_FORMAT_PNG);
+                let mut picture = Picture::new_picture(fallback_name, fallback_data, width, height);
+                let blip = picture.get_blip_fill_mut().get_blip_mut();
+                blip.set_svg_image_name(format!("image{:x}.{}", md5::compute(&image_data), image_format));
+                blip.set_svg_image_data(image_data);
+                picture
+            },
+            _ => {
+                let image_name = format!("image{:x}.{}", md5::compute(&image_data), image_format);
+                Picture::new_picture(image_name, image_data, width, height)
+            },
+        };
+        Ok(self.worksheet_drawing.add_picture(one_cell_anchor, picture))
+    }
+
+    // Where an object anchored at `from_marker` with the size `extent` ends.
+    pub(crate) fn get_to_marker(&self, from_marker:&FromMarker, extent:&Extent) -> ToMarker {
+        let (to_col, to_col_off) = self.get_anchor_end(from_marker.get_col() + 1, from_marker.get_col_off() + extent.get_cx(), |col| self.get_column_width_emu(col));
+        let (to_row, to_row_off) = self.get_anchor_end(from_marker.get_row() + 1, from_marker.get_row_off() + extent.get_cy(), |row| self.get_row_height_emu(row));
+        let mut to_marker = ToMarker::default();
+        to_marker
+            .set_col(to_col - 1)
+            .set_col_off(to_col_off)
+            .set_row(to_row - 1)
+            .set_row_off(to_row_off);
+        to_marker
+    }
+
+    // Column or row (from 1) where a length starting at `start` ends, and the offset into it.
+    fn get_anchor_end<F: Fn(usize) -> usize>(&self, start:usize, length:usize, get_size:F) -> (usize, usize) {
+        let mut index = start;
+        let mut remaining = length;
+        loop {
+            let size = get_size(index);
+            if remaining < size || size == 0 {
+                return (index, remaining);
+            }
+            remaining -= size;
+            index += 1;
+        }
+    }
+
+    fn get_column_width_emu(&self, col:usize) -> usize {
+        let width = self.column_dimensions.iter()
+            .find(|v| v.get_col_num_start() <= &col && &col <= v.get_col_num_end())
+            .map(|v| *v.get_width() as f64);
+        let pixel = match width {
+            Some(v) => (((256f64 * v + (128f64 / 7f64).trunc()) / 256f64) * 7f64).trunc(),
+            None => 64f64,
+        };
+        pixel as usize * 9525
+    }
+
+    fn get_row_height_emu(&self, row:usize) -> usize {
+        let height = match self.get_row_dimension(&row) {
+            Some(v) if v.get_height() > &0f32 => *v.get_height() as f64,
+            _ => 15f64,
+        };
+        (height * 12700f64).round() as usize
+    }
+
     // ************************
     // update Coordinate
     // ************************
@@ -612,6 +1174,16 @@ impl Worksheet {
                 comment.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
             }
 
+            // update threaded comments
+            for threaded_comment in &mut self.threaded_comments {
+                threaded_comment.get_coordinate_mut().adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+
+            // update hyperlink ranges
+            for (range, _) in &mut self.hyperlink_range_collection {
+                range.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+
             // update conditional styles
             for conditional_styles in &mut self.conditional_styles_collection {
                 for range in conditional_styles.get_range_collection_mut() {
@@ -631,6 +1203,9 @@ impl Worksheet {
                 },
                 None => {}
             };
+
+            // update drawing anchors
+            self.worksheet_drawing.adjustment_insert_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
         }
 
         if offset_col_num != &0 || offset_row_num != &0 {
@@ -640,9 +1215,25 @@ impl Worksheet {
                 cell.adjustment_insert_formula_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
             }
 
+            // update hyperlink location
+            for cell in self.get_cell_collection_mut() {
+                cell.adjustment_insert_hyperlink_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+            for (_, hyperlink) in &mut self.hyperlink_range_collection {
+                hyperlink.adjustment_insert_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+
+            // update conditional formula
+            for conditional_styles in &mut self.conditional_styles_collection {
+                for conditional in conditional_styles.get_conditional_collection_mut() {
+                    conditional.adjustment_insert_formula_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+                }
+            }
+
             // update chart
             for graphic_frame in self.worksheet_drawing.get_graphic_frame_collection_mut() {
-                for formula in  graphic_frame.get_graphic_mut().get_graphic_data_mut().get_chart_space_mut().get_chart_mut().get_formula_mut() {
+                for formula in graphic_frame.get_graphic_mut().get_graphic_data_mut().get_chart_space_mut().get_chart_mut().get_formula_mut() {
+                    formula.set_default_sheet_name(&title);
                     formula.get_address_mut().adjustment_insert_coordinate(sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
                 }
             }
@@ -693,6 +1284,22 @@ impl Worksheet {
                 comment.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
             }
 
+            // update threaded comments
+            self.threaded_comments.retain(|x| {
+                !(x.get_coordinate().is_remove(root_col_num, offset_col_num, root_row_num, offset_row_num))
+            });
+            for threaded_comment in &mut self.threaded_comments {
+                threaded_comment.get_coordinate_mut().adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+
+            // update hyperlink ranges
+            self.hyperlink_range_collection.retain(|x| {
+                !(x.0.is_remove(root_col_num, offset_col_num, root_row_num, offset_row_num))
+            });
+            for (range, _) in &mut self.hyperlink_range_collection {
+                range.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+
             // update conditional styles
             for conditional_styles in &mut self.conditional_styles_collection {
                 conditional_styles.get_range_collection_mut().retain(|x| {
@@ -732,6 +1339,9 @@ impl Worksheet {
                 },
                 None => {}
             };
+
+            // update drawing anchors
+            self.worksheet_drawing.adjustment_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num);
         }
 
         if offset_col_num != &0 || offset_row_num != &0 {
@@ -741,15 +1351,56 @@ impl Worksheet {
                 cell.adjustment_remove_formula_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
             }
 
+            // update hyperlink location
+            for cell in self.get_cell_collection_mut() {
+                cell.adjustment_remove_hyperlink_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+            for (_, hyperlink) in &mut self.hyperlink_range_collection {
+                hyperlink.adjustment_remove_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+            }
+
+            // update conditional formula
+            for conditional_styles in &mut self.conditional_styles_collection {
+                for conditional in conditional_styles.get_conditional_collection_mut() {
+                    conditional.adjustment_remove_formula_coordinate(&title, sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
+                }
+            }
+
             // update chart
             for graphic_frame in self.worksheet_drawing.get_graphic_frame_collection_mut() {
                 for formula in graphic_frame.get_graphic_mut().get_graphic_data_mut().get_chart_space_mut().get_chart_mut().get_formula_mut() {
+                    formula.set_default_sheet_name(&title);
                     formula.get_address_mut().adjustment_remove_coordinate(sheet_name, root_col_num, offset_col_num, root_row_num, offset_row_num);
                 }
             }
         }
     }
 
+    pub(crate) fn adjustment_rename_sheet(&mut self, old_sheet_name:&str, new_sheet_name:&str) {
+        // update cell formula and hyperlink location
+        for cell in self.get_cell_collection_mut() {
+            cell.adjustment_rename_formula_sheet(old_sheet_name, new_sheet_name);
+            cell.adjustment_rename_hyperlink_sheet(old_sheet_name, new_sheet_name);
+        }
+        for (_, hyperlink) in &mut self.hyperlink_range_collection {
+            hyperlink.adjustment_rename_sheet(old_sheet_name, new_sheet_name);
+        }
+
+        // update conditional formula
+        for conditional_styles in &mut self.conditional_styles_collection {
+            for conditional in conditional_styles.get_conditional_collection_mut() {
+                conditional.adjustment_rename_formula_sheet(old_sheet_name, new_sheet_name);
+            }
+        }
+
+        // update chart
+        for graphic_frame in self.worksheet_drawing.get_graphic_frame_collection_mut() {
+            for formula in graphic_frame.get_graphic_mut().get_graphic_data_mut().get_chart_space_mut().get_chart_mut().get_formula_mut() {
+                formula.get_address_mut().adjustment_rename_sheet(old_sheet_name, new_sheet_name);
+            }
+        }
+    }
+
     pub(crate) fn get_coordinates(&self)-> Vec<String> {
         let mut result:Vec<String> = Vec::new();
         for cell in self.cell_collection.get_collection() {
@@ -790,6 +1441,17 @@ impl Worksheet {
     pub(crate) fn set_active_cell<S: Into<String>>(&mut self, value:S) {
         self.active_cell = value.into();
     }
+    /// Date system of the workbook, kept in step by the spreadsheet.
+    pub(crate) fn set_date1904(&mut self, value:bool) {
+        self.date1904 = value;
+    }
+    pub(crate) fn get_calendar(&self) -> &str {
+        if self.date1904 {
+            CALENDAR_MAC_1904
+        } else {
+            CALENDAR_WINDOWS_1900
+        }
+    }
     pub fn get_sheet_id(&self) -> &String {
         &self.sheet_id
     }
@@ -12,38 +12,41 @@ use std::path::Path;
 
 use ::structs::Color;
 
-pub(crate) fn write_to_file(path: &Path, dir: &TempDir) -> Result<(), io::Error> {
+pub(crate) fn write_to_file(path: &Path, dir: &TempDir, is_deterministic: &bool) -> Result<(), io::Error> {
     let file = File::create(&path)?;
     let mut zip = zip::ZipWriter::new(file);
-    let options = FileOptions::default()
+    let mut options = FileOptions::default()
         .compression_method(zip::CompressionMethod::Deflated)
         .unix_permissions(0o644);
-    let walkdir = WalkDir::new(dir.path());
-    let it = walkdir.into_iter();
+    if *is_deterministic {
+        // 1980-01-01 00:00:00, the earliest time a zip entry can hold.
+        options = options.last_modified_time(zip::DateTime::default());
+    }
 
-    for dent in it.filter_map(|e| e.ok()) {
+    // [Content_Types].xml first, then the other parts by name.
+    let mut names: Vec<String> = Vec::new();
+    for dent in WalkDir::new(dir.path()).into_iter().filter_map(|e| e.ok()) {
         let path = dent.path();
-        let name = path
-            .strip_prefix(Path::new(dir.path()))
-            .unwrap()
-            .to_str()
-            .unwrap();
-
         if path.is_file() {
-            //println!("adding {:?} as {:?} ...", path, name);
-            let _= zip.start_file(name, options);
-            let mut f = File::open(path)?;
-            let mut buffer = Vec::new();
-            f.read_to_end(&mut buffer)?;
-            zip.write_all(&*buffer)?;
-        }
-        /*
-         else {
-            let mut dir_name = String::from(name);
-            dir_name.push('/');
-            let _ = zip.add_directory(dir_name.as_str(), FileOptions::default());
+            let name = path
+                .strip_prefix(Path::new(dir.path()))
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .replace('\\', "/");
+            names.push(name);
         }
-        */
+    }
+    names.sort_by(|a, b| {
+        (a != "[Content_Types].xml", a).cmp(&(b != "[Content_Types].xml", b))
+    });
+
+    for name in &names {
+        let _= zip.start_file(name.as_str(), options);
+        let mut f = File::open(dir.path().join(name))?;
+        let mut buffer = Vec::new();
+        f.read_to_end(&mut buffer)?;
+        zip.write_all(&*buffer)?;
     }
 
     let _= zip.finish();
@@ -10,6 +10,7 @@ mod chart;
 mod content_types;
 mod doc_props_app;
 mod doc_props_core;
+mod doc_props_custom;
 mod workbook;
 mod worksheet;
 mod rels;
@@ -22,6 +23,8 @@ mod drawing;
 mod drawing_rels;
 mod vba_project_bin;
 mod comment;
+mod threaded_comment;
+mod person;
 mod vml_drawing;
 mod media;
 
@@ -57,6 +60,36 @@ impl From<FromUtf8Error> for XlsxError {
     }
 }
 
+/// Options for writing a spreadsheet file.
+#[derive(Default, Debug, Clone)]
+pub struct WriterOption {
+    use_inline_string: bool,
+    is_deterministic: bool,
+}
+impl WriterOption {
+    pub fn get_use_inline_string(&self) -> &bool {
+        &self.use_inline_string
+    }
+
+    /// Write strings inline in the cells instead of in the shared string table.
+    /// Keeps memory low for large, mostly unique data at the cost of file size.
+    pub fn set_use_inline_string(&mut self, value:bool) -> &mut WriterOption {
+        self.use_inline_string = value;
+        self
+    }
+
+    pub fn get_is_deterministic(&self) -> &bool {
+        &self.is_deterministic
+    }
+
+    /// Write byte-identical files for identical spreadsheets.
+    /// Parts are stored in a fixed order and with a fixed timestamp.
+    pub fn set_is_deterministic(&mut self, value:bool) -> &mut WriterOption {
+        self.is_deterministic = value;
+        self
+    }
+}
+
 /// write spreadsheet file.
 /// # Arguments
 /// * `spreadsheet` - Spreadsheet structs object.
@@ -70,6 +103,25 @@ impl From<FromUtf8Error> for XlsxError {
 /// let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
 /// ```
 pub fn write(spreadsheet: &Spreadsheet, path: &Path) -> Result<(), XlsxError> {
+    write_with_option(spreadsheet, path, &WriterOption::default())
+}
+
+/// write spreadsheet file with options.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// * `option` - writer options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message. 
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let mut option = umya_spreadsheet::writer::xlsx::WriterOption::default();
+/// option.set_use_inline_string(true);
+/// let path = std::path::Path::new("C:/spread_test_data/zzz_inline.xlsx");
+/// let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option);
+/// ```
+pub fn write_with_option(spreadsheet: &Spreadsheet, path: &Path, option: &WriterOption) -> Result<(), XlsxError> {
     let dir = TempDir::new("shreadsheet")?;
 
     // Add Content_Types
@@ -81,6 +133,9 @@ pub fn write(spreadsheet: &Spreadsheet, path: &Path) -> Result<(), XlsxError> {
     // Add docProps Core
     let _= doc_props_core::write(spreadsheet, &dir, "docProps", "core.xml");
 
+    // Add docProps Custom
+    let _= doc_props_custom::write(spreadsheet, &dir, "docProps", "custom.xml");
+
     // Add vbaProject.bin
     let _= vba_project_bin::write(spreadsheet, &dir, "xl", "vbaProject.bin");
 
@@ -95,15 +150,20 @@ pub fn write(spreadsheet: &Spreadsheet, path: &Path) -> Result<(), XlsxError> {
     let _ = workbook::write(spreadsheet, &dir, "xl", "workbook.xml");
 
     // Add SharedStrings
-    let shared = shared_strings::write(spreadsheet, &dir).unwrap();
+    let shared = shared_strings::write(spreadsheet, option.get_use_inline_string(), &dir).unwrap();
 
     // Add Styles
     let _ = styles::write(spreadsheet, &dir).unwrap();
 
+    // Add persons of threaded comments
+    let _ = person::write(spreadsheet, &dir);
+
     // Add worksheets and relationships (drawings, ...)
     let mut chart_id = 1;
     let mut drawing_id = 1;
     let mut comment_id = 1;
+    let mut threaded_comment_id = 1;
+    let mut media_names:Vec<String> = Vec::new();
     for i in 0..spreadsheet.get_sheet_count() {
         let p_worksheet_id:&str = &(i+1).to_string();
         let worksheet = &spreadsheet.get_sheet_collection()[i];
@@ -116,16 +176,19 @@ pub fn write(spreadsheet: &Spreadsheet, path: &Path) -> Result<(), XlsxError> {
             &(i+1),
             &is_selected,
             has_macros,
+            spreadsheet.get_calendar(),
             all_cell_xf_list,
             conditonal_style_list,
             shared.clone(),
+            option.get_use_inline_string(),
             &dir
         );
-        let _ = worksheet_rels::write(worksheet, p_worksheet_id, &drawing_id, &comment_id,  &dir);
+        let _ = worksheet_rels::write(worksheet, p_worksheet_id, &drawing_id, &comment_id, &threaded_comment_id, &dir);
         let _ = drawing::write(worksheet, &drawing_id, &dir);
         let _ = drawing_rels::write(worksheet, &drawing_id, &chart_id, &dir);
         let _ = comment::write(worksheet, &comment_id,  &dir);
         let _ = vml_drawing::write(worksheet, &comment_id,  &dir);
+        let _ = threaded_comment::write(worksheet, &threaded_comment_id, &dir);
 
         if worksheet.has_drawing_object() {
             drawing_id += 1;
@@ -135,18 +198,40 @@ pub fn write(spreadsheet: &Spreadsheet, path: &Path) -> Result<(), XlsxError> {
             comment_id += 1;
         }
 
+        if worksheet.get_threaded_comments().len() > 0 {
+            threaded_comment_id += 1;
+        }
+
         for graphic_frame in worksheet.get_worksheet_drawing().get_graphic_frame_collection(){
-            let chart_space = graphic_frame.get_graphic().get_graphic_data().get_chart_space();
-            let _ = chart::write(chart_space, &chart_id, &dir);
+            let mut chart_space = graphic_frame.get_graphic().get_graphic_data().get_chart_space().clone();
+            // only empty caches are filled; Spreadsheet::update_chart_caches refreshes the rest
+            chart_space.update_caches(spreadsheet, worksheet.get_title(), &false);
+            let _ = chart::write(&chart_space, &chart_id, &dir);
             chart_id += 1;
         }
 
         for picture in worksheet.get_worksheet_drawing().get_picture_collection(){
-            let _ = media::write(picture, &dir, "xl/media");
+            // pictures of the same image share one file
+            let blip = picture.get_blip_fill().get_blip();
+            let images = vec![
+                (blip.get_image_name(), blip.get_image_data()),
+                (blip.get_svg_image_name(), blip.get_svg_image_data()),
+            ];
+            for (image_name, image_data) in images {
+                let image_data = match image_data {
+                    Some(v) => v,
+                    None => continue,
+                };
+                if media_names.contains(image_name) {
+                    continue;
+                }
+                media_names.push(image_name.clone());
+                let _ = media::write(image_name, image_data, &dir, "xl/media");
+            }
         }
     }
 
-    driver::write_to_file(path, &dir)?;
+    driver::write_to_file(path, &dir, option.get_is_deterministic())?;
     dir.close()?;
     Ok(())
 }
@@ -5,6 +5,7 @@ use tempdir::TempDir;
 use onig::*;
 use ::structs::Worksheet;
 use ::structs::Font;
+use ::structs::Comment;
 use super::driver::*;
 use super::XlsxError;
 
@@ -15,9 +16,10 @@ pub(crate) fn write(
     comment_id: &usize,
     dir: &TempDir
 ) -> Result<(), XlsxError> {
-    if worksheet.get_comments().len() == 0 {
+    if !worksheet.has_comments() {
         return Ok(());
     }
+    let comments = worksheet.get_comments_with_threaded_fallback();
 
     let file_name = format!("comments{}.xml", comment_id);
 
@@ -32,7 +34,7 @@ pub(crate) fn write(
     ], false);
 
     // authors
-    let authors = get_authors(worksheet);
+    let authors = get_authors(&comments);
     write_start_tag(&mut writer, "authors", vec![], false);
     for author in &authors {
         write_start_tag(&mut writer, "author", vec![], false);
@@ -43,7 +45,7 @@ pub(crate) fn write(
 
     // commentList
     write_start_tag(&mut writer, "commentList", vec![], false);
-    for comment in worksheet.get_comments() {
+    for comment in &comments {
         let coordinate = comment.get_coordinate().get_coordinate();
 
         // comment
@@ -90,8 +92,8 @@ pub(crate) fn write(
                     // color
                     write_color(&mut writer, &font.get_color(), "color");
 
-                    // name
-                    write_start_tag(&mut writer, "name", vec![
+                    // rFont
+                    write_start_tag(&mut writer, "rFont", vec![
                         ("val", font.get_name()),
                     ], true);
 
@@ -139,9 +141,9 @@ pub(crate) fn write(
     Ok(())
 }
 
-fn get_authors(worksheet: &Worksheet) -> Vec<String> {
+fn get_authors(comments: &Vec<Comment>) -> Vec<String> {
     let mut authors: Vec<String> = Vec::new();
-    for comment in worksheet.get_comments() {
+    for comment in comments {
         let mut is_match = false;
         for author in &authors {
             if comment.get_author() == author {
@@ -7,6 +7,18 @@ use ::structs::Spreadsheet;
 use super::driver::*;
 use super::XlsxError;
 
+const IMAGE_CONTENT_TYPES: &'static [(&'static str, &'static str)] = &[
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("tiff", "image/tiff"),
+    ("gif", "image/gif"),
+    ("bmp", "image/bmp"),
+    ("svg", "image/svg+xml"),
+    ("emf", "image/x-emf"),
+    ("wmf", "image/x-wmf"),
+];
+
 pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, file_name: &str) -> Result<(), XlsxError> {
     let mut writer = Writer::new(Cursor::new(Vec::new()));
     // XML header
@@ -44,68 +56,23 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, file_name: &str) -
         ], true);
     }
 
-    // Default png
-    let mut writed = false;
-    for work_sheet in spreadsheet.get_sheet_collection() {
-        for picture in work_sheet.get_worksheet_drawing().get_picture_collection() {
-            if picture.get_blip_fill().get_blip().is_png() {
-                write_start_tag(&mut writer, "Default", vec![
-                    ("Extension", "png"),
-                    ("ContentType", "image/png"),
-                ], true);
-                writed = true;
-                break;
-            }
-        }
-        if writed {break};
-    }
-
-    // Default jpg
-    let mut writed = false;
-    for work_sheet in spreadsheet.get_sheet_collection() {
-        for picture in work_sheet.get_worksheet_drawing().get_picture_collection() {
-            if picture.get_blip_fill().get_blip().is_jpg() {
-                write_start_tag(&mut writer, "Default", vec![
-                    ("Extension", "jpg"),
-                    ("ContentType", "image/jpeg"),
-                ], true);
-                writed = true;
-                break;
-            }
-        }
-        if writed {break};
-    }
-
-    // Default jpeg
-    let mut writed = false;
-    for work_sheet in spreadsheet.get_sheet_collection() {
-        for picture in work_sheet.get_worksheet_drawing().get_picture_collection() {
-            if picture.get_blip_fill().get_blip().is_jpeg() {
-                write_start_tag(&mut writer, "Default", vec![
-                    ("Extension", "jpeg"),
-                    ("ContentType", "image/jpeg"),
-                ], true);
-                writed = true;
-                break;
+    // Default image
+    for (extension, content_type) in IMAGE_CONTENT_TYPES {
+        let mut writed = false;
+        for work_sheet in spreadsheet.get_sheet_collection() {
+            for picture in work_sheet.get_worksheet_drawing().get_picture_collection() {
+                let blip = picture.get_blip_fill().get_blip();
+                if &blip.get_extension() == extension || (blip.has_svg_image() && &blip.get_svg_extension() == extension) {
+                    write_start_tag(&mut writer, "Default", vec![
+                        ("Extension", extension),
+                        ("ContentType", content_type),
+                    ], true);
+                    writed = true;
+                    break;
+                }
             }
+            if writed {break};
         }
-        if writed {break};
-    }
-
-    // Default tiff
-    let mut writed = false;
-    for work_sheet in spreadsheet.get_sheet_collection() {
-        for picture in work_sheet.get_worksheet_drawing().get_picture_collection() {
-            if picture.get_blip_fill().get_blip().is_tiff() {
-                write_start_tag(&mut writer, "Default", vec![
-                    ("Extension", "tiff"),
-                    ("ContentType", "image/tiff"),
-                ], true);
-                writed = true;
-                break;
-            }
-        }
-        if writed {break};
     }
 
     // Override workbook
@@ -127,16 +94,37 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, file_name: &str) -
     }
 
     // Override comments
-    for i in 0..spreadsheet.get_sheet_count() {
-        let worksheet = &spreadsheet.get_sheet_collection()[i];
-        if worksheet.get_comments().len() > 0 {
+    let mut comment_id = 1;
+    for worksheet in spreadsheet.get_sheet_collection() {
+        if worksheet.has_comments() {
             write_start_tag(&mut writer, "Override", vec![
-                ("PartName", format!("/xl/comments{}.xml", (i+1).to_string().as_str()).as_str()),
+                ("PartName", format!("/xl/comments{}.xml", comment_id.to_string().as_str()).as_str()),
                 ("ContentType", "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml"),
             ], true);
+            comment_id += 1;
         }
     }
 
+    // Override threaded comments
+    let mut threaded_comment_id = 1;
+    for worksheet in spreadsheet.get_sheet_collection() {
+        if worksheet.get_threaded_comments().len() > 0 {
+            write_start_tag(&mut writer, "Override", vec![
+                ("PartName", format!("/xl/threadedComments/threadedComment{}.xml", threaded_comment_id.to_string().as_str()).as_str()),
+                ("ContentType", "application/vnd.ms-excel.threadedcomments+xml"),
+            ], true);
+            threaded_comment_id += 1;
+        }
+    }
+
+    // Override persons
+    if spreadsheet.get_person_collection().len() > 0 {
+        write_start_tag(&mut writer, "Override", vec![
+            ("PartName", "/xl/persons/person.xml"),
+            ("ContentType", "application/vnd.ms-excel.person+xml"),
+        ], true);
+    }
+
     // Override theme
     write_start_tag(&mut writer, "Override", vec![
         ("PartName", "/xl/theme/theme1.xml"),
@@ -200,6 +188,14 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, file_name: &str) -
         ("ContentType", "application/vnd.openxmlformats-officedocument.extended-properties+xml"),
     ], true);
 
+    // Override docProps/custom
+    if spreadsheet.get_properties().get_custom_properties().len() > 0 {
+        write_start_tag(&mut writer, "Override", vec![
+            ("PartName", "/docProps/custom.xml"),
+            ("ContentType", "application/vnd.openxmlformats-officedocument.custom-properties+xml"),
+        ], true);
+    }
+
     write_end_tag(&mut writer, "Types");
     let _ = make_file_from_writer(format!("{}",file_name).as_str(), dir, writer, None).unwrap();
     Ok(())
@@ -4,10 +4,15 @@ use std::io::Cursor;
 use tempdir::TempDir;
 
 use ::structs::Spreadsheet;
+use ::helper::formula::*;
 use super::driver::*;
 use super::XlsxError;
 
 pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, file_name: &str) -> Result<(), XlsxError> {
+    let properties = spreadsheet.get_properties();
+    let sheet_titles: Vec<String> = spreadsheet.get_sheet_collection().iter().map(|x| x.get_title().to_string()).collect();
+    let named_range_titles = get_named_range_titles(spreadsheet);
+
     let mut writer = Writer::new(Cursor::new(Vec::new()));
     // XML header
     let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
@@ -20,48 +25,41 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     ], false);
 
     // Application
-    write_start_tag(&mut writer, "Application", vec![], false);
-    write_text_node(&mut writer, "Microsoft Excel");
-    write_end_tag(&mut writer, "Application");
+    write_text_element(&mut writer, "Application", properties.get_application());
 
     // DocSecurity
-    write_start_tag(&mut writer, "DocSecurity", vec![], false);
-    write_text_node(&mut writer, "0");
-    write_end_tag(&mut writer, "DocSecurity");
+    write_text_element(&mut writer, "DocSecurity", properties.get_doc_security().to_string().as_str());
 
     // ScaleCrop
-    write_start_tag(&mut writer, "ScaleCrop", vec![], false);
-    write_text_node(&mut writer, "false");
-    write_end_tag(&mut writer, "ScaleCrop");
+    write_text_element(&mut writer, "ScaleCrop", properties.get_scale_crop().to_string().as_str());
 
     // HeadingPairs
+    let mut heading_pairs: Vec<(&str, usize)> = Vec::new();
+    if sheet_titles.len() > 0 {
+        heading_pairs.push(("Worksheets", sheet_titles.len()));
+    }
+    if named_range_titles.len() > 0 {
+        heading_pairs.push(("Named Ranges", named_range_titles.len()));
+    }
     write_start_tag(&mut writer, "HeadingPairs", vec![], false);
 
     // vt:vector
     write_start_tag(&mut writer, "vt:vector", vec![
-        ("size", "2"),
+        ("size", (heading_pairs.len() * 2).to_string().as_str()),
         ("baseType", "variant"),
     ], false);
 
-    // vt:variant
-    write_start_tag(&mut writer, "vt:variant", vec![], false);
-
-    // vt:i4
-    write_start_tag(&mut writer, "vt:lpstr", vec![], false);
-    write_text_node(&mut writer, "Worksheets");
-    write_end_tag(&mut writer, "vt:lpstr");
-
-    write_end_tag(&mut writer, "vt:variant");
-
-    // vt:variant
-    write_start_tag(&mut writer, "vt:variant", vec![], false);
+    for (heading, count) in &heading_pairs {
+        // vt:variant
+        write_start_tag(&mut writer, "vt:variant", vec![], false);
+        write_text_element(&mut writer, "vt:lpstr", heading);
+        write_end_tag(&mut writer, "vt:variant");
 
-    // vt:i4
-    write_start_tag(&mut writer, "vt:i4", vec![], false);
-    write_text_node(&mut writer, spreadsheet.get_sheet_count().to_string().as_str());
-    write_end_tag(&mut writer, "vt:i4");
-
-    write_end_tag(&mut writer, "vt:variant");
+        // vt:variant
+        write_start_tag(&mut writer, "vt:variant", vec![], false);
+        write_text_element(&mut writer, "vt:i4", count.to_string().as_str());
+        write_end_tag(&mut writer, "vt:variant");
+    }
 
     write_end_tag(&mut writer, "vt:vector");
 
@@ -72,15 +70,13 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
 
     // vt:vector
     write_start_tag(&mut writer, "vt:vector", vec![
-        ("size", spreadsheet.get_sheet_count().to_string().as_str()),
+        ("size", (sheet_titles.len() + named_range_titles.len()).to_string().as_str()),
         ("baseType", "lpstr"),
     ], false);
 
-    for workseet in spreadsheet.get_sheet_collection(){
+    for title in sheet_titles.iter().chain(named_range_titles.iter()) {
         // vt:lpstr
-        write_start_tag(&mut writer, "vt:lpstr", vec![], false);
-        write_text_node(&mut writer, workseet.get_title());
-        write_end_tag(&mut writer, "vt:lpstr");
+        write_text_element(&mut writer, "vt:lpstr", title);
     }
 
     write_end_tag(&mut writer, "vt:vector");
@@ -88,36 +84,58 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     write_end_tag(&mut writer, "TitlesOfParts");
 
     // Manager
-    write_start_tag(&mut writer, "Manager", vec![], false);
-    write_text_node(&mut writer, spreadsheet.get_properties().get_manager());
-    write_end_tag(&mut writer, "Manager");
+    write_text_element(&mut writer, "Manager", properties.get_manager());
 
     // Company
-    write_start_tag(&mut writer, "Company", vec![], false);
-    write_text_node(&mut writer, spreadsheet.get_properties().get_company());
-    write_end_tag(&mut writer, "Company");
+    write_text_element(&mut writer, "Company", properties.get_company());
 
     // LinksUpToDate
-    write_start_tag(&mut writer, "LinksUpToDate", vec![], false);
-    write_text_node(&mut writer, "false");
-    write_end_tag(&mut writer, "LinksUpToDate");
+    write_text_element(&mut writer, "LinksUpToDate", properties.get_links_up_to_date().to_string().as_str());
 
     // SharedDoc
-    write_start_tag(&mut writer, "SharedDoc", vec![], false);
-    write_text_node(&mut writer, "false");
-    write_end_tag(&mut writer, "SharedDoc");
+    write_text_element(&mut writer, "SharedDoc", properties.get_shared_doc().to_string().as_str());
+
+    // HyperlinkBase
+    if properties.get_hyperlink_base() != "" {
+        write_text_element(&mut writer, "HyperlinkBase", properties.get_hyperlink_base());
+    }
 
     // HyperlinksChanged
-    write_start_tag(&mut writer, "HyperlinksChanged", vec![], false);
-    write_text_node(&mut writer, "false");
-    write_end_tag(&mut writer, "HyperlinksChanged");
+    write_text_element(&mut writer, "HyperlinksChanged", properties.get_hyperlinks_changed().to_string().as_str());
 
     // AppVersion
-    write_start_tag(&mut writer, "AppVersion", vec![], false);
-    write_text_node(&mut writer, "14.0300");
-    write_end_tag(&mut writer, "AppVersion");
+    write_text_element(&mut writer, "AppVersion", properties.get_app_version());
 
     write_end_tag(&mut writer, "Properties");
     let _ = make_file_from_writer(format!("{}/{}",sub_dir,file_name).as_str(), dir, writer, Some(sub_dir)).unwrap();
     Ok(())
-}
\ No newline at end of file
+}
+
+fn write_text_element(writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str, value: &str) {
+    write_start_tag(writer, tag_name, vec![], false);
+    write_text_node(writer, value);
+    write_end_tag(writer, tag_name);
+}
+
+// Excel lists defined names under "Named Ranges", local names prefixed with their sheet.
+// Built-in names other than the print settings are left out.
+fn get_named_range_titles(spreadsheet: &Spreadsheet) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for defined_name in spreadsheet.get_defined_names() {
+        let name = match defined_name.get_name() {
+            "_xlnm.Print_Area" => "Print_Area",
+            "_xlnm.Print_Titles" => "Print_Titles",
+            v if v.starts_with("_xlnm.") => continue,
+            v => v,
+        };
+        let title = match defined_name.get_local_sheet_id() {
+            Some(v) => match spreadsheet.get_sheet(*v) {
+                Ok(worksheet) => format!("{}!{}", quote_sheet_name(worksheet.get_title()), name),
+                Err(_) => continue,
+            },
+            None => name.to_string(),
+        };
+        result.push(title);
+    }
+    result
+}
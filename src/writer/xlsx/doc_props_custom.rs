@@ -0,0 +1,62 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io::Cursor;
+use tempdir::TempDir;
+
+use ::structs::Spreadsheet;
+use ::structs::CustomProperty;
+use ::structs::CustomPropertyValue;
+use super::driver::*;
+use super::XlsxError;
+
+pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, file_name: &str) -> Result<(), XlsxError> {
+    if spreadsheet.get_properties().get_custom_properties().len() == 0 {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // Properties
+    write_start_tag(&mut writer, "Properties", vec![
+        ("xmlns", "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties"),
+        ("xmlns:vt", "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"),
+    ], false);
+
+    // property ids start at 2
+    let mut pid = 2;
+    for custom_property in spreadsheet.get_properties().get_custom_properties() {
+        // property
+        write_start_tag(&mut writer, "property", vec![
+            ("fmtid", CustomProperty::FMTID),
+            ("pid", pid.to_string().as_str()),
+            ("name", custom_property.get_name()),
+        ], false);
+
+        let (tag_name, value) = match custom_property.get_value() {
+            CustomPropertyValue::Text(v) => ("vt:lpwstr", v.clone()),
+            CustomPropertyValue::Integer(v) => {
+                if *v >= i32::min_value() as i64 && *v <= i32::max_value() as i64 {
+                    ("vt:i4", v.to_string())
+                } else {
+                    ("vt:i8", v.to_string())
+                }
+            },
+            CustomPropertyValue::Number(v) => ("vt:r8", v.to_string()),
+            CustomPropertyValue::Bool(v) => ("vt:bool", v.to_string()),
+            CustomPropertyValue::Date(v) => ("vt:filetime", v.clone()),
+        };
+        write_start_tag(&mut writer, tag_name, vec![], false);
+        write_text_node(&mut writer, value);
+        write_end_tag(&mut writer, tag_name);
+
+        write_end_tag(&mut writer, "property");
+        pid += 1;
+    }
+
+    write_end_tag(&mut writer, "Properties");
+    let _ = make_file_from_writer(format!("{}/{}",sub_dir,file_name).as_str(), dir, writer, Some(sub_dir)).unwrap();
+    Ok(())
+}
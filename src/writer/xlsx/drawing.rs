@@ -26,7 +26,7 @@ pub(crate) fn write(
     let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
     write_new_line(&mut writer);
 
-    worksheet.get_worksheet_drawing().write_to(&mut writer);
+    worksheet.get_worksheet_drawing().write_to(&mut writer, &|from_marker, extent| worksheet.get_to_marker(from_marker, extent));
     
     let _ = make_file_from_writer(format!("{}/{}", SUB_DIR, file_name).as_str(), dir, writer, Some(SUB_DIR)).unwrap();
     Ok(())
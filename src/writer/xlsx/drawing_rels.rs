@@ -4,6 +4,10 @@ use std::io::Cursor;
 use tempdir::TempDir;
 
 use ::structs::Worksheet;
+use ::structs::drawing::spreadsheet::GraphicFrame;
+use ::structs::drawing::spreadsheet::Picture;
+use ::structs::drawing::spreadsheet::GroupShape;
+use ::structs::drawing::spreadsheet::GroupShapeChild;
 use super::driver::*;
 use super::XlsxError;
 
@@ -31,35 +35,8 @@ pub(crate) fn write(
 
     let mut r_id = 1;
     let mut chart_id = chart_start_id.clone();
-    let two_cell_anchor_collection = worksheet.get_worksheet_drawing().get_two_cell_anchor_collection();
-    for two_cell_anchor in two_cell_anchor_collection {
-        match two_cell_anchor.get_graphic_frame() {
-            Some(_) => {
-                is_write = write_relationship(
-                    &mut writer,
-                    &r_id,
-                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
-                    format!("../charts/chart{}.xml", chart_id).as_str(),
-                    ""
-                );
-                r_id += 1;
-                chart_id += 1;
-            },
-            None => {}
-        }
-        match two_cell_anchor.get_picture() {
-            Some(picture) => {
-                is_write = write_relationship(
-                    &mut writer,
-                    &r_id,
-                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
-                    format!("../media/{}", picture.get_blip_fill().get_blip().get_image_name()).as_str(),
-                    ""
-                );
-                r_id += 1;
-            },
-            None => {}
-        }
+    for anchor in worksheet.get_worksheet_drawing().get_anchor_collection() {
+        write_anchor_relationships(&mut writer, anchor.get_graphic_frame(), anchor.get_picture(), anchor.get_group_shape(), &mut r_id, &mut chart_id, &mut is_write);
     }
 
     write_end_tag(&mut writer, "Relationships");
@@ -70,6 +47,82 @@ pub(crate) fn write(
     Ok(())
 }
 
+fn write_anchor_relationships(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    graphic_frame: &Option<GraphicFrame>,
+    picture: &Option<Picture>,
+    group_shape: &Option<GroupShape>,
+    r_id: &mut i32,
+    chart_id: &mut usize,
+    is_write: &mut bool
+) {
+    match graphic_frame {
+        Some(_) => write_chart_relationship(writer, r_id, chart_id, is_write),
+        None => {}
+    }
+    match picture {
+        Some(picture) => write_image_relationship(writer, picture, r_id, is_write),
+        None => {}
+    }
+    match group_shape {
+        Some(group_shape) => write_group_relationships(writer, group_shape, r_id, chart_id, is_write),
+        None => {}
+    }
+}
+
+// In the order GroupShape::write_to gives the ids.
+fn write_group_relationships(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    group_shape: &GroupShape,
+    r_id: &mut i32,
+    chart_id: &mut usize,
+    is_write: &mut bool
+) {
+    for child in group_shape.get_child_collection() {
+        match child {
+            GroupShapeChild::GraphicFrame(_) => write_chart_relationship(writer, r_id, chart_id, is_write),
+            GroupShapeChild::Picture(picture) => write_image_relationship(writer, picture, r_id, is_write),
+            GroupShapeChild::GroupShape(child) => write_group_relationships(writer, child, r_id, chart_id, is_write),
+            _ => {}
+        }
+    }
+}
+
+fn write_chart_relationship(writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32, chart_id: &mut usize, is_write: &mut bool) {
+    *is_write = write_relationship(
+        writer,
+        r_id,
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
+        format!("../charts/chart{}.xml", chart_id).as_str(),
+        ""
+    );
+    *r_id += 1;
+    *chart_id += 1;
+}
+
+// The PNG fallback first, then the SVG image, as Blip::write_to gives the ids.
+fn write_image_relationship(writer: &mut Writer<Cursor<Vec<u8>>>, picture: &Picture, r_id: &mut i32, is_write: &mut bool) {
+    let blip = picture.get_blip_fill().get_blip();
+    *is_write = write_relationship(
+        writer,
+        r_id,
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+        format!("../media/{}", blip.get_image_name()).as_str(),
+        ""
+    );
+    *r_id += 1;
+    if blip.has_svg_image() {
+        write_relationship(
+            writer,
+            r_id,
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+            format!("../media/{}", blip.get_svg_image_name()).as_str(),
+            ""
+        );
+        *r_id += 1;
+    }
+}
+
 fn write_relationship(writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &i32, p_type: &str, p_target: &str, p_target_mode: &str) -> bool
 {
     let tag_name = "Relationship";
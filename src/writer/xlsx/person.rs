@@ -0,0 +1,41 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io::Cursor;
+use tempdir::TempDir;
+use ::structs::Spreadsheet;
+use super::driver::*;
+use super::XlsxError;
+
+const SUB_DIR: &'static str = "xl/persons";
+const FILE_NAME: &'static str = "person.xml";
+
+pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir) -> Result<(), XlsxError> {
+    if spreadsheet.get_person_collection().len() == 0 {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // personList
+    write_start_tag(&mut writer, "personList", vec![
+        ("xmlns", "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments"),
+        ("xmlns:x", "http://schemas.openxmlformats.org/spreadsheetml/2006/main"),
+    ], false);
+
+    for person in spreadsheet.get_person_collection() {
+        // person
+        write_start_tag(&mut writer, "person", vec![
+            ("displayName", person.get_display_name()),
+            ("id", person.get_id()),
+            ("userId", person.get_user_id()),
+            ("providerId", person.get_provider_id()),
+        ], true);
+    }
+    write_end_tag(&mut writer, "personList");
+
+    let _ = make_file_from_writer(format!("{}/{}", SUB_DIR, FILE_NAME).as_str(), dir, writer, Some(SUB_DIR)).unwrap();
+    Ok(())
+}
@@ -19,15 +19,15 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     ], false);
 
     // relationship docProps/custom.xml
-    //if spreadsheet.get_properties().get_custom_properties().len() > 0 {
-    //    write_relationship(
-    //        &mut writer,
-    //        "4",
-    //        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
-    //        "docProps/custom.xml",
-    //        ""
-    //    );
-    //}
+    if spreadsheet.get_properties().get_custom_properties().len() > 0 {
+        write_relationship(
+            &mut writer,
+            "4",
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
+            "docProps/custom.xml",
+            ""
+        );
+    }
 
     // relationship docProps/app.xml
     write_relationship(
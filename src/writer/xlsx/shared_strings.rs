@@ -8,29 +8,41 @@ use super::XlsxError;
 use onig::*;
 use ::structs::Spreadsheet;
 use ::structs::RichText;
+use ::structs::Cell;
+use ::structs::CellValue;
 use ::structs::Font;
 use super::driver::*;
 
 const SHARED_STRINGS: &'static str = "xl/sharedStrings.xml";
 
-pub fn write(spreadsheet: &Spreadsheet, dir: &TempDir) -> result::Result<HashMap<String, usize>, XlsxError> {
-    let mut shared_strings: HashMap<String, (String, Option<RichText>)> = HashMap::new();
+pub fn write(spreadsheet: &Spreadsheet, use_inline_string: &bool, dir: &TempDir) -> result::Result<HashMap<String, usize>, XlsxError> {
+    let mut shared_strings: Vec<(String, Option<RichText>)> = Vec::new();
+    let mut map: HashMap<String, usize> = HashMap::new();
     let mut count: usize = 0;
-    for sheet in spreadsheet.get_sheet_collection() {
-        for cell in sheet.get_cell_collection() {
-            if cell.get_data_type() == "s" {
-                count = count + 1;
-                let value = cell.get_hash_code_by_value();
-                shared_strings.entry(value).or_insert((cell.get_value().clone(), cell.get_rich_text().clone()));
+    if use_inline_string == &false {
+        for sheet in spreadsheet.get_sheet_collection() {
+            // first-use order, row by row
+            let mut cells: Vec<&Cell> = sheet.get_cell_collection().iter().collect();
+            cells.sort_by_key(|cell| (*cell.get_coordinate().get_row_num(), *cell.get_coordinate().get_col_num()));
+            for cell in cells {
+                if cell.is_formula() {
+                    continue;
+                }
+                match cell.get_cell_value() {
+                    CellValue::String(_) | CellValue::RichText(_) => {
+                        count = count + 1;
+                        let hash_code = cell.get_hash_code_by_value();
+                        if !map.contains_key(&hash_code) {
+                            map.insert(hash_code, shared_strings.len());
+                            shared_strings.push((cell.get_value(), cell.get_rich_text().cloned()));
+                        }
+                    },
+                    _ => {}
+                }
             }
         }
     }
 
-    let mut map: HashMap<String, usize> = HashMap::new();
-    if shared_strings.len() == 0 {
-        return Ok(map);
-    }
-    
     let mut writer = Writer::new(Cursor::new(Vec::new()));
     let _ = writer.write_event(Event::Decl(
         BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
@@ -39,110 +51,112 @@ pub fn write(spreadsheet: &Spreadsheet, dir: &TempDir) -> result::Result<HashMap
         ("xmlns", "http://schemas.openxmlformats.org/spreadsheetml/2006/main"),
         ("count", count.to_string().as_str()),
         ("uniqueCount", shared_strings.len().to_string().as_str()),
-    ], false);
-    let mut index = 0;
-    for (hash_code, (value, rich_text)) in shared_strings {
-         write_start_tag(&mut writer, "si", vec![], false);
-         match rich_text {
-             Some(v) => {
-                for element in v.get_rich_text_elements() {
-                    write_start_tag(&mut writer, "r", vec![], false);
-                    match element.get_font() {
-                        Some(font) => {
-                            write_start_tag(&mut writer, "rPr", vec![], false);
-                            // bold
-                            if font.get_bold() == &true {
-                                write_start_tag(&mut writer, "b", vec![], true);
-                             }
- 
-                             // under line
-                             if font.get_underline() != Font::UNDERLINE_NONE {
-                                 write_start_tag(&mut writer, "u", vec![
-                                     ("val", font.get_underline()),
-                                 ], true);
-                             }
-
-                            // italic
-                            if font.get_italic() == &true {
-                            write_start_tag(&mut writer, "i", vec![], true);
-                            }
-
-                            // strike
-                            if font.get_strikethrough() == &true {
-                                write_start_tag(&mut writer, "strike", vec![], true);
-                            }
-
-                            // sz
-                            write_start_tag(&mut writer, "sz", vec![
-                                ("val", font.get_size().to_string().as_str()),
-                            ], true);
+    ], shared_strings.len() == 0);
+    if shared_strings.len() == 0 {
+        let _ = make_file_from_writer(SHARED_STRINGS, dir, writer, Some("xl"))?;
+        return Ok(map);
+    }
+    for (value, rich_text) in &shared_strings {
+        write_start_tag(&mut writer, "si", vec![], false);
+        write_text_runs(&mut writer, value, rich_text.as_ref());
+        write_start_tag(&mut writer, "phoneticPr", vec![
+            ("fontId", "1"),
+        ], true);
+        write_end_tag(&mut writer, "si");
+    }
+    write_end_tag(&mut writer, "sst");
+    let _ = make_file_from_writer(SHARED_STRINGS, dir, writer, Some("xl"))?;
+    Ok(map)
+}
 
-                            // color
-                            write_color(&mut writer, &font.get_color(), "color");
+/// Write the content of a string item: a plain `t` or rich text runs.
+pub(crate) fn write_text_runs(writer: &mut Writer<Cursor<Vec<u8>>>, value: &str, rich_text: Option<&RichText>) {
+    match rich_text {
+        Some(v) => {
+            for element in v.get_rich_text_elements() {
+                write_start_tag(writer, "r", vec![], false);
+                match element.get_font() {
+                    Some(font) => {
+                        write_start_tag(writer, "rPr", vec![], false);
+                        // bold
+                        if font.get_bold() == &true {
+                            write_start_tag(writer, "b", vec![], true);
+                        }
 
-                            // name
-                            write_start_tag(&mut writer, "name", vec![
-                                ("val", font.get_name()),
+                        // under line
+                        if font.get_underline() != Font::UNDERLINE_NONE {
+                            write_start_tag(writer, "u", vec![
+                                ("val", font.get_underline()),
                             ], true);
+                        }
+
+                        // italic
+                        if font.get_italic() == &true {
+                            write_start_tag(writer, "i", vec![], true);
+                        }
 
-                            // family
-                            write_start_tag(&mut writer, "family", vec![
-                                ("val", font.get_family().to_string().as_str()),
+                        // strike
+                        if font.get_strikethrough() == &true {
+                            write_start_tag(writer, "strike", vec![], true);
+                        }
+
+                        // sz
+                        write_start_tag(writer, "sz", vec![
+                            ("val", font.get_size().to_string().as_str()),
+                        ], true);
+
+                        // color
+                        write_color(writer, &font.get_color(), "color");
+
+                        // name
+                        write_start_tag(writer, "name", vec![
+                            ("val", font.get_name()),
+                        ], true);
+
+                        // family
+                        write_start_tag(writer, "family", vec![
+                            ("val", font.get_family().to_string().as_str()),
+                        ], true);
+
+                        // charset
+                        let zero:usize = 0;
+                        if font.get_charset() > &zero {
+                            write_start_tag(writer, "charset", vec![
+                                ("val", font.get_charset().to_string().as_str()),
                             ], true);
+                        }
 
-                            // charset
-                            let zero:usize = 0;
-                            if font.get_charset() > &zero {
-                                write_start_tag(&mut writer, "charset", vec![
-                                    ("val", font.get_charset().to_string().as_str()),
-                                ], true);
-                            }
-        
-                            // scheme
-                            if font.get_scheme() != "" {
-                                write_start_tag(&mut writer, "scheme", vec![
-                                    ("val",  font.get_scheme()),
-                                ], true);
-                            }
-                            write_end_tag(&mut writer, "rPr");
-                        },
-                        None => {}
-                    }
-
-                    // t
-                    let mut attributes: Vec<(&str, &str)> = Vec::new();
-                    let re = Regex::new(r#"^(\s|　)"#).unwrap();
-                    if re.find(element.get_text()).is_some() {
-                        attributes.push(("xml:space" , "preserve"));
-                    }
-                    write_start_tag(&mut writer, "t", attributes, false);
-                    write_text_node(&mut writer, element.get_text());
-                    write_end_tag(&mut writer, "t");
-
-                    write_end_tag(&mut writer, "r");
+                        // scheme
+                        if font.get_scheme() != "" {
+                            write_start_tag(writer, "scheme", vec![
+                                ("val",  font.get_scheme()),
+                            ], true);
+                        }
+                        write_end_tag(writer, "rPr");
+                    },
+                    None => {}
                 }
-             },
-             None => {
+
                 // t
-                let mut attributes: Vec<(&str, &str)> = Vec::new();
-                let re = Regex::new(r#"^(\s|　)"#).unwrap();
-                if re.find(&value).is_some() {
-                    attributes.push(("xml:space" , "preserve"));
-                }
-                write_start_tag(&mut writer, "t", attributes, false);
-                write_text_node(&mut writer, value);
-                write_end_tag(&mut writer, "t");
-             }
-         }
-         write_start_tag(&mut writer, "phoneticPr", vec![
-             ("fontId", "1"),
-         ], true);
-         write_end_tag(&mut writer, "si");
-         map.insert(hash_code, index);
-         index = index + 1;
+                write_text(writer, element.get_text());
+
+                write_end_tag(writer, "r");
+            }
+        },
+        None => {
+            // t
+            write_text(writer, value);
+        }
     }
-    write_end_tag(&mut writer, "sst");
-    let _ = make_file_from_writer(SHARED_STRINGS, dir, writer, Some("xl"))?;
-    Ok(map)
 }
 
+fn write_text(writer: &mut Writer<Cursor<Vec<u8>>>, value: &str) {
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    let re = Regex::new(r#"^(\s|　)"#).unwrap();
+    if re.find(value).is_some() {
+        attributes.push(("xml:space" , "preserve"));
+    }
+    write_start_tag(writer, "t", attributes, false);
+    write_text_node(writer, value);
+    write_end_tag(writer, "t");
+}
@@ -0,0 +1,75 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io::Cursor;
+use tempdir::TempDir;
+use ::structs::Worksheet;
+use super::driver::*;
+use super::XlsxError;
+
+const SUB_DIR: &'static str = "xl/threadedComments";
+
+pub(crate) fn write(
+    worksheet: &Worksheet,
+    threaded_comment_id: &usize,
+    dir: &TempDir
+) -> Result<(), XlsxError> {
+    if worksheet.get_threaded_comments().len() == 0 {
+        return Ok(());
+    }
+
+    let file_name = format!("threadedComment{}.xml", threaded_comment_id);
+
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // ThreadedComments
+    write_start_tag(&mut writer, "ThreadedComments", vec![
+        ("xmlns", "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments"),
+        ("xmlns:x", "http://schemas.openxmlformats.org/spreadsheetml/2006/main"),
+    ], false);
+
+    for threaded_comment in worksheet.get_threaded_comments() {
+        // threadedComment
+        let coordinate = threaded_comment.get_coordinate().get_coordinate();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("ref", &coordinate));
+        attributes.push(("dT", threaded_comment.get_date_time()));
+        attributes.push(("personId", threaded_comment.get_person_id()));
+        attributes.push(("id", threaded_comment.get_id()));
+        match threaded_comment.get_parent_id() {
+            Some(v) => attributes.push(("parentId", v)),
+            None => {}
+        }
+        if threaded_comment.get_done() == &true {
+            attributes.push(("done", "1"));
+        }
+        write_start_tag(&mut writer, "threadedComment", attributes, false);
+
+        // text
+        write_start_tag(&mut writer, "text", vec![], false);
+        write_text_node(&mut writer, threaded_comment.get_text());
+        write_end_tag(&mut writer, "text");
+
+        // mentions
+        if threaded_comment.get_mentions().len() > 0 {
+            write_start_tag(&mut writer, "mentions", vec![], false);
+            for mention in threaded_comment.get_mentions() {
+                write_start_tag(&mut writer, "mention", vec![
+                    ("mentionpersonId", mention.get_mention_person_id()),
+                    ("mentionId", mention.get_mention_id()),
+                    ("startIndex", mention.get_start_index().to_string().as_str()),
+                    ("length", mention.get_length().to_string().as_str()),
+                ], true);
+            }
+            write_end_tag(&mut writer, "mentions");
+        }
+
+        write_end_tag(&mut writer, "threadedComment");
+    }
+    write_end_tag(&mut writer, "ThreadedComments");
+
+    let _ = make_file_from_writer(format!("{}/{}", SUB_DIR, file_name).as_str(), dir, writer, Some(SUB_DIR)).unwrap();
+    Ok(())
+}
@@ -14,9 +14,10 @@ pub(crate) fn write(
     comment_id: &usize,
     dir: &TempDir
 ) -> Result<(), XlsxError> {
-    if worksheet.get_comments().len() == 0 {
+    if !worksheet.has_comments() {
         return Ok(());
     }
+    let comments = worksheet.get_comments_with_threaded_fallback();
 
     let file_name = format!("vmlDrawing{}.vml", comment_id);
 
@@ -63,7 +64,7 @@ pub(crate) fn write(
     write_end_tag(&mut writer, "v:shapetype");
 
     let mut id = 1025;
-    for comment in worksheet.get_comments() {
+    for comment in &comments {
         // v:shape
         write_start_tag(&mut writer, "v:shape", vec![
             ("id", format!("_x0000_s{}", id).to_string().as_str()),
@@ -79,11 +80,13 @@ pub(crate) fn write(
         ], true);
 
         // v:shadow
-        write_start_tag(&mut writer, "v:shadow", vec![
-            ("on", "t"),
-            ("color", "black"),
-            ("obscured", "t"),
-        ], true);
+        if comment.get_shadow() == &true {
+            write_start_tag(&mut writer, "v:shadow", vec![
+                ("on", "t"),
+                ("color", "black"),
+                ("obscured", "t"),
+            ], true);
+        }
 
         // v:path
         write_start_tag(&mut writer, "v:path", vec![
@@ -91,13 +94,19 @@ pub(crate) fn write(
         ], true);
 
         // v:textbox
+        let textbox_style = if comment.get_auto_fit() == &true {
+            "mso-direction-alt:auto;mso-fit-shape-to-text:t"
+        } else {
+            "mso-direction-alt:auto"
+        };
         write_start_tag(&mut writer, "v:textbox", vec![
-            ("style", "mso-direction-alt:auto"),
+            ("style", textbox_style),
         ], false);
 
         // div
+        let alignment = if comment.get_alignment() == "" { Comment::ALIGNMENT_LEFT } else { comment.get_alignment() };
         write_start_tag(&mut writer, "div", vec![
-            ("style", "text-align:left"),
+            ("style", format!("text-align:{}", alignment).as_str()),
         ], false);
         write_end_tag(&mut writer, "div");
 
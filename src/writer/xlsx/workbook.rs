@@ -4,6 +4,8 @@ use std::io::Cursor;
 use tempdir::TempDir;
 
 use ::structs::Spreadsheet;
+use ::structs::WorkbookView;
+use ::structs::Calculation;
 use super::driver::*;
 use super::XlsxError;
 
@@ -30,28 +32,60 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
 
     // workbookPr
     let mut attributes: Vec<(&str, &str)> = Vec::new();
-    attributes.push(("filterPrivacy", "1"));
-    //attributes.push(("defaultThemeVersion", "124226"));
-    match spreadsheet.get_has_macros() {
-        &true => attributes.push(("codeName", "ThisWorkbook")),
-        &false => {}
+    if spreadsheet.get_date1904() == &true {
+        attributes.push(("date1904", "1"));
+    }
+    if spreadsheet.get_filter_privacy() == &true {
+        attributes.push(("filterPrivacy", "1"));
+    }
+    match spreadsheet.get_code_name() {
+        Some(v) => attributes.push(("codeName", v)),
+        None => {
+            if spreadsheet.get_has_macros() == &true {
+                attributes.push(("codeName", "ThisWorkbook"));
+            }
+        }
+    }
+    match spreadsheet.get_default_theme_version() {
+        Some(v) => attributes.push(("defaultThemeVersion", v)),
+        None => {}
     }
     write_start_tag(&mut writer, "workbookPr", attributes, true);
 
     // workbookProtection
-    if spreadsheet.get_security().is_security_enabled() {
-        let tag_name = "workbookProtection";
+    let security = spreadsheet.get_security();
+    if security.is_security_enabled() {
+        let revisions_spin_count = security.get_revisions_spin_count().to_string();
+        let workbook_spin_count = security.get_workbook_spin_count().to_string();
         let mut attributes: Vec<(&str, &str)> = Vec::new();
-        attributes.push(("lockRevision", if spreadsheet.get_security().get_lock_revision() == &true { "true" } else {"false"}));
-        attributes.push(("lockStructure", if spreadsheet.get_security().get_lock_structure() == &true { "true" } else {"false"}));
-        attributes.push(("lockWindows", if spreadsheet.get_security().get_lock_windows() == &true { "true" } else {"false"}));
-        if spreadsheet.get_security().get_revisions_password() != "" {
-            attributes.push(("lockWindows", spreadsheet.get_security().get_revisions_password()));
+        if security.get_workbook_password() != "" {
+            attributes.push(("workbookPassword", security.get_workbook_password()));
+        }
+        if security.get_revisions_password() != "" {
+            attributes.push(("revisionsPassword", security.get_revisions_password()));
+        }
+        if security.get_lock_structure() == &true {
+            attributes.push(("lockStructure", "1"));
+        }
+        if security.get_lock_windows() == &true {
+            attributes.push(("lockWindows", "1"));
+        }
+        if security.get_lock_revision() == &true {
+            attributes.push(("lockRevision", "1"));
+        }
+        if security.get_revisions_hash_value() != "" {
+            attributes.push(("revisionsAlgorithmName", security.get_revisions_algorithm_name()));
+            attributes.push(("revisionsHashValue", security.get_revisions_hash_value()));
+            attributes.push(("revisionsSaltValue", security.get_revisions_salt_value()));
+            attributes.push(("revisionsSpinCount", &revisions_spin_count));
         }
-        if spreadsheet.get_security().get_workbook_password() != "" {
-            attributes.push(("workbookPassword", spreadsheet.get_security().get_workbook_password()));
+        if security.get_workbook_hash_value() != "" {
+            attributes.push(("workbookAlgorithmName", security.get_workbook_algorithm_name()));
+            attributes.push(("workbookHashValue", security.get_workbook_hash_value()));
+            attributes.push(("workbookSaltValue", security.get_workbook_salt_value()));
+            attributes.push(("workbookSpinCount", &workbook_spin_count));
         }
-        write_start_tag(&mut writer, tag_name, attributes, true);
+        write_start_tag(&mut writer, "workbookProtection", attributes, true);
     }
 
     // bookViews
@@ -59,12 +93,15 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     ], false);
 
     // workbookView
-    write_start_tag(&mut writer, "workbookView", vec![
-        ("xWindow", "240"),
-        ("yWindow", "105"),
-        ("windowWidth", "14805"),
-        ("windowHeight", "8010"),
-    ], true);
+    let default_workbook_views = vec![WorkbookView::default()];
+    let workbook_views = match spreadsheet.get_workbook_view_collection().len() {
+        0 => &default_workbook_views,
+        _ => spreadsheet.get_workbook_view_collection(),
+    };
+    for (index, workbook_view) in workbook_views.iter().enumerate() {
+        let active_tab = if index == 0 { spreadsheet.get_active_sheet_index() } else { workbook_view.get_active_tab() };
+        write_workbook_view(&mut writer, workbook_view, active_tab);
+    }
 
     write_end_tag(&mut writer, "bookViews");
 
@@ -89,19 +126,28 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     write_end_tag(&mut writer, "sheets");
 
     // definedNames
-    if spreadsheet.get_defined_names().len() > 1 {
+    if spreadsheet.get_defined_names().len() > 0 {
         write_start_tag(&mut writer, "definedNames", vec![], false);
 
         for defined_name in spreadsheet.get_defined_names() {
             // definedName
+            let local_sheet_id = match defined_name.get_local_sheet_id() {
+                Some(v) => v.to_string(),
+                None => String::from(""),
+            };
             let mut attributes: Vec<(&str, &str)> = Vec::new();
             attributes.push(("name", defined_name.get_name()));
-            if defined_name.get_is_local_only() == &true {
-                attributes.push(("localSheetId", "0"));
+            if defined_name.get_comment() != "" {
+                attributes.push(("comment", defined_name.get_comment()));
+            }
+            if &local_sheet_id != "" {
+                attributes.push(("localSheetId", &local_sheet_id));
+            }
+            if defined_name.get_hidden() == &true {
                 attributes.push(("hidden", "1"));
             }
             write_start_tag(&mut writer, "definedName", attributes, false);
-            write_text_node(&mut writer, defined_name.get_address());
+            write_text_node(&mut writer, defined_name.get_formula());
             write_end_tag(&mut writer, "definedName");
         }
 
@@ -109,16 +155,91 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     }
 
     // calcPr
-    write_start_tag(&mut writer, "calcPr", vec![
-        ("calcId", "122211"),
-        //("calcId", "999999"),
-        //("calcMode", "auto"),
-        //("calcCompleted", if recalc_required {"1"} else {"0"}),
-        //("fullCalcOnLoad", if recalc_required {"0"} else {"1"}),
-        //("forceFullCalc", if recalc_required {"0"} else {"1"}),
-    ], true);
+    let calculation = spreadsheet.get_calculation();
+    let calc_id = calculation.get_calc_id().to_string();
+    let iterate_count = calculation.get_iterate_count().to_string();
+    let iterate_delta = calculation.get_iterate_delta().to_string();
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    attributes.push(("calcId", &calc_id));
+    if calculation.get_calc_mode() != Calculation::CALC_MODE_AUTO {
+        attributes.push(("calcMode", calculation.get_calc_mode()));
+    }
+    if calculation.get_full_calc_on_load() == &true {
+        attributes.push(("fullCalcOnLoad", "1"));
+    }
+    if calculation.get_ref_mode() != Calculation::REF_MODE_A1 {
+        attributes.push(("refMode", calculation.get_ref_mode()));
+    }
+    if calculation.get_iterate() == &true {
+        attributes.push(("iterate", "1"));
+    }
+    if calculation.get_iterate_count() != &100 {
+        attributes.push(("iterateCount", &iterate_count));
+    }
+    if calculation.get_iterate_delta() != &0.001 {
+        attributes.push(("iterateDelta", &iterate_delta));
+    }
+    if calculation.get_full_precision() == &false {
+        attributes.push(("fullPrecision", "0"));
+    }
+    if calculation.get_calc_completed() == &false {
+        attributes.push(("calcCompleted", "0"));
+    }
+    if calculation.get_calc_on_save() == &false {
+        attributes.push(("calcOnSave", "0"));
+    }
+    if calculation.get_concurrent_calc() == &false {
+        attributes.push(("concurrentCalc", "0"));
+    }
+    if calculation.get_force_full_calc() == &true {
+        attributes.push(("forceFullCalc", "1"));
+    }
+    write_start_tag(&mut writer, "calcPr", attributes, true);
     
     write_end_tag(&mut writer, "workbook");
     let _ = make_file_from_writer(format!("{}/{}",sub_dir,file_name).as_str(), dir, writer, Some(sub_dir)).unwrap();
     Ok(())
 }
+
+fn write_workbook_view(writer: &mut Writer<Cursor<Vec<u8>>>, workbook_view: &WorkbookView, active_tab: &usize) {
+    let x_window = workbook_view.get_x_window().to_string();
+    let y_window = workbook_view.get_y_window().to_string();
+    let window_width = workbook_view.get_window_width().to_string();
+    let window_height = workbook_view.get_window_height().to_string();
+    let tab_ratio = workbook_view.get_tab_ratio().to_string();
+    let first_sheet = workbook_view.get_first_sheet().to_string();
+    let active_tab = active_tab.to_string();
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    if workbook_view.get_visibility() != WorkbookView::VISIBILITY_VISIBLE {
+        attributes.push(("visibility", workbook_view.get_visibility()));
+    }
+    if workbook_view.get_minimized() == &true {
+        attributes.push(("minimized", "1"));
+    }
+    if workbook_view.get_show_horizontal_scroll() == &false {
+        attributes.push(("showHorizontalScroll", "0"));
+    }
+    if workbook_view.get_show_vertical_scroll() == &false {
+        attributes.push(("showVerticalScroll", "0"));
+    }
+    if workbook_view.get_show_sheet_tabs() == &false {
+        attributes.push(("showSheetTabs", "0"));
+    }
+    attributes.push(("xWindow", &x_window));
+    attributes.push(("yWindow", &y_window));
+    attributes.push(("windowWidth", &window_width));
+    attributes.push(("windowHeight", &window_height));
+    if workbook_view.get_tab_ratio() != &600 {
+        attributes.push(("tabRatio", &tab_ratio));
+    }
+    if workbook_view.get_first_sheet() != &0 {
+        attributes.push(("firstSheet", &first_sheet));
+    }
+    if active_tab != "0" {
+        attributes.push(("activeTab", &active_tab));
+    }
+    if workbook_view.get_auto_filter_date_grouping() == &false {
+        attributes.push(("autoFilterDateGrouping", "0"));
+    }
+    write_start_tag(writer, "workbookView", attributes, true);
+}
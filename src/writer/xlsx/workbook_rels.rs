@@ -62,15 +62,27 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     }
 
     // relationships for vbaProject if needed
+    let mut id = index+1+3+1;
     if spreadsheet.get_has_macros() == &true {
-        let id = (index+1+3+1).to_string();
         write_relationship(
             &mut writer,
-            id.as_str(),
+            id.to_string().as_str(),
             "http://schemas.microsoft.com/office/2006/relationships/vbaProject",
             "vbaProject.bin",
             ""
         );
+        id += 1;
+    }
+
+    // relationships for persons of threaded comments
+    if spreadsheet.get_person_collection().len() > 0 {
+        write_relationship(
+            &mut writer,
+            id.to_string().as_str(),
+            "http://schemas.microsoft.com/office/2017/10/relationships/person",
+            "persons/person.xml",
+            ""
+        );
     }
     
     write_end_tag(&mut writer, root_tag_name);
@@ -8,8 +8,12 @@ use std::collections::BTreeSet;
 
 use ::structs::Style;
 use ::structs::Worksheet;
+use ::structs::Cell;
+use ::structs::CellValue;
 use super::super::helper::coordinate::*;
+use super::super::helper::date::*;
 use super::driver::*;
+use super::shared_strings::write_text_runs;
 use super::XlsxError;
 
 const SUB_DIR: &'static str = "xl/worksheets";
@@ -19,9 +23,11 @@ pub(crate) fn write(
     sheet_no: &usize,
     is_selected: &bool,
     has_macros: &bool,
+    calendar: &str,
     all_cell_xf_list: Vec<(String, Style)>,
     conditonal_style_list: Vec<(String, Style)>,
     shared_strings: HashMap<String, usize>,
+    use_inline_string: &bool,
     dir: &TempDir
 ) -> Result<(), XlsxError> 
 {
@@ -207,12 +213,17 @@ pub(crate) fn write(
 
         for col_num in col_num_list {
             let cell = &cells.get(&col_num);
-            let style = &styles.get(&col_num);
+            let style = styles.get(&col_num);
 
             let coordinate = coordinate_from_index(&col_num, &row_num);
             let mut attributes: Vec<(&str, &str)> = Vec::new();
             attributes.push(("r", &coordinate));
 
+            let date_time_style = worksheet.get_date_time_style_by_column_and_row(&col_num, &row_num);
+            let style = match &date_time_style {
+                Some(v) => Some(v),
+                None => style.cloned()
+            };
             let mut xf_index:usize = 0;
             match style {
                 Some(v) => {
@@ -232,35 +243,60 @@ pub(crate) fn write(
 
             match cell {
                 Some(c) => {
-                    if c.get_data_type() == "s" || c.get_data_type() == "b" {
-                        attributes.push(("t", c.get_data_type()));
+                    let result = c.get_cell_value().get_result();
+                    match result {
+                        CellValue::String(_) | CellValue::RichText(_) => {
+                            if c.is_formula() {
+                                attributes.push(("t", Cell::TYPE_STRING2));
+                            } else if use_inline_string == &true {
+                                attributes.push(("t", Cell::TYPE_INLINE));
+                            } else {
+                                attributes.push(("t", Cell::TYPE_STRING));
+                            }
+                        },
+                        CellValue::Bool(_) => attributes.push(("t", Cell::TYPE_BOOL)),
+                        CellValue::Error(_) => attributes.push(("t", Cell::TYPE_ERROR)),
+                        _ => {}
                     }
-                    write_start_tag(&mut writer, "c", attributes, false);
+                    let has_value = !result.is_empty();
+                    write_start_tag(&mut writer, "c", attributes, !c.is_formula() && !has_value);
 
                     // f
-                    if c.get_formula() != "" {
+                    if c.is_formula() {
                         write_start_tag(&mut writer, "f", vec![], false);
                         write_text_node(&mut writer, c.get_formula());
                         write_end_tag(&mut writer, "f");
                     }
 
-                    // v
-                    write_start_tag(&mut writer, "v", vec![], false);
+                    // is
+                    let is_inline_string = !c.is_formula() && use_inline_string == &true && matches!(result, CellValue::String(_) | CellValue::RichText(_));
+                    if is_inline_string {
+                        write_start_tag(&mut writer, "is", vec![], false);
+                        write_text_runs(&mut writer, &result.to_string(), result.get_rich_text());
+                        write_end_tag(&mut writer, "is");
+                    }
 
-                    match c.get_data_type() {
-                        "s" => {
-                            let val_index = shared_strings.get(&c.get_hash_code_by_value()).unwrap().to_string();
-                            write_text_node(&mut writer, val_index);
-                        },
-                        "b" => {
-                            let upper_value = c.get_value().to_uppercase();
-                            let prm = if upper_value == "TRUE" {"1"} else {"0"};
-                            write_text_node(&mut writer, prm);
-                        },
-                        _ => write_text_node(&mut writer, c.get_value()),
+                    // v
+                    if has_value && !is_inline_string {
+                        write_start_tag(&mut writer, "v", vec![], false);
+                        match result {
+                            CellValue::String(_) | CellValue::RichText(_) if !c.is_formula() => {
+                                let val_index = shared_strings.get(&c.get_hash_code_by_value()).unwrap().to_string();
+                                write_text_node(&mut writer, val_index);
+                            },
+                            CellValue::Bool(v) => {
+                                write_text_node(&mut writer, if *v {"1"} else {"0"});
+                            },
+                            CellValue::DateTime(v) => {
+                                write_text_node(&mut writer, date_time_object_to_excel(v, calendar).to_string());
+                            },
+                            _ => write_text_node(&mut writer, result.to_string()),
+                        }
+                        write_end_tag(&mut writer, "v");
+                    }
+                    if c.is_formula() || has_value {
+                        write_end_tag(&mut writer, "c");
                     }
-                    write_end_tag(&mut writer, "v");
-                    write_end_tag(&mut writer, "c");
                 },
                 None => {
                     write_start_tag(&mut writer, "c", attributes, true);
@@ -410,6 +446,12 @@ pub(crate) fn write(
                 attributes.push(("r:id", rid.as_str()));
                 i += 1;
             }
+            if hyperlink.get_tooltip() != "" {
+                attributes.push(("tooltip", hyperlink.get_tooltip()));
+            }
+            if hyperlink.get_display() != "" {
+                attributes.push(("display", hyperlink.get_display()));
+            }
             write_start_tag(&mut writer, "hyperlink", attributes, true);
         }
 
@@ -443,7 +485,7 @@ pub(crate) fn write(
     }
 
     // comment
-    if worksheet.get_comments().len() > 0 {
+    if worksheet.has_comments() {
         let i = first_legacy_drawing_id(worksheet);
         let rid = format!("rId{}", &i);
         // legacyDrawing
@@ -14,6 +14,7 @@ pub(crate) fn write(
     p_worksheet_id: &str,
     drawing_id: &usize,
     comment_id: &usize,
+    threaded_comment_id: &usize,
     dir: &TempDir
 ) -> Result<(), XlsxError> {
     let file_name = format!("sheet{}.xml.rels", p_worksheet_id);
@@ -57,7 +58,7 @@ pub(crate) fn write(
     }
 
     // Write comments relationship
-    if worksheet.get_comments().len() > 0 {
+    if worksheet.has_comments() {
         write_relationship(
             &mut writer,
             id.to_string().as_str(),
@@ -73,6 +74,18 @@ pub(crate) fn write(
             format!("../comments{}.xml", comment_id.to_string().as_str()).as_str(),
             ""
         );
+        id+=1;
+    }
+
+    // Write threaded comments relationship
+    if worksheet.get_threaded_comments().len() > 0 {
+        is_write = write_relationship(
+            &mut writer,
+            id.to_string().as_str(),
+            "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment",
+            format!("../threadedComments/threadedComment{}.xml", threaded_comment_id.to_string().as_str()).as_str(),
+            ""
+        );
     }
 
     // Write header/footer relationship
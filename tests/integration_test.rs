@@ -1,4 +1,6 @@
 extern crate umya_spreadsheet;
+extern crate chrono;
+extern crate zip;
 
 #[test]
 fn read_and_wite() {
@@ -109,7 +111,7 @@ fn new_and_wite() {
 
     let _ = book.get_sheet_by_name_mut("Sheet2").unwrap().get_cell_by_column_and_row_mut(3, 3).set_value_and_data_type("true", umya_spreadsheet::Cell::TYPE_BOOL);
     let a1_value = book.get_sheet_by_name("Sheet2").unwrap().get_cell_by_column_and_row(3, 3).unwrap().get_value();
-    assert_eq!("true", a1_value);
+    assert_eq!("TRUE", a1_value);
 
     let _ = book.get_sheet_by_name_mut("Sheet2").unwrap().get_cell_by_column_and_row_mut(3, 4).set_value("true");
     let a1_value = book.get_sheet_by_name("Sheet2").unwrap().get_cell_by_column_and_row(3, 4).unwrap().get_value();
@@ -153,3 +155,1160 @@ fn duplicate_sheet() {
         Err(_) => {}
     }
 }
+
+#[test]
+fn typed_cell_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let _ = worksheet.get_cell_mut("A1").set_value("00123");
+    let _ = worksheet.get_cell_mut("A2").set_value("1e5");
+    let _ = worksheet.get_cell_mut("A3").set_value("NULL");
+    let _ = worksheet.get_cell_mut("A4").set_value_number(1.5);
+    let _ = worksheet.get_cell_mut("A5").set_value_bool(true);
+    let _ = worksheet.get_cell_mut("A6").set_value(42);
+    let _ = worksheet.get_cell_mut("A7").set_formula("A4*2").set_formula_cached_value(3);
+    assert!(worksheet.get_cell_mut("A8").set_value_and_data_type("abc", umya_spreadsheet::Cell::TYPE_NUMERIC).is_err());
+
+    let path = std::path::Path::new("C:/spread_test_data/typed_values.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(0).unwrap();
+    assert_eq!(Some("00123"), worksheet.get_cell("A1").unwrap().get_value_string());
+    assert_eq!(Some("1e5"), worksheet.get_cell("A2").unwrap().get_value_string());
+    assert_eq!(Some("NULL"), worksheet.get_cell("A3").unwrap().get_value_string());
+    assert_eq!(Some(1.5), worksheet.get_cell("A4").unwrap().get_value_number());
+    assert_eq!(Some(true), worksheet.get_cell("A5").unwrap().get_value_bool());
+    assert_eq!("TRUE", worksheet.get_cell("A5").unwrap().get_value());
+    assert_eq!(&umya_spreadsheet::CellValue::Number(42f64), worksheet.get_cell("A6").unwrap().get_cell_value());
+    assert_eq!("A4*2", worksheet.get_cell("A7").unwrap().get_formula());
+    assert_eq!(Some(3f64), worksheet.get_cell("A7").unwrap().get_value_number());
+}
+
+#[test]
+fn date_time_values() {
+    let date_time = chrono::NaiveDate::from_ymd_opt(2021, 6, 2).unwrap().and_hms_opt(5, 4, 2).unwrap();
+    let date = chrono::NaiveDate::from_ymd_opt(1900, 2, 28).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    for date1904 in &[false, true] {
+        let mut book = umya_spreadsheet::new_file();
+        book.set_date1904(*date1904);
+        let _ = book.get_sheet_mut(0).get_cell_mut("A1").set_date_time(date_time);
+        let _ = book.get_sheet_mut(0).get_cell_mut("A2").set_date_time(date);
+        let _ = book.get_sheet_mut(0).get_cell_mut("A3").set_value_number(43973);
+        let _ = book.get_sheet_mut(0).get_style_mut("A3").get_number_format_mut().set_format_code(umya_spreadsheet::NumberFormat::FORMAT_DATE_YYYYMMDD);
+        let expected = if *date1904 {"2024-05-23"} else {"2020-05-22"};
+        assert_eq!(expected, book.get_sheet(0).unwrap().get_formatted_value("A3"));
+
+        let path = std::path::Path::new("C:/spread_test_data/date_time.xlsx");
+        let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+        let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+        assert_eq!(date1904, book.get_date1904());
+        let worksheet = book.get_sheet(0).unwrap();
+        assert_eq!(Some(date_time), worksheet.get_cell("A1").unwrap().get_date_time());
+        if !date1904 {
+            // the 1904 date system has no dates before 1904
+            assert_eq!(Some(date), worksheet.get_cell("A2").unwrap().get_date_time());
+        }
+        assert_eq!("6/2/2021 5:04", worksheet.get_formatted_value("A1"));
+        assert_eq!(expected, worksheet.get_formatted_value("A3"));
+        let calendar = if *date1904 {umya_spreadsheet::helper::date::CALENDAR_MAC_1904} else {umya_spreadsheet::helper::date::CALENDAR_WINDOWS_1900};
+        assert_eq!(Some(43973f64), worksheet.get_cell("A3").unwrap().get_cell_value().get_number_with_calendar(calendar));
+    }
+}
+
+#[test]
+fn error_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let _ = worksheet.get_cell_mut("A1").set_value_error(umya_spreadsheet::CellErrorType::NA);
+    let _ = worksheet.get_cell_mut("A2").set_formula("1/0").set_formula_cached_value(umya_spreadsheet::CellErrorType::Div0);
+    let _ = worksheet.get_cell_mut("A3").set_value("#REF!");
+    let _ = worksheet.get_cell_mut("A4").set_value_error(umya_spreadsheet::CellErrorType::Other("#UNKNOWN!".to_string()));
+
+    let path = std::path::Path::new("C:/spread_test_data/error_values.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(0).unwrap();
+    assert_eq!(Some(umya_spreadsheet::CellErrorType::NA), worksheet.get_cell("A1").unwrap().get_value_error());
+    assert_eq!(Some(umya_spreadsheet::CellErrorType::Div0), worksheet.get_cell("A2").unwrap().get_value_error());
+    assert_eq!("#DIV/0!", worksheet.get_value("A2"));
+    assert_eq!(None, worksheet.get_cell("A3").unwrap().get_value_error());
+    assert_eq!(Some(umya_spreadsheet::CellErrorType::Other("#UNKNOWN!".to_string())), worksheet.get_cell("A4").unwrap().get_value_error());
+    assert_eq!("#UNKNOWN!", worksheet.get_value("A4"));
+}
+
+#[test]
+fn inline_string_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let _ = worksheet.get_cell_mut("A1").set_value(" leading space");
+    let mut text_element = umya_spreadsheet::TextElement::default();
+    text_element.set_text("rich");
+    let _ = worksheet.get_cell_mut("A2").set_value(text_element);
+    let _ = worksheet.get_cell_mut("A3").set_value_number(1);
+
+    let path = std::path::Path::new("C:/spread_test_data/inline_string_values.xlsx");
+    let mut option = umya_spreadsheet::writer::xlsx::WriterOption::default();
+    let _ = option.set_use_inline_string(true);
+    let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(0).unwrap();
+    assert_eq!(Some(" leading space"), worksheet.get_cell("A1").unwrap().get_value_string());
+    assert_eq!("rich", worksheet.get_value("A2"));
+    assert!(worksheet.get_cell("A2").unwrap().get_rich_text().is_some());
+    assert_eq!(Some(1f64), worksheet.get_cell("A3").unwrap().get_value_number());
+}
+
+#[test]
+fn deterministic_write() {
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet2");
+    for (i, value) in ["c", "b", "a", "b", "d"].iter().enumerate() {
+        let _ = book.get_sheet_mut(0).get_cell_by_column_and_row_mut(1, i + 1).set_value(*value);
+        let _ = book.get_sheet_mut(1).get_cell_by_column_and_row_mut(2, i + 1).set_value_number(i as i32);
+    }
+    let _ = book.get_sheet_mut(0).get_style_mut("A1").get_font_mut().set_bold(true);
+    let _ = book.get_sheet_mut(0).get_cell_mut("A2").get_hyperlink_mut().set_url("https://example.com/2");
+    let _ = book.get_sheet_mut(0).get_cell_mut("A3").get_hyperlink_mut().set_url("https://example.com/3");
+    let _ = book.get_properties_mut()
+        .set_created("2024-01-01T00:00:00Z")
+        .set_modified("2024-01-01T00:00:00Z");
+
+    let mut option = umya_spreadsheet::writer::xlsx::WriterOption::default();
+    let _ = option.set_is_deterministic(true);
+    let path1 = std::path::Path::new("C:/spread_test_data/deterministic_1.xlsx");
+    let path2 = std::path::Path::new("C:/spread_test_data/deterministic_2.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path1, &option).unwrap();
+    let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path2, &option).unwrap();
+
+    assert_eq!(std::fs::read(path1).unwrap(), std::fs::read(path2).unwrap());
+
+    // every part carries the fixed timestamp instead of the time of writing
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path1).unwrap()).unwrap();
+    assert_eq!("[Content_Types].xml", archive.by_index(0).unwrap().name());
+    for i in 0..archive.len() {
+        let last_modified = archive.by_index(i).unwrap().last_modified();
+        assert_eq!((1980, 1, 1, 0, 0, 0), (last_modified.year(), last_modified.month(), last_modified.day(), last_modified.hour(), last_modified.minute(), last_modified.second()));
+    }
+}
+
+#[test]
+fn add_chart_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let _ = worksheet.get_cell_mut("B1").set_value("Sales");
+    for (i, (label, value)) in [("Jan", 10), ("Feb", 20), ("Mar", 15)].iter().enumerate() {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, i + 2).set_value(*label);
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, i + 2).set_value_number(*value);
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Column, "D2:K16").unwrap()
+        .add_series("$B$1", "$A$2:$A$4", "$B$2:$B$4")
+        .set_title_text("Monthly Sales")
+        .set_legend_position(umya_spreadsheet::drawing::charts::LegendPosition::POSITION_BOTTOM)
+        .set_category_axis_title_text("Month")
+        .set_value_axis_title_text("Amount");
+
+    let path = std::path::Path::new("C:/spread_test_data/add_chart_values.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(0).unwrap();
+    let graphic_frame = &worksheet.get_worksheet_drawing().get_graphic_frame_collection()[0];
+    let chart = graphic_frame.get_graphic().get_graphic_data().get_chart_space().get_chart();
+    assert_eq!("Monthly Sales", chart.get_title().as_ref().unwrap().get_text());
+    let series = chart.get_plot_area().get_area_chart_series();
+    assert_eq!(1, series.len());
+    let number_reference = series[0].get_values().as_ref().unwrap().get_number_reference();
+    assert_eq!("Sheet1!$B$2:$B$4", number_reference.get_formula().get_address_str());
+    let points = number_reference.get_numbering_cache().get_string_point();
+    assert_eq!(3, points.len());
+    assert_eq!("20", points[1].get_numeric_value().get_val());
+}
+
+#[test]
+fn radar_bubble_stock_surface_charts() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    for row in 1..6 {
+        for col in 1..5 {
+            let _ = worksheet.get_cell_by_column_and_row_mut(col, row).set_value_number((row * col) as i32);
+        }
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Radar, "F1:K10").unwrap()
+        .add_series("", "$A$1:$A$5", "$B$1:$B$5");
+    let chart_space = worksheet.add_chart(umya_spreadsheet::ChartType::Bubble, "F11:K20").unwrap();
+    chart_space.add_series("", "$A$1:$A$5", "$B$1:$B$5");
+    let mut bubble_size = umya_spreadsheet::drawing::charts::BubbleSize::default();
+    bubble_size.get_number_reference_mut().get_formula_mut().set_address_str("$C$1:$C$5");
+    chart_space.get_chart_mut().get_plot_area_mut().get_area_chart_series_mut()[0].set_bubble_size(bubble_size);
+    worksheet.add_chart(umya_spreadsheet::ChartType::Stock, "F21:K30").unwrap()
+        .add_series("", "$A$1:$A$5", "$B$1:$B$5")
+        .add_series("", "$A$1:$A$5", "$C$1:$C$5")
+        .add_series("", "$A$1:$A$5", "$D$1:$D$5");
+    worksheet.add_chart(umya_spreadsheet::ChartType::Surface, "F31:K40").unwrap()
+        .add_series("", "$A$1:$A$5", "$B$1:$B$5")
+        .add_series("", "$A$1:$A$5", "$C$1:$C$5");
+
+    let path = std::path::Path::new("C:/spread_test_data/radar_bubble_stock_surface_charts.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let graphic_frames = book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection();
+    assert_eq!(4, graphic_frames.len());
+    let plot_area = |index:usize| graphic_frames[index].get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area();
+    assert_eq!("marker", plot_area(0).get_radar_chart().as_ref().unwrap().get_radar_style().get_val());
+    let bubble_series = plot_area(1).get_area_chart_series();
+    assert_eq!("Sheet1!$C$1:$C$5", bubble_series[0].get_bubble_size().as_ref().unwrap().get_number_reference().get_formula().get_address_str());
+    let stock_chart = plot_area(2).get_stock_chart().unwrap();
+    assert_eq!(3, stock_chart.get_area_chart_series().len());
+    assert!(stock_chart.get_high_low_lines().is_some());
+    assert!(plot_area(3).get_surface_3d_chart().is_some());
+    assert_eq!(1, plot_area(3).get_series_axis().len());
+}
+
+#[test]
+fn three_d_and_of_pie_charts() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    for row in 1..6 {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, row).set_value(format!("Item{}", row));
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, row).set_value_number(row as i32);
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Line3D, "D1:J10").unwrap().add_series("", "$A$1:$A$5", "$B$1:$B$5");
+    worksheet.add_chart(umya_spreadsheet::ChartType::Area3D, "D11:J20").unwrap().add_series("", "$A$1:$A$5", "$B$1:$B$5");
+    worksheet.add_chart(umya_spreadsheet::ChartType::Area, "D21:J30").unwrap().add_series("", "$A$1:$A$5", "$B$1:$B$5");
+    let chart_space = worksheet.add_chart(umya_spreadsheet::ChartType::Pie3D, "D31:J40").unwrap();
+    chart_space.add_series("", "$A$1:$A$5", "$B$1:$B$5");
+    let mut explosion = umya_spreadsheet::drawing::charts::Explosion::default();
+    explosion.set_val("25");
+    chart_space.get_chart_mut().get_plot_area_mut().get_area_chart_series_mut()[0].set_explosion(explosion);
+    worksheet.add_chart(umya_spreadsheet::ChartType::BarOfPie, "D41:J50").unwrap().add_series("", "$A$1:$A$5", "$B$1:$B$5");
+
+    let path = std::path::Path::new("C:/spread_test_data/three_d_and_of_pie_charts.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let graphic_frames = book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection();
+    assert_eq!(5, graphic_frames.len());
+    let chart = |index:usize| graphic_frames[index].get_graphic().get_graphic_data().get_chart_space().get_chart();
+    assert_eq!(3, chart(0).get_plot_area().get_line_3d_chart().as_ref().unwrap().get_axis_id().len());
+    assert!(chart(1).get_plot_area().get_area_3d_chart().is_some());
+    assert_eq!(1, chart(1).get_plot_area().get_series_axis().len());
+    assert!(chart(2).get_plot_area().get_area_chart().is_some());
+    assert_eq!("30", chart(3).get_view_3d().as_ref().unwrap().get_rotate_x().as_ref().unwrap().get_val());
+    let pie_series = chart(3).get_plot_area().get_area_chart_series();
+    assert_eq!("25", pie_series[0].get_explosion().as_ref().unwrap().get_val());
+    let of_pie_chart = chart(4).get_plot_area().get_of_pie_chart().unwrap();
+    assert_eq!("bar", of_pie_chart.get_of_pie_type().get_val());
+    assert_eq!("75", of_pie_chart.get_second_pie_size().as_ref().unwrap().get_val());
+}
+
+#[test]
+fn combination_chart() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let _ = worksheet.get_cell_mut("B1").set_value("Revenue");
+    let _ = worksheet.get_cell_mut("C1").set_value("Margin");
+    for row in 2..6 {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, row).set_value(format!("Q{}", row - 1));
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, row).set_value_number((row * 100) as i32);
+        let _ = worksheet.get_cell_by_column_and_row_mut(3, row).set_value_number(row as f64 / 10f64);
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Column, "E2:L20").unwrap()
+        .add_series("$B$1", "$A$2:$A$5", "$B$2:$B$5")
+        .add_series_with_chart_type(umya_spreadsheet::ChartType::Line, true, "$C$1", "$A$2:$A$5", "$C$2:$C$5")
+        .set_value_axis_title_text("Revenue")
+        .set_secondary_value_axis_title_text("Margin %");
+    assert!(worksheet.add_chart(umya_spreadsheet::ChartType::Column, "e2:l20").is_err());
+    assert!(worksheet.add_chart(umya_spreadsheet::ChartType::Column, "L20:E2").is_err());
+    assert!(worksheet.add_chart(umya_spreadsheet::ChartType::Column, "E2:L").is_err());
+
+    let path = std::path::Path::new("C:/spread_test_data/combination_chart.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let graphic_frame = &book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection()[0];
+    let plot_area = graphic_frame.get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area();
+    assert_eq!(2, plot_area.get_category_axis().len());
+    assert_eq!(2, plot_area.get_value_axis().len());
+    let bar_axis_ids = plot_area.get_bar_chart().unwrap().get_axis_id();
+    let line_axis_ids = plot_area.get_line_chart().unwrap().get_axis_id();
+    assert_ne!(bar_axis_ids[1].get_val(), line_axis_ids[1].get_val());
+    let secondary_value_axis = plot_area.get_value_axis().iter().find(|v| v.get_axis_id().get_val() == line_axis_ids[1].get_val()).unwrap();
+    assert_eq!(line_axis_ids[0].get_val(), secondary_value_axis.get_tick_crossing_axis().get_val());
+    assert_eq!("max", secondary_value_axis.get_crosses().get_val());
+    assert_eq!("Margin %", secondary_value_axis.get_title().as_ref().unwrap().get_text());
+    assert_eq!(2, plot_area.get_area_chart_series().len());
+    assert_eq!("1", plot_area.get_line_chart().unwrap().get_area_chart_series()[0].get_index().get_val());
+    let chart_types:Vec<umya_spreadsheet::ChartType> = plot_area.get_chart_group().iter().map(|v| v.get_chart_type()).collect();
+    assert_eq!(vec![umya_spreadsheet::ChartType::Column, umya_spreadsheet::ChartType::Line], chart_types);
+}
+
+#[test]
+fn chart_axis_scaling_and_date_axis() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    for row in 1..6 {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, row).set_value_number(44197 + row as i32);
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, row).set_value_number((row * 1000) as i32);
+    }
+    let plot_area = worksheet.add_chart(umya_spreadsheet::ChartType::Line, "D1:J15").unwrap()
+        .add_series("", "$A$1:$A$5", "$B$1:$B$5")
+        .get_chart_mut().get_plot_area_mut();
+
+    let category_axis = plot_area.get_category_axis_mut().remove(0);
+    let mut date_axis = umya_spreadsheet::drawing::charts::DateAxis::default();
+    date_axis.set_axis_id(category_axis.get_axis_id().clone());
+    date_axis.set_scaling(category_axis.get_scaling().clone());
+    date_axis.set_delete(category_axis.get_delete().clone());
+    date_axis.set_axis_position(category_axis.get_axis_position().clone());
+    date_axis.set_major_tick_mark(category_axis.get_major_tick_mark().clone());
+    date_axis.set_minor_tick_mark(category_axis.get_minor_tick_mark().clone());
+    date_axis.set_tick_label_position(category_axis.get_tick_label_position().clone());
+    date_axis.set_tick_crossing_axis(category_axis.get_tick_crossing_axis().clone());
+    date_axis.set_crosses(category_axis.get_crosses().clone());
+    date_axis.get_auto_labeled_mut().set_val("1");
+    date_axis.get_label_offset_mut().set_val("100");
+    let mut base_time_unit = umya_spreadsheet::drawing::charts::BaseTimeUnit::default();
+    base_time_unit.set_val("days");
+    date_axis.set_base_time_unit(base_time_unit);
+    plot_area.set_date_axis(date_axis);
+
+    let value_axis = &mut plot_area.get_value_axis_mut()[0];
+    let mut log_base = umya_spreadsheet::drawing::charts::LogBase::default();
+    log_base.set_val("10");
+    value_axis.get_scaling_mut().set_log_base(log_base);
+    let mut max_axis_value = umya_spreadsheet::drawing::charts::MaxAxisValue::default();
+    max_axis_value.set_val("10000");
+    value_axis.get_scaling_mut().set_max_axis_value(max_axis_value);
+    let mut built_in_unit = umya_spreadsheet::drawing::charts::BuiltInUnit::default();
+    built_in_unit.set_val("thousands");
+    let mut display_units = umya_spreadsheet::drawing::charts::DisplayUnits::default();
+    display_units.set_built_in_unit(built_in_unit);
+    display_units.set_display_units_label(umya_spreadsheet::drawing::charts::DisplayUnitsLabel::default());
+    value_axis.set_display_units(display_units);
+
+    let path = std::path::Path::new("C:/spread_test_data/chart_axis_scaling_and_date_axis.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let graphic_frame = &book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection()[0];
+    let plot_area = graphic_frame.get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area();
+    assert_eq!(0, plot_area.get_category_axis().len());
+    assert_eq!("days", plot_area.get_date_axis()[0].get_base_time_unit().as_ref().unwrap().get_val());
+    let scaling = plot_area.get_value_axis()[0].get_scaling();
+    assert_eq!("10", scaling.get_log_base().as_ref().unwrap().get_val());
+    assert_eq!("10000", scaling.get_max_axis_value().as_ref().unwrap().get_val());
+    assert!(scaling.get_min_axis_value().is_none());
+    let display_units = plot_area.get_value_axis()[0].get_display_units().as_ref().unwrap();
+    assert_eq!("thousands", display_units.get_built_in_unit().as_ref().unwrap().get_val());
+    assert!(display_units.get_display_units_label().is_some());
+}
+
+#[test]
+fn chart_trendline_error_bars_data_points() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    for row in 1..6 {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, row).set_value(format!("Item{}", row));
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, row).set_value_number((row * 10) as i32);
+        let _ = worksheet.get_cell_by_column_and_row_mut(3, row).set_value_number(row as i32);
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Column, "E1:L15").unwrap()
+        .add_series("", "$A$1:$A$5", "$B$1:$B$5")
+        .add_trendline(0, umya_spreadsheet::drawing::charts::TrendlineType::TYPE_LINEAR, true, true).unwrap()
+        .add_trendline(0, umya_spreadsheet::drawing::charts::TrendlineType::TYPE_POLYNOMIAL, false, false).unwrap()
+        .set_error_bars(0, umya_spreadsheet::drawing::charts::ErrorBarValueType::TYPE_PERCENTAGE, 5f64).unwrap()
+        .set_data_point_color(0, 1, "FF0000").unwrap();
+    let chart_space = worksheet.add_chart(umya_spreadsheet::ChartType::Line, "E16:L30").unwrap()
+        .add_series("", "$A$1:$A$5", "$B$1:$B$5")
+        .set_custom_error_bars(0, "$C$1:$C$5", "$C$1:$C$5").unwrap();
+    assert!(chart_space.add_polynomial_trendline(0, 7, false, false).is_err());
+    assert!(chart_space.add_moving_average_trendline(0, 1).is_err());
+    assert_eq!(Err("Not found."), chart_space.add_trendline(1, umya_spreadsheet::drawing::charts::TrendlineType::TYPE_LINEAR, false, false).map(|_| ()));
+    assert_eq!(Err("Not found."), chart_space.add_polynomial_trendline(1, 3, false, false).map(|_| ()));
+    assert_eq!(Err("Not found."), chart_space.add_moving_average_trendline(1, 3).map(|_| ()));
+    assert_eq!(Err("Not found."), chart_space.set_error_bars(1, umya_spreadsheet::drawing::charts::ErrorBarValueType::TYPE_PERCENTAGE, 5f64).map(|_| ()));
+    assert_eq!(Err("Not found."), chart_space.set_custom_error_bars(1, "$C$1:$C$5", "$C$1:$C$5").map(|_| ()));
+    assert_eq!(Err("Not found."), chart_space.set_data_point_color(1, 0, "FF0000").map(|_| ()));
+    let _ = chart_space.add_polynomial_trendline(0, 4, false, false).unwrap()
+        .add_moving_average_trendline(0, 3).unwrap();
+
+    let path = std::path::Path::new("C:/spread_test_data/chart_trendline_error_bars_data_points.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let graphic_frames = book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection();
+    let series = &graphic_frames[0].get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area().get_bar_chart().unwrap().get_area_chart_series()[0];
+    assert_eq!(2, series.get_trendline().len());
+    let trendline = &series.get_trendline()[0];
+    assert_eq!("linear", trendline.get_trendline_type().get_val());
+    assert_eq!("1", trendline.get_display_r_squared_value().as_ref().unwrap().get_val());
+    assert_eq!("1", trendline.get_display_equation().as_ref().unwrap().get_val());
+    assert_eq!("2", series.get_trendline()[1].get_polynomial_order().as_ref().unwrap().get_val());
+    let error_bars = &series.get_error_bars()[0];
+    assert_eq!("percentage", error_bars.get_error_bar_value_type().get_val());
+    assert_eq!("5", error_bars.get_error_bar_value().as_ref().unwrap().get_val());
+    assert_eq!(1, series.get_data_point().len());
+    assert_eq!("1", series.get_data_point()[0].get_index().get_val());
+    assert!(series.get_data_point()[0].get_shape_properties().is_some());
+
+    let series = &graphic_frames[1].get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area().get_line_chart().unwrap().get_area_chart_series()[0];
+    let error_bars = &series.get_error_bars()[0];
+    assert_eq!("cust", error_bars.get_error_bar_value_type().get_val());
+    assert_eq!("Sheet1!$C$1:$C$5", error_bars.get_plus().as_ref().unwrap().get_number_reference().get_formula().get_address().get_address());
+    assert_eq!(2, series.get_trendline().len());
+    assert_eq!("4", series.get_trendline()[0].get_polynomial_order().as_ref().unwrap().get_val());
+    assert_eq!("3", series.get_trendline()[1].get_period().as_ref().unwrap().get_val());
+}
+
+#[test]
+fn update_chart_caches() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    for row in 1..4 {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, row).set_value(format!("Item{}", row));
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, row).set_value_number(row as i32);
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Column, "D1:J10").unwrap()
+        .add_series("", "$A$1:$A$3", "$B$1:$B$3");
+
+    let path = std::path::Path::new("C:/spread_test_data/update_chart_caches.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(0);
+    let _ = worksheet.get_cell_by_column_and_row_mut(1, 4).set_value("Item4");
+    let _ = worksheet.get_cell_by_column_and_row_mut(2, 2).set_value_number(20);
+    let _ = worksheet.get_cell_by_column_and_row_mut(2, 4).set_value_number(40);
+    worksheet.get_style_by_column_and_row_mut(2, 1).get_number_format_mut().set_format_code(umya_spreadsheet::NumberFormat::FORMAT_NUMBER_00);
+    let graphic_frame = worksheet.get_worksheet_drawing_mut().get_graphic_frame_collection_mut().into_iter().next().unwrap();
+    let series = graphic_frame.get_graphic_mut().get_graphic_data_mut().get_chart_space_mut().get_chart_mut().get_plot_area_mut()
+        .get_area_chart_series_mut().into_iter().next().unwrap();
+    series.get_category_axis_data_mut().as_mut().unwrap().get_string_reference_mut().get_formula_mut().set_address_str("Sheet1!$A$1:$A$4");
+    series.get_values_mut().as_mut().unwrap().get_number_reference_mut().get_formula_mut().set_address_str("Sheet1!$B$1:$B$4");
+
+    book.update_chart_caches();
+
+    let graphic_frame = &book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection()[0];
+    let series = &graphic_frame.get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area().get_area_chart_series()[0];
+    let string_cache = series.get_category_axis_data().as_ref().unwrap().get_string_reference().get_string_cache();
+    assert_eq!("4", string_cache.get_point_count().get_val());
+    assert_eq!("Item4", string_cache.get_string_point()[3].get_numeric_value().get_val());
+    let numbering_cache = series.get_values().as_ref().unwrap().get_number_reference().get_numbering_cache();
+    assert_eq!("0.00", numbering_cache.get_format_code().get_val());
+    assert_eq!("4", numbering_cache.get_point_count().get_val());
+    assert_eq!("20", numbering_cache.get_string_point()[1].get_numeric_value().get_val());
+    assert_eq!("40", numbering_cache.get_string_point()[3].get_numeric_value().get_val());
+}
+
+#[test]
+fn adjustment_references_on_insert_and_rename() {
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet2");
+    let worksheet = book.get_sheet_mut(0);
+    for row in 1..4 {
+        let _ = worksheet.get_cell_by_column_and_row_mut(1, row).set_value(format!("Item{}", row));
+        let _ = worksheet.get_cell_by_column_and_row_mut(2, row).set_value_number(row as i32);
+    }
+    worksheet.add_chart(umya_spreadsheet::ChartType::Column, "D1:J10").unwrap()
+        .add_series("", "$A$1:$A$3", "Sheet1!$B$1:$B$3");
+    let _ = book.add_defined_name("Items", "Sheet1!$A$1:$A$3");
+    let _ = book.get_sheet_mut(1).get_cell_mut("A1").set_formula("SUM(Sheet1!B1:B3)");
+    let _ = book.get_sheet_mut(1).get_cell_mut("A2").get_hyperlink_mut().set_url("Sheet1!B2").set_location(true);
+
+    book.insert_new_row("Sheet1", 1, 2);
+    let _ = book.set_sheet_title(0, "My Data");
+
+    assert_eq!("'My Data'!$A$3:$A$5", book.get_defined_names()[0].get_address());
+    let worksheet = book.get_sheet(1).unwrap();
+    assert_eq!("SUM('My Data'!B3:B5)", worksheet.get_cell("A1").unwrap().get_formula());
+    assert_eq!("'My Data'!B4", worksheet.get_cell("A2").unwrap().get_hyperlink().as_ref().unwrap().get_url());
+    let graphic_frame = &book.get_sheet(0).unwrap().get_worksheet_drawing().get_graphic_frame_collection()[0];
+    let series = &graphic_frame.get_graphic().get_graphic_data().get_chart_space().get_chart().get_plot_area().get_area_chart_series()[0];
+    assert_eq!("'My Data'!$A$3:$A$5", series.get_category_axis_data().as_ref().unwrap().get_string_reference().get_formula().get_address_str());
+    assert_eq!("'My Data'!$B$3:$B$5", series.get_values().as_ref().unwrap().get_number_reference().get_formula().get_address_str());
+
+    let path = std::path::Path::new("C:/spread_test_data/adjustment_references_on_insert_and_rename.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+}
+
+#[test]
+fn add_image() {
+    use umya_spreadsheet::drawing::spreadsheet::TwoCellAnchor;
+
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet2");
+    let picture = book.get_sheet_mut(0).add_image("./images/sample1.png", "B3").unwrap();
+    let transform2d = picture.get_shape_properties().get_transform2d();
+    assert_eq!(580, (*transform2d.get_width() as f64 / 9525f64).round() as usize);
+    assert_eq!(318, (*transform2d.get_height() as f64 / 9525f64).round() as usize);
+    assert_eq!(&None, picture.get_edit_as());
+    assert!(picture.set_edit_as("floating").is_err());
+    let _ = book.get_sheet_mut(0).add_image("./images/sample1.png", "B30").unwrap();
+    let _ = book.get_sheet_mut(1).add_image("./images/sample1.png", "A1").unwrap();
+    let gif = vec![b'G', b'I', b'F', b'8', b'9', b'a', 16, 0, 8, 0, 0x80, 0, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF,
+        0x21, 0xF9, 0x04, 0x01, 0, 0, 0, 0, 0x2C, 0, 0, 0, 0, 16, 0, 8, 0, 0, 0x02, 0x02, 0x44, 0x01, 0, 0x3B];
+    let _ = book.get_sheet_mut(1).add_image_from_bytes(gif, "D4").unwrap()
+        .set_edit_as(TwoCellAnchor::EDIT_AS_TWO_CELL).unwrap();
+    assert!(book.get_sheet_mut(1).add_image_from_bytes(vec![0, 1, 2, 3], "D4").is_err());
+    assert!(book.get_sheet_mut(1).add_image("./images/sample1.png", "d4").is_err());
+    assert!(book.get_sheet_mut(1).add_image("./images/sample1.png", "D").is_err());
+    assert!(book.get_sheet_mut(1).add_image("./images/sample1.png", "XFE1").is_err());
+    let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"></svg>"#.to_vec();
+    let picture = book.get_sheet_mut(1).add_image_from_bytes(svg.clone(), "F4").unwrap();
+    assert_eq!(&(10 * 9525), picture.get_shape_properties().get_transform2d().get_width());
+
+    let path = std::path::Path::new("C:/spread_test_data/add_image.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    // pictures move with the cell they start in and keep their size
+    let anchor = &book.get_sheet(0).unwrap().get_worksheet_drawing().get_one_cell_anchor_collection()[0];
+    assert_eq!(&1, anchor.get_from_marker().get_col());
+    assert_eq!(&2, anchor.get_from_marker().get_row());
+    assert_eq!(580, (*anchor.get_extent().get_cx() as f64 / 9525f64).round() as usize);
+    let pictures = book.get_sheet(0).unwrap().get_worksheet_drawing().get_picture_collection();
+    assert_eq!(2, pictures.len());
+    let image_name = pictures[0].get_blip_fill().get_blip().get_image_name();
+    assert!(image_name.ends_with(".png"));
+    assert_eq!(image_name, pictures[1].get_blip_fill().get_blip().get_image_name());
+    let pictures = book.get_sheet(1).unwrap().get_worksheet_drawing().get_picture_collection();
+    assert_eq!(image_name, pictures[0].get_blip_fill().get_blip().get_image_name());
+    assert!(pictures[1].get_blip_fill().get_blip().get_image_name().ends_with(".gif"));
+    assert_eq!(&(16 * 9525), pictures[1].get_shape_properties().get_transform2d().get_width());
+    let anchor = &book.get_sheet(1).unwrap().get_worksheet_drawing().get_two_cell_anchor_collection()[0];
+    assert_eq!(TwoCellAnchor::EDIT_AS_TWO_CELL, anchor.get_edit_as_str());
+    assert_eq!(&3, anchor.get_from_marker().get_col());
+    assert_eq!(&3, anchor.get_to_marker().get_col());
+    assert_eq!(&(16 * 9525), anchor.get_to_marker().get_col_off());
+
+    // SVG images keep a PNG fallback
+    let blip = pictures[2].get_blip_fill().get_blip();
+    assert!(blip.get_image_name().ends_with(".png"));
+    assert!(blip.get_svg_image_name().ends_with(".svg"));
+    assert_eq!(&Some(svg), blip.get_svg_image_data());
+}
+
+#[test]
+fn one_cell_and_absolute_anchors() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let picture = worksheet.add_image("./images/sample1.png", "B3").unwrap();
+    let picture_copy = picture.clone();
+    let _ = picture.set_edit_as(umya_spreadsheet::drawing::spreadsheet::TwoCellAnchor::EDIT_AS_ONE_CELL).unwrap();
+    let picture = picture_copy;
+
+    let mut one_cell_anchor = umya_spreadsheet::drawing::spreadsheet::OneCellAnchor::default();
+    one_cell_anchor.get_from_marker_mut().set_col(3).set_row(20);
+    one_cell_anchor.get_extent_mut().set_cx(952500).set_cy(476250);
+    one_cell_anchor.set_picture(picture.clone());
+    worksheet.get_worksheet_drawing_mut().add_one_cell_anchor_collection(one_cell_anchor);
+
+    let mut absolute_anchor = umya_spreadsheet::drawing::spreadsheet::AbsoluteAnchor::default();
+    absolute_anchor.get_position_mut().set_x(95250).set_y(190500);
+    absolute_anchor.get_extent_mut().set_cx(952500).set_cy(476250);
+    absolute_anchor.set_picture(picture);
+    worksheet.get_worksheet_drawing_mut().add_absolute_anchor_collection(absolute_anchor);
+    worksheet.add_chart(umya_spreadsheet::ChartType::Line, "H2:M10").unwrap()
+        .add_series("", "", "$B$1:$B$3");
+
+    book.insert_new_row("Sheet1", 2, 3);
+
+    let path = std::path::Path::new("C:/spread_test_data/one_cell_and_absolute_anchors.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet_drawing = book.get_sheet(0).unwrap().get_worksheet_drawing();
+    assert_eq!(3, worksheet_drawing.get_picture_collection().len());
+
+    // editAs="oneCell" moves with the cell and keeps its size
+    let two_cell_anchor = &worksheet_drawing.get_two_cell_anchor_collection()[0];
+    assert_eq!(umya_spreadsheet::drawing::spreadsheet::TwoCellAnchor::EDIT_AS_ONE_CELL, two_cell_anchor.get_edit_as_str());
+    assert_eq!(&5, two_cell_anchor.get_from_marker().get_row());
+    assert_eq!(&20, two_cell_anchor.get_to_marker().get_row());
+
+    let one_cell_anchor = &worksheet_drawing.get_one_cell_anchor_collection()[0];
+    assert_eq!(&3, one_cell_anchor.get_from_marker().get_col());
+    assert_eq!(&23, one_cell_anchor.get_from_marker().get_row());
+    assert_eq!(&952500, one_cell_anchor.get_extent().get_cx());
+    assert!(one_cell_anchor.get_picture().is_some());
+
+    let absolute_anchor = &worksheet_drawing.get_absolute_anchor_collection()[0];
+    assert_eq!(&190500, absolute_anchor.get_position().get_y());
+    assert_eq!(&476250, absolute_anchor.get_extent().get_cy());
+    assert!(absolute_anchor.get_picture().as_ref().unwrap().get_blip_fill().get_blip().get_image_name().ends_with(".png"));
+
+    // anchors keep the order they were added in, and the chart after them gets its own relationship
+    use umya_spreadsheet::drawing::spreadsheet::Anchor;
+    let anchors = worksheet_drawing.get_anchor_collection();
+    assert_eq!(4, anchors.len());
+    assert!(matches!(anchors[0], Anchor::TwoCell(_)));
+    assert!(matches!(anchors[1], Anchor::OneCell(_)));
+    assert!(matches!(anchors[2], Anchor::Absolute(_)));
+    assert!(anchors[3].get_graphic_frame().is_some());
+}
+
+#[test]
+fn add_shape_and_connector() {
+    use umya_spreadsheet::drawing::{PresetGeometry, HeadEnd, TailEnd};
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let shape = worksheet.add_shape(PresetGeometry::GEOMETRY_WEDGERECTCALLOUT, "B2:D5").unwrap();
+    shape.add_text_run("Total:").set_bold("1");
+    shape.add_text_run("120").set_font_name("Arial").set_font_size(14.0).set_color("FF0000");
+    shape.add_text_paragraph();
+    shape.add_text_run("checked");
+    shape.get_shape_properties_mut()
+        .set_fill_color("FFF2CC")
+        .set_outline_color("BF9000")
+        .set_outline_width(19050);
+    let start_id = shape.get_non_visual_shape_properties().get_non_visual_drawing_properties().get_id().to_string();
+
+    let text_box = worksheet.add_shape(PresetGeometry::GEOMETRY_RECT, "G2:I5").unwrap();
+    text_box.set_text("Line 1\nLine 2");
+    let mut outline = umya_spreadsheet::drawing::Outline::default();
+    outline.set_no_fill(umya_spreadsheet::drawing::NoFill::default());
+    text_box.get_shape_properties_mut().set_outline(outline);
+    text_box.get_shape_properties_mut().set_outline_color("2F528F");
+    let outline = text_box.get_shape_properties().get_outline().as_ref().unwrap();
+    assert!(outline.get_solid_fill().is_some());
+    assert!(outline.get_no_fill().is_none());
+    let end_id = text_box.get_non_visual_shape_properties().get_non_visual_drawing_properties().get_id().to_string();
+
+    worksheet.add_connection_shape(PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1, "E3:F3").unwrap()
+        .set_start_connection_shape(start_id.clone(), &3)
+        .set_end_connection_shape(end_id.clone(), &1)
+        .get_shape_properties_mut()
+        .set_outline_color("000000")
+        .set_head_end_type(HeadEnd::TYPE_OVAL)
+        .set_tail_end_type(TailEnd::TYPE_TRIANGLE);
+    assert!(worksheet.add_shape(PresetGeometry::GEOMETRY_RECT, "b2:d5").is_err());
+    assert!(worksheet.add_connection_shape(PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1, "E3:").is_err());
+
+    let path = std::path::Path::new("C:/spread_test_data/add_shape_and_connector.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet_drawing = book.get_sheet(0).unwrap().get_worksheet_drawing();
+    let shapes = worksheet_drawing.get_shape_collection();
+    assert_eq!(2, shapes.len());
+    assert_ne!(start_id, end_id);
+
+    let shape = shapes[0];
+    assert_eq!(PresetGeometry::GEOMETRY_WEDGERECTCALLOUT, shape.get_shape_properties().get_geometry().get_geometry());
+    assert_eq!("Total:120\nchecked", shape.get_text());
+    let run_properties = shape.get_text_body().get_paragraph()[0].get_run()[1].get_run_properties();
+    assert_eq!(&Some("1400".to_string()), run_properties.get_sz());
+    assert_eq!("Arial", run_properties.get_latin_font().as_ref().unwrap().get_typeface());
+    assert_eq!(&Some("FF0000".to_string()), run_properties.get_solid_fill().as_ref().unwrap().get_rgb_color_model_hex().as_ref().unwrap().get_val());
+    let outline = shape.get_shape_properties().get_outline().as_ref().unwrap();
+    assert_eq!(&19050, outline.get_width());
+    assert_eq!("Line 1\nLine 2", shapes[1].get_text());
+
+    let connection_shapes = worksheet_drawing.get_connection_shape_collection();
+    assert_eq!(1, connection_shapes.len());
+    let connector = connection_shapes[0].get_non_visual_connection_shape_properties().get_non_visual_connector_shape_drawing_properties();
+    assert_eq!(start_id, connector.get_start_connection().as_ref().unwrap().get_id());
+    assert_eq!("3", connector.get_start_connection().as_ref().unwrap().get_index());
+    assert_eq!(end_id, connector.get_end_connection().as_ref().unwrap().get_id());
+    let outline = connection_shapes[0].get_shape_properties().get_outline().as_ref().unwrap();
+    assert_eq!(HeadEnd::TYPE_OVAL, outline.get_head_end().as_ref().unwrap().get_type());
+    assert_eq!(TailEnd::TYPE_TRIANGLE, outline.get_tail_end().as_ref().unwrap().get_type());
+}
+
+#[test]
+fn group_shape_and_custom_geometry() {
+    use umya_spreadsheet::drawing::{PresetGeometry, Transform2D, CustomGeometry, PathCommand, PathPoint};
+    use umya_spreadsheet::drawing::{AdjustHandle, ConnectionSite, SolidFill, NoFill, RgbColorModelHex};
+    use umya_spreadsheet::drawing::spreadsheet::GroupShape;
+
+    let new_transform2d = |x:usize, y:usize, width:usize, height:usize| {
+        let mut transform2d = Transform2D::default();
+        transform2d.set_x(x);
+        transform2d.set_y(y);
+        transform2d.set_width(width);
+        transform2d.set_height(height);
+        transform2d
+    };
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let picture = worksheet.add_image("./images/sample1.png", "J2").unwrap().clone();
+
+    let group_shape = worksheet.add_group_shape("B2:F10").unwrap();
+    let group_id = group_shape.get_non_visual_group_shape_properties().get_non_visual_drawing_properties().get_id().to_string();
+    group_shape.add_picture_collection(picture);
+    let mut rgb_color_model_hex = RgbColorModelHex::default();
+    rgb_color_model_hex.set_val("FF0000");
+    let mut solid_fill = SolidFill::default();
+    solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+    group_shape.get_group_shape_properties_mut().set_solid_fill(solid_fill);
+    let mut child_group = GroupShape::default();
+    child_group.get_group_shape_properties_mut().set_no_fill(NoFill::default());
+    child_group.get_non_visual_group_shape_properties_mut().get_non_visual_drawing_properties_mut().set_id("100");
+    child_group.get_non_visual_group_shape_properties_mut().get_non_visual_drawing_properties_mut().set_name("Group 2");
+    group_shape.add_group_shape_collection(child_group);
+
+    worksheet.add_shape_to_group(&group_id, PresetGeometry::GEOMETRY_RECT, new_transform2d(0, 0, 914400, 457200)).unwrap()
+        .set_text("Logo");
+
+    let mut custom_geometry = CustomGeometry::default();
+    custom_geometry.add_path(&100, &100)
+        .add_move_to(&50, &0)
+        .add_line_to(&100, &50)
+        .add_cubic_bezier_to(&100, &80, &80, &100, &50, &100)
+        .add_quadratic_bezier_to(&0, &100, &0, &50)
+        .add_arc_to(&50, &50, &10800000, &5400000)
+        .add_close();
+    let mut position = PathPoint::default();
+    position.set_x("50").set_y("0");
+    custom_geometry.get_adjust_handle_list_mut().add_adjust_handle_collection(AdjustHandle::XY {
+        x_reference: "adj".to_string(),
+        min_x: "0".to_string(),
+        max_x: "100000".to_string(),
+        y_reference: String::new(),
+        min_y: String::new(),
+        max_y: String::new(),
+        position: position,
+    });
+    let mut connection_site = ConnectionSite::default();
+    connection_site.set_angle("16200000").get_position_mut().set_x("50").set_y("0");
+    custom_geometry.get_connection_site_list_mut().add_connection_site_collection(connection_site);
+    worksheet.add_shape_to_group("100", PresetGeometry::GEOMETRY_RECT, new_transform2d(1828800, 0, 914400, 914400)).unwrap()
+        .get_shape_properties_mut().set_custom_geometry(custom_geometry);
+
+    worksheet.add_connection_shape_to_group(&group_id, PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1, new_transform2d(914400, 228600, 914400, 0)).unwrap();
+    assert!(worksheet.add_shape_to_group("999", PresetGeometry::GEOMETRY_RECT, Transform2D::default()).is_err());
+    assert!(worksheet.add_group_shape("F10:B2").is_err());
+
+    let path = std::path::Path::new("C:/spread_test_data/group_shape_and_custom_geometry.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet_drawing = book.get_sheet(0).unwrap().get_worksheet_drawing();
+    let group_shapes = worksheet_drawing.get_group_shape_collection();
+    assert_eq!(2, group_shapes.len());
+    let transform2d = group_shapes[0].get_group_shape_properties().get_transform2d().as_ref().unwrap();
+    assert_eq!(&Some(0), transform2d.get_child_x());
+    assert_eq!(transform2d.get_width(), &transform2d.get_child_width().unwrap());
+    assert_eq!(1, group_shapes[0].get_shape_collection().len());
+    assert_eq!(1, group_shapes[0].get_connection_shape_collection().len());
+    assert_eq!(1, group_shapes[0].get_group_shape_collection().len());
+    let group_shape_properties = group_shapes[0].get_group_shape_properties();
+    let solid_fill = group_shape_properties.get_solid_fill().as_ref().unwrap();
+    assert_eq!(&Some("FF0000".to_string()), solid_fill.get_rgb_color_model_hex().as_ref().unwrap().get_val());
+    assert!(group_shapes[0].get_group_shape_collection()[0].get_group_shape_properties().get_no_fill().is_some());
+
+    // children keep the order they were added in
+    use umya_spreadsheet::drawing::spreadsheet::GroupShapeChild;
+    let children = group_shapes[0].get_child_collection();
+    assert_eq!(4, children.len());
+    assert!(matches!(children[0], GroupShapeChild::Picture(_)));
+    assert!(matches!(children[1], GroupShapeChild::GroupShape(_)));
+    assert!(matches!(children[2], GroupShapeChild::Shape(_)));
+    assert!(matches!(children[3], GroupShapeChild::ConnectionShape(_)));
+
+    // grouped pictures keep their image
+    let pictures = worksheet_drawing.get_picture_collection();
+    assert_eq!(2, pictures.len());
+    assert_eq!(pictures[0].get_blip_fill().get_blip().get_image_name(), pictures[1].get_blip_fill().get_blip().get_image_name());
+    assert!(pictures[1].get_blip_fill().get_blip().get_image_data().is_some());
+
+    let shapes = worksheet_drawing.get_shape_collection();
+    assert_eq!(2, shapes.len());
+    assert_eq!("Logo", shapes[1].get_text());
+    let ids: Vec<&str> = shapes.iter().map(|v| v.get_non_visual_shape_properties().get_non_visual_drawing_properties().get_id()).collect();
+    assert_ne!(ids[0], ids[1]);
+
+    let custom_geometry = shapes[0].get_shape_properties().get_custom_geometry().as_ref().unwrap();
+    let path = &custom_geometry.get_path_list().get_path_collection()[0];
+    assert_eq!(&Some(100), path.get_width());
+    let commands = path.get_command_collection();
+    assert_eq!(6, commands.len());
+    match &commands[2] {
+        PathCommand::CubicBezierTo(_, _, point) => {
+            assert_eq!("50", point.get_x());
+            assert_eq!("100", point.get_y());
+        },
+        _ => panic!("cubicBezTo expected"),
+    }
+    match &commands[4] {
+        PathCommand::ArcTo { swing_angle, .. } => assert_eq!("5400000", swing_angle),
+        _ => panic!("arcTo expected"),
+    }
+    assert!(matches!(commands[5], PathCommand::Close));
+
+    let adjust_handles = custom_geometry.get_adjust_handle_list().get_adjust_handle_collection();
+    assert_eq!(1, adjust_handles.len());
+    match &adjust_handles[0] {
+        AdjustHandle::XY { x_reference, max_x, y_reference, .. } => {
+            assert_eq!("adj", x_reference);
+            assert_eq!("100000", max_x);
+            assert_eq!("", y_reference);
+        },
+        _ => panic!("ahXY expected"),
+    }
+    assert_eq!("50", adjust_handles[0].get_position().get_x());
+    let connection_sites = custom_geometry.get_connection_site_list().get_connection_site_collection();
+    assert_eq!(1, connection_sites.len());
+    assert_eq!("16200000", connection_sites[0].get_angle());
+    assert_eq!("50", connection_sites[0].get_position().get_x());
+}
+
+#[test]
+fn threaded_comments_and_persons() {
+    use std::io::Read;
+
+    let mut book = umya_spreadsheet::new_file();
+    let taro_id = book.add_person("Taro Yamada").get_id().to_string();
+    let hanako_id = book.add_person("Hanako Sato").get_id().to_string();
+    let worksheet = book.get_sheet_mut(0);
+    let thread_id = worksheet.add_threaded_comment("B2", &taro_id, "@Hanako Sato please check.").get_id().to_string();
+    worksheet.get_threaded_comment_by_id_mut(&thread_id).unwrap().add_mention(&hanako_id, 0, 12);
+    let reply_id = worksheet.add_threaded_comment_reply(&thread_id, &hanako_id, "Done.").unwrap().get_id().to_string();
+    worksheet.get_threaded_comment_by_id_mut(&thread_id).unwrap().set_done(true);
+    assert!(worksheet.add_threaded_comment_reply(&reply_id, &taro_id, "Thanks.").is_err());
+    worksheet.add_threaded_comment("D5", &taro_id, "Second thread.");
+
+    // threads follow inserted rows
+    book.insert_new_row("Sheet1", 2, 1);
+
+    let path = std::path::Path::new("C:/spread_test_data/threaded_comments.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    // the legacy notes copy the conversation
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut comments_xml = String::new();
+    archive.by_name("xl/comments1.xml").unwrap().read_to_string(&mut comments_xml).unwrap();
+    assert!(comments_xml.contains(&format!("tc={}", thread_id)));
+    assert!(comments_xml.contains("Reply:"));
+    assert!(archive.by_name("xl/threadedComments/threadedComment1.xml").is_ok());
+    assert!(archive.by_name("xl/persons/person.xml").is_ok());
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(2, book.get_person_collection().len());
+    assert_eq!("Hanako Sato", book.get_person_by_id(&hanako_id).unwrap().get_display_name());
+
+    let worksheet = book.get_sheet(0).unwrap();
+    assert_eq!(0, worksheet.get_comments().len());
+    assert_eq!(3, worksheet.get_threaded_comments().len());
+    let thread = worksheet.get_threaded_comment_by_id(&thread_id).unwrap();
+    assert_eq!("B3", thread.get_coordinate().get_coordinate());
+    assert_eq!(&true, thread.get_done());
+    assert_eq!(&taro_id, thread.get_person_id());
+    assert_eq!("@Hanako Sato please check.", thread.get_text());
+    assert_eq!(1, thread.get_mentions().len());
+    assert_eq!(&hanako_id, thread.get_mentions()[0].get_mention_person_id());
+    assert_eq!(&12, thread.get_mentions()[0].get_length());
+
+    let replies = worksheet.get_threaded_comment_replies(&thread_id);
+    assert_eq!(1, replies.len());
+    assert_eq!(&reply_id, replies[0].get_id());
+    assert_eq!("Done.", replies[0].get_text());
+    assert_eq!("B3", replies[0].get_coordinate().get_coordinate());
+    assert_eq!("D6", worksheet.get_threaded_comments()[2].get_coordinate().get_coordinate());
+}
+
+#[test]
+fn comment_shape() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(0);
+    let comment = worksheet.add_comment("B2", "Taro", "Checked.");
+    comment.set_anchor_range("C1:F6");
+    comment.set_width_pt(150.0);
+    comment.set_height_emu(1270000);
+    comment.set_visible(true);
+    comment.set_shadow(false);
+    comment.set_auto_fit(true);
+    comment.set_alignment(umya_spreadsheet::Comment::ALIGNMENT_CENTER);
+
+    let mut text_element = umya_spreadsheet::TextElement::default();
+    text_element.set_text("Bold");
+    text_element.get_font_mut().set_bold(true).set_name("Tahoma");
+    let mut rich_text = umya_spreadsheet::RichText::default();
+    rich_text.add_rich_text_elements(text_element);
+    worksheet.add_comment("D4", "Hanako", "").set_text(rich_text);
+
+    let path = std::path::Path::new("C:/spread_test_data/comment_shape.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let comments = book.get_sheet(0).unwrap().get_comments_to_hashmap();
+    let comment = comments.get("B2").unwrap();
+    assert_eq!("Taro", comment.get_author());
+    assert_eq!("Checked.", comment.get_text().get_text());
+    assert_eq!(150.0, comment.get_width_pt());
+    assert_eq!(100.0, comment.get_height_pt());
+    assert_eq!(1270000, comment.get_height_emu());
+    assert_eq!(59.25, comment.get_margin_left_pt());
+    assert_eq!(&2, comment.get_anchor().get_left_column());
+    assert_eq!(&0, comment.get_anchor().get_top_row());
+    assert_eq!(&6, comment.get_anchor().get_right_column());
+    assert_eq!(&6, comment.get_anchor().get_bottom_row());
+    assert_eq!(&true, comment.get_visible());
+    assert_eq!(&false, comment.get_shadow());
+    assert_eq!(&true, comment.get_auto_fit());
+    assert_eq!("center", comment.get_alignment());
+
+    let comment = comments.get("D4").unwrap();
+    assert_eq!(&true, comment.get_shadow());
+    assert_eq!(&false, comment.get_auto_fit());
+    assert_eq!(&false, comment.get_visible());
+    let font = comment.get_text().get_rich_text_elements()[0].get_font().as_ref().unwrap();
+    assert_eq!(&true, font.get_bold());
+    assert_eq!("Tahoma", font.get_name());
+}
+
+#[test]
+fn hyperlink_collection() {
+    use umya_spreadsheet::{Hyperlink, HyperlinkTarget};
+
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet 2");
+    let worksheet = book.get_sheet_mut(0);
+    worksheet.add_hyperlink("A1", Hyperlink::new_url("https://example.com")).set_tooltip("Example");
+    worksheet.add_hyperlink("b3:d3", Hyperlink::new_location("Sheet 2", "a1")).set_display("Jump");
+    worksheet.add_hyperlink("A5", Hyperlink::new_mail("info@example.com", Some("Hello world")));
+    worksheet.add_hyperlink("A6", Hyperlink::new_file("C:/docs/report.xlsx"));
+    worksheet.add_hyperlink("A7:B7", Hyperlink::new_url("https://example.com/removed"));
+    worksheet.add_hyperlink("A8", Hyperlink::new_location("Sheet1", "E10"));
+    assert!(worksheet.remove_hyperlink("A7:B7"));
+    assert!(!worksheet.remove_hyperlink("A7:B7"));
+    assert_eq!(5, worksheet.get_hyperlink_collection().len());
+
+    // ranges and locations follow inserted rows and columns
+    book.insert_new_row("Sheet1", 2, 1);
+    book.insert_new_colmun("Sheet1", "A", 1);
+
+    let path = std::path::Path::new("C:/spread_test_data/hyperlink_collection.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(0).unwrap();
+    let hyperlinks = worksheet.get_hyperlink_collection();
+    let refs: Vec<&str> = hyperlinks.iter().map(|x| x.0.as_str()).collect();
+    assert_eq!(vec!["B1", "C4:E4", "B6", "B7", "B9"], refs);
+
+    let hyperlink = worksheet.get_hyperlink("B1").unwrap();
+    assert_eq!(HyperlinkTarget::Url("https://example.com".to_string()), hyperlink.get_target());
+    assert_eq!("Example", hyperlink.get_tooltip());
+
+    let hyperlink = worksheet.get_hyperlink("C4:E4").unwrap();
+    assert_eq!(HyperlinkTarget::Location("'Sheet 2'!A1".to_string()), hyperlink.get_target());
+    assert_eq!("Jump", hyperlink.get_display());
+
+    assert_eq!(
+        HyperlinkTarget::Mail("info@example.com".to_string(), Some("Hello world".to_string())),
+        worksheet.get_hyperlink("B6").unwrap().get_target()
+    );
+    assert_eq!(
+        HyperlinkTarget::File("file:///C:/docs/report.xlsx".to_string()),
+        worksheet.get_hyperlink("B7").unwrap().get_target()
+    );
+    assert_eq!("Sheet1!F11", worksheet.get_hyperlink("B9").unwrap().get_url());
+    assert!(worksheet.get_hyperlink("A1").is_none());
+}
+
+#[test]
+fn workbook_properties_and_views() {
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet2");
+    let _ = book.new_sheet("Sheet3");
+    book.set_date1904(true);
+    book.set_code_name("MyWorkbook");
+    book.get_calculation_mut()
+        .set_calc_mode(umya_spreadsheet::Calculation::CALC_MODE_MANUAL)
+        .set_full_calc_on_load(true)
+        .set_iterate(true)
+        .set_iterate_count(50)
+        .set_iterate_delta(0.01);
+    book.get_workbook_view_mut().set_tab_ratio(750).set_first_sheet(1).set_show_sheet_tabs(false);
+    let mut workbook_view = umya_spreadsheet::WorkbookView::default();
+    workbook_view.set_x_window(500).set_active_tab(2).set_minimized(true);
+    book.add_workbook_view_collection(workbook_view);
+    book.set_active_sheet(1).unwrap();
+
+    let path = std::path::Path::new("C:/spread_test_data/workbook_properties.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(&true, book.get_date1904());
+    assert_eq!(&true, book.get_filter_privacy());
+    assert_eq!(&Some("MyWorkbook".to_string()), book.get_code_name());
+
+    let calculation = book.get_calculation();
+    assert_eq!("manual", calculation.get_calc_mode());
+    assert_eq!(&true, calculation.get_full_calc_on_load());
+    assert_eq!(&true, calculation.get_iterate());
+    assert_eq!(&50, calculation.get_iterate_count());
+    assert_eq!(&0.01, calculation.get_iterate_delta());
+    assert_eq!(&true, calculation.get_full_precision());
+
+    let workbook_views = book.get_workbook_view_collection();
+    assert_eq!(2, workbook_views.len());
+    assert_eq!(&750, workbook_views[0].get_tab_ratio());
+    assert_eq!(&1, workbook_views[0].get_first_sheet());
+    assert_eq!(&false, workbook_views[0].get_show_sheet_tabs());
+    assert_eq!(&1, workbook_views[0].get_active_tab());
+    assert_eq!(&500, workbook_views[1].get_x_window());
+    assert_eq!(&2, workbook_views[1].get_active_tab());
+    assert_eq!(&true, workbook_views[1].get_minimized());
+    assert_eq!(&1, book.get_active_sheet_index());
+}
+
+#[test]
+fn custom_properties() {
+    use umya_spreadsheet::structs::CustomPropertyValue;
+
+    let mut book = umya_spreadsheet::new_file();
+    book.get_properties_mut()
+        .set_custom_property("Classification", "Internal & Confidential")
+        .set_custom_property("Project ID", 1234)
+        .set_custom_property("Budget", 12.5)
+        .set_custom_property("Serial", 9876543210i64)
+        .set_custom_property("Approved", true)
+        .set_custom_property("Retention", CustomPropertyValue::Date("2030-03-31T00:00:00Z".to_string()))
+        .set_custom_property("Obsolete", "x");
+    assert_eq!(true, book.get_properties_mut().remove_custom_property("Obsolete"));
+    assert_eq!(false, book.get_properties_mut().remove_custom_property("Obsolete"));
+    book.get_properties_mut().set_custom_property("Project ID", 5678);
+
+    let path = std::path::Path::new("C:/spread_test_data/ccc_custom_properties.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let properties = book.get_properties();
+    assert_eq!(6, properties.get_custom_properties().len());
+    assert_eq!(Some(&CustomPropertyValue::Text("Internal & Confidential".to_string())), properties.get_custom_property("Classification"));
+    assert_eq!(Some(&CustomPropertyValue::Integer(5678)), properties.get_custom_property("Project ID"));
+    assert_eq!(Some(&CustomPropertyValue::Number(12.5)), properties.get_custom_property("Budget"));
+    assert_eq!(Some(&CustomPropertyValue::Integer(9876543210)), properties.get_custom_property("Serial"));
+    assert_eq!(Some(&CustomPropertyValue::Bool(true)), properties.get_custom_property("Approved"));
+    assert_eq!(Some(&CustomPropertyValue::Date("2030-03-31T00:00:00Z".to_string())), properties.get_custom_property("Retention"));
+    assert_eq!(None, properties.get_custom_property("Obsolete"));
+}
+
+#[test]
+fn extended_properties() {
+    use std::io::Read;
+
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sales 2021");
+    let _ = book.add_defined_name("Totals", "Sheet1!$A$1:$B$2");
+    book.get_properties_mut()
+        .set_company("Example Co.")
+        .set_manager("Jane Smith")
+        .set_app_version("16.0300")
+        .set_doc_security(2)
+        .set_hyperlink_base("https://example.com/docs/")
+        .set_scale_crop(true);
+
+    let path = std::path::Path::new("C:/spread_test_data/eee_extended_properties.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut app_xml = String::new();
+    archive.by_name("docProps/app.xml").unwrap().read_to_string(&mut app_xml).unwrap();
+    assert!(app_xml.contains("<vt:lpstr>Named Ranges</vt:lpstr>"));
+    assert!(app_xml.contains("<vt:vector size=\"3\" baseType=\"lpstr\"><vt:lpstr>Sheet1</vt:lpstr><vt:lpstr>Sales 2021</vt:lpstr><vt:lpstr>Totals</vt:lpstr></vt:vector>"));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let properties = book.get_properties();
+    assert_eq!("Microsoft Excel", properties.get_application());
+    assert_eq!("16.0300", properties.get_app_version());
+    assert_eq!(&2, properties.get_doc_security());
+    assert_eq!("https://example.com/docs/", properties.get_hyperlink_base());
+    assert_eq!(&true, properties.get_scale_crop());
+    assert_eq!("Example Co.", properties.get_company());
+    assert_eq!("Jane Smith", properties.get_manager());
+}
+
+#[test]
+fn defined_names() {
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet2");
+    assert!(book.add_defined_name("Items", "Sheet1!$A$1:$A$10").is_ok());
+    assert!(book.add_defined_name("TaxRate", "=0.19").is_ok());
+    assert!(book.add_defined_name("Dynamic", "=OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)").is_ok());
+    assert!(book.add_defined_name("Header", "Sheet1!$C$1").is_ok());
+    let _ = book.get_sheet_mut(0).get_cell_mut("E5").set_formula("SUM($A$1:A10)+Sheet2!A1+D1+C1&\"C1\"");
+    assert!(book.add_defined_name("taxrate", "0.2").is_err());
+    assert!(book.add_local_defined_name("TaxRate", "0.07", "Sheet2").is_ok());
+    assert!(book.add_local_defined_name("TaxRate", "0.08", "Sheet2").is_err());
+    assert!(book.add_local_defined_name("TaxRate", "0.08", "Missing").is_err());
+    book.get_defined_name_mut("TaxRate", Some(1)).unwrap()
+        .set_hidden(true)
+        .set_comment("Reduced rate & VAT");
+
+    assert_eq!("0.19", book.find_defined_name("TaxRate", "Sheet1").unwrap().get_formula());
+    assert_eq!("0.07", book.find_defined_name("TaxRate", "Sheet2").unwrap().get_formula());
+    assert_eq!(true, book.get_defined_name("Items", None).unwrap().is_reference());
+    assert_eq!(false, book.get_defined_name("Dynamic", None).unwrap().is_reference());
+
+    book.insert_new_row("Sheet1", 1, 2);
+    book.remove_colmun("Sheet1", "C", 1);
+    let _ = book.set_sheet_title(0, "My Data");
+
+    assert_eq!("'My Data'!$A$3:$A$12", book.get_defined_name("Items", None).unwrap().get_formula());
+    assert_eq!("OFFSET('My Data'!$A$3,0,0,COUNTA('My Data'!$A:$A),1)", book.get_defined_name("Dynamic", None).unwrap().get_formula());
+    assert!(book.get_defined_name("Header", None).is_none());
+    assert_eq!("SUM($A$3:A12)+Sheet2!A1+C3+#REF!&\"C1\"", book.get_sheet(0).unwrap().get_cell("D7").unwrap().get_formula());
+
+    let path = std::path::Path::new("C:/spread_test_data/ddd_defined_names.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(4, book.get_defined_names().len());
+    assert_eq!("'My Data'!$A$3:$A$12", book.get_defined_name("Items", None).unwrap().get_formula());
+    assert_eq!("0.19", book.get_defined_name("TaxRate", None).unwrap().get_formula());
+    let local = book.get_defined_name("TaxRate", Some(1)).unwrap();
+    assert_eq!("0.07", local.get_formula());
+    assert_eq!(&true, local.get_hidden());
+    assert_eq!("Reduced rate & VAT", local.get_comment());
+    assert_eq!(true, book.remove_defined_name("TaxRate", Some(1)));
+    assert_eq!(false, book.remove_defined_name("TaxRate", Some(1)));
+}
+
+#[test]
+fn workbook_protection() {
+    use std::io::Read;
+
+    let mut book = umya_spreadsheet::new_file();
+    book.get_security_mut()
+        .set_workbook_password("P@ssw0rd")
+        .set_revisions_password_legacy("secret")
+        .set_lock_structure(true);
+    assert!(book.get_security().verify_workbook_password("P@ssw0rd"));
+    assert!(!book.get_security().verify_workbook_password("password"));
+    assert_eq!("DAA7", book.get_security().get_revisions_password());
+
+    let path = std::path::Path::new("C:/spread_test_data/fff_workbook_protection.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut workbook_xml = String::new();
+    archive.by_name("xl/workbook.xml").unwrap().read_to_string(&mut workbook_xml).unwrap();
+    assert!(workbook_xml.contains("revisionsPassword=\"DAA7\""));
+    assert!(workbook_xml.contains("workbookAlgorithmName=\"SHA-512\""));
+    assert!(workbook_xml.contains("workbookSpinCount=\"100000\""));
+    assert!(!workbook_xml.contains("lockWindows"));
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let security = book.get_security();
+    assert_eq!(&true, security.get_lock_structure());
+    assert_eq!(&false, security.get_lock_windows());
+    assert_eq!("SHA-512", security.get_workbook_algorithm_name());
+    assert_eq!(&100000, security.get_workbook_spin_count());
+    assert!(security.verify_workbook_password("P@ssw0rd"));
+    assert!(security.verify_revisions_password("secret"));
+    assert!(!security.verify_revisions_password("Secret"));
+
+    book.get_security_mut().clear_workbook_password();
+    assert!(book.get_security().verify_workbook_password(""));
+}